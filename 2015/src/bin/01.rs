@@ -1,28 +1,57 @@
-use aoc2015::read_stdin_to_string;
 use std::io;
 
-fn main() -> io::Result<()> {
-    let buffer = read_stdin_to_string()?;
-    let chars = buffer.chars();
-
-    let movements = chars.map(|c| {
-        match c {
+fn parse_input(input: &str) -> Vec<i32> {
+    input
+        .chars()
+        .map(|c| match c {
             '(' => 1,
             ')' => -1,
-            _ => 0
-        }
-    }).collect::<Vec<i32>>();
+            _ => 0,
+        })
+        .collect()
+}
 
-    println!("{}", movements.iter().sum::<i32>());
+fn part1(movements: &[i32]) -> i32 {
+    movements.iter().sum()
+}
 
+fn part2(movements: &[i32]) -> usize {
     let mut level = 0;
     for (i, m) in movements.iter().enumerate() {
         level += m;
         if level < 0 {
-            println!("{}", i + 1);
-            break;
+            return i + 1;
         }
     }
+    panic!("Never entered the basement")
+}
+
+struct Day01;
+
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day01);
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::example_tests! {
+    Day01;
+    balanced: "(())", part1 = 0;
+    nested: "))(((((", part1 = 3;
+    basement: ")", part2 = 1;
+    basement_later: "()())", part2 = 5;
+}
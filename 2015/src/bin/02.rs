@@ -1,5 +1,5 @@
-use std::io;
 use std::cmp::min;
+use std::io;
 
 fn parse_line(line: &str) -> (u32, u32, u32) {
     let parts = line.split('x');
@@ -7,32 +7,62 @@ fn parse_line(line: &str) -> (u32, u32, u32) {
     (
         number_iter.next().unwrap(),
         number_iter.next().unwrap(),
-        number_iter.next().unwrap()
+        number_iter.next().unwrap(),
     )
 }
 
-fn main()-> io::Result<()>  {
-    let input = aoc2015::read_stdin_lines()?;
+fn part1(input: &[String]) -> u32 {
+    input
+        .iter()
+        .map(|l| {
+            let (h, l, d) = parse_line(l);
+            let (a, b, c) = (h * l, l * d, h * d);
+
+            2 * a + 2 * b + 2 * c + min(min(a, b), c)
+        })
+        .sum()
+}
+
+fn part2(input: &[String]) -> u32 {
+    input
+        .iter()
+        .map(|l| {
+            let (h, l, d) = parse_line(l);
+            let (min, mid) = if h < l {
+                (h, min(d, l))
+            } else {
+                (l, min(h, d))
+            };
 
-    let areas = input.iter().map(|l| {
-        let (h, l, d) = parse_line(l);
-        let (a, b, c) = (h*l, l*d, h*d);
+            2 * min + 2 * mid + h * l * d
+        })
+        .sum()
+}
+
+struct Day02;
+
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-        2*a + 2*b + 2*c + min(min(a, b), c)
-    });
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
 
-    let ribbons = input.iter().map(|l| {
-        let (h, l, d) = parse_line(l);
-        let (min, mid) = if h < l {
-            (h, min(d, l))
-        } else {
-            (l, min(h, d))
-        };
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
 
-        2 * min + 2 * mid + h*l*d
-    });
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day02);
 
-    println!("1: {}", areas.sum::<u32>());
-    println!("2: {}", ribbons.sum::<u32>());
-    Ok(())
-}
\ No newline at end of file
+aoclib::example_tests! {
+    Day02;
+    example1: "2x3x4", part1 = 58, part2 = 34;
+    example2: "1x1x10", part1 = 43, part2 = 14;
+}
@@ -1,7 +1,7 @@
-use std::io;
 use std::collections::BTreeMap;
+use std::io;
 
-fn part1(input: &String) {
+fn part1(input: &str) -> usize {
     let mut pos = (0, 0);
     let mut houses: BTreeMap<(i32, i32), u32> = BTreeMap::new();
 
@@ -19,10 +19,10 @@ fn part1(input: &String) {
         *h_entry += 1;
     }
 
-    println!("{}", houses.len());
+    houses.len()
 }
 
-fn part2(input: String) {
+fn part2(input: &str) -> usize {
     let mut pos = (0, 0);
     let mut houses: BTreeMap<(i32, i32), u32> = BTreeMap::new();
 
@@ -54,15 +54,33 @@ fn part2(input: String) {
         *h_entry += 1;
     }
 
-    println!("{}", houses.len());
+    houses.len()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_to_string()?;
+struct Day03;
+
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    part1(&input);
-    part2(input);
+aoclib::aoc_main!(Day03);
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::example_tests! {
+    Day03;
+    example1: "^>v<", part1 = 4, part2 = 3;
+    example2: "^v^v^v^v^v", part1 = 2, part2 = 11;
+}
@@ -1,6 +1,6 @@
+use lazy_static::lazy_static;
 use regex::Regex;
 use std::io;
-use lazy_static::lazy_static;
 
 lazy_static! {
     static ref VOWEL_REGEX: Regex = Regex::new(r"[aeiou].*[aeiou].*[aeiou]").unwrap();
@@ -47,22 +47,32 @@ fn is_nice2(s: &str) -> bool {
         has_repeating_pair(s)
 }
 
-fn run_part1(input: &Vec<String>) {
-    let c = input.iter().filter(|s| is_nice1(s.as_str())).count();
-    println!("Part 1: {}", c);
+fn part1(input: &[String]) -> usize {
+    input.iter().filter(|s| is_nice1(s.as_str())).count()
 }
 
-fn run_part2(input: &Vec<String>) {
-    let c = input.iter().filter(|s| is_nice2(s.as_str())).count();
-    println!("Part 2: {}", c);
+fn part2(input: &[String]) -> usize {
+    input.iter().filter(|s| is_nice2(s.as_str())).count()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_lines()?;
+struct Day05;
+
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
 
-    run_part1(&input);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    run_part2(&input);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day05);
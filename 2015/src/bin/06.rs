@@ -1,48 +1,46 @@
-use std::io;
-use regex::Regex;
 use lazy_static::lazy_static;
+use regex::Regex;
+use std::io;
 
 lazy_static! {
     static ref NUMBER_REGEX: Regex = Regex::new(r"\d+").unwrap();
 }
 
 
-fn run1(input: &Vec<Operation>) {
+fn part1(input: &[Operation]) -> usize {
     let mut grid: [[bool; 1000]; 1000] = [[false; 1000]; 1000];
 
     for op in input {
-        for i in op.start.0..=op.end.0 {
-            for j in op.start.1..=op.end.1 {
-                grid[j][i] = match op.op_type {
+        for row in &mut grid[op.start.1..=op.end.1] {
+            for light in &mut row[op.start.0..=op.end.0] {
+                *light = match op.op_type {
                     OperationType::Off => false,
                     OperationType::On => true,
-                    OperationType::Toggle => !grid[j][i],
+                    OperationType::Toggle => !*light,
                 };
             }
         }
     }
 
-    let c = grid.iter().flat_map(|x| x.iter()).filter(|x| **x).count();
-    println!("Part 1: {}", c);
+    grid.iter().flat_map(|x| x.iter()).filter(|x| **x).count()
 }
 
-fn run2(input: &Vec<Operation>) {
+fn part2(input: &[Operation]) -> u64 {
     let mut grid: [[u64; 1000]; 1000] = [[0; 1000]; 1000];
 
     for op in input {
-        for i in op.start.0..=op.end.0 {
-            for j in op.start.1..=op.end.1 {
-                grid[j][i] = match op.op_type {
-                    OperationType::Off => grid[j][i].saturating_sub(1),
-                    OperationType::On => grid[j][i]+1,
-                    OperationType::Toggle => grid[j][i]+2,
+        for row in &mut grid[op.start.1..=op.end.1] {
+            for light in &mut row[op.start.0..=op.end.0] {
+                *light = match op.op_type {
+                    OperationType::Off => light.saturating_sub(1),
+                    OperationType::On => *light + 1,
+                    OperationType::Toggle => *light + 2,
                 };
             }
         }
     }
 
-    let c: u64 = grid.iter().flat_map(|x| x.iter()).sum();
-    println!("Part 2: {}", c);
+    grid.iter().flat_map(|x| x.iter()).sum()
 }
 
 
@@ -77,17 +75,30 @@ fn parse_input_line(line: &str) -> Operation {
     let ey = cs.next().unwrap();
 
     Operation {
-        op_type: op_type,
+        op_type,
         start: (sx, sy),
         end: (ex, ey),
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_lines()
-        .map(|x| x.iter().map(|l| parse_input_line(l)).collect::<Vec<Operation>>())?;
-    run1(&input);
-    run2(&input);
+struct Day06;
+
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = Vec<Operation>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::nonempty_lines(input).map(parse_input_line).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day06);
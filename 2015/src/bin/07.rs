@@ -23,22 +23,22 @@ struct Definition<'a> {
     dst: &'a str,
 }
 
-fn parse_operand(s: &str) -> Operand {
+fn parse_operand(s: &str) -> Operand<'_> {
     match s.parse() {
         Ok(val) => Operand::Imm(val),
         _ => Operand::Wire(s)
     }
 }
 
-fn parse_input_line<'a>(line: &'a str) -> Definition<'a> {
+fn parse_input_line(line: &str) -> Definition<'_> {
     let parts = line.split_whitespace().collect::<Vec<&str>>();
     if parts.len() == 3 {
-        return Definition {
+        Definition {
             op: Instruction::Mov(parse_operand(parts[0])),
             dst: parts[2],
         }
     } else if parts.len() == 4 {
-        return Definition {
+        Definition {
             op: Instruction::Not(parse_operand(parts[1])),
             dst: parts[3],
         }
@@ -50,8 +50,8 @@ fn parse_input_line<'a>(line: &'a str) -> Definition<'a> {
             "RSHIFT" => Instruction::Rshift(parse_operand(parts[0]), parts[2].parse().unwrap()),
             &_ => panic!("Illegal operation"),
         };
-        return Definition {
-            op: op,
+        Definition {
+            op,
             dst: parts[4],
         }
     }
@@ -86,7 +86,7 @@ fn eval<'a>(op: &Operand<'a>, wires: &HashMap<&str, &'a Instruction<'a>>, cache:
     ret
 }
 
-fn run1(defs: &Vec<Definition>) -> Option<u16> {
+fn part1(defs: &[Definition]) -> u16 {
     let mut wires: HashMap<&str, &Instruction> = HashMap::new();
     let mut cache: HashMap<&Instruction, u16> = HashMap::new();
 
@@ -94,12 +94,12 @@ fn run1(defs: &Vec<Definition>) -> Option<u16> {
         wires.insert(def.dst, &def.op);
     }
 
-    let res = eval(&Operand::Wire("a"), &wires, &mut cache);
-    println!("Part 1: {:?}", res);
-    res
+    eval(&Operand::Wire("a"), &wires, &mut cache).unwrap()
 }
 
-fn run2(defs: &Vec<Definition>, res1: u16) {
+fn part2(defs: &[Definition]) -> u16 {
+    let res1 = part1(defs);
+
     let mut wires: HashMap<&str, &Instruction> = HashMap::new();
     let mut cache: HashMap<&Instruction, u16> = HashMap::new();
 
@@ -109,19 +109,27 @@ fn run2(defs: &Vec<Definition>, res1: u16) {
     let i = Instruction::Mov(Operand::Imm(res1));
     wires.insert("b", &i);
 
-    let res = eval(&Operand::Wire("a"), &wires, &mut cache);
-    println!("Part 1: {:?}", res);
+    eval(&Operand::Wire("a"), &wires, &mut cache).unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_lines()?;
-    let defs = input.iter()
-        .map(|l| parse_input_line(l))
-        .collect::<Vec<_>>();
+struct Day07;
+
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = Vec<Definition<'a>>;
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::nonempty_lines(input).map(parse_input_line).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u16 {
+        part1(input)
+    }
 
-    let res1 = run1(&defs).unwrap();
-    
-    run2(&defs, res1);
+    fn part2(input: &Self::Input<'_>) -> u16 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day07);
@@ -1,5 +1,4 @@
 use std::io;
-use regex;
 
 fn get_escaped_len(s: &str) -> usize {
     let r = regex::Regex::new(r#"\\([\\"]|x[a-f0-9]{2})"#).unwrap();
@@ -12,16 +11,36 @@ fn encode(s: &str) -> usize {
         .len() + 2
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_lines()?;
+fn part1(input: &[String]) -> usize {
+    let escaped_len: usize = input.iter().map(|l| get_escaped_len(l.trim())).sum();
+    let unescaped_len: usize = input.iter().map(|l| l.trim().len()).sum();
+    unescaped_len - escaped_len
+}
+
+fn part2(input: &[String]) -> usize {
+    let unescaped_len: usize = input.iter().map(|l| l.trim().len()).sum();
+    let encoded_len: usize = input.iter().map(|l| encode(l.trim())).sum();
+    encoded_len - unescaped_len
+}
 
-    let escaped_len_1 = input.iter().map(|l| get_escaped_len(l.trim())).sum::<usize>();
-    let unescaped_len_1: usize = input.iter().map(|l| l.trim().len()).sum();
-    println!("Part 1 {}", unescaped_len_1 - escaped_len_1);
+struct Day08;
 
-    let escaped_len_2 : usize = input.iter().map(|l| encode(l.trim())).sum();
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("part 2 {}", escaped_len_2 - unescaped_len_1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day08);
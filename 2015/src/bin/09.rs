@@ -1,29 +1,18 @@
 use itertools::{Itertools, MinMaxResult};
-use regex;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 use std::io;
 
-fn get_escaped_len(s: &str) -> usize {
-    let r = regex::Regex::new(r#"\\([\\"]|x[a-f0-9]{2})"#).unwrap();
-    r.replace_all(s, "a").len() - 2
-}
-
-fn encode(s: &str) -> usize {
-    s.replace(r"\", r"\\").replace("\"", "\\\"").len() + 2
-}
+type Distances = BTreeMap<(String, String), usize>;
 
 fn parse_line(l: &str) -> Option<(String, String, usize)> {
-    let (a, b) = aoc2015::split_to_tuple2(l, " to ")?;
-    let (c, d) = aoc2015::split_to_tuple2(b, " = ")?;
+    let (a, b) = aoclib::split_to_tuple2(l, " to ")?;
+    let (c, d) = aoclib::split_to_tuple2(b, " = ")?;
+    let [dist] = aoclib::parse_int_array(d, false).ok()?;
 
-    return Some((
-        a.to_owned(),
-        c.to_owned(),
-        aoc2015::read_ints_from_string(d)[0],
-    ));
+    Some((a.to_owned(), c.to_owned(), dist))
 }
 
-fn parse_input<'a>(v: Vec<String>) -> Option<(Vec<String>, BTreeMap<(String, String), usize>)> {
+fn parse_input(v: Vec<String>) -> Option<(Vec<String>, Distances)> {
     let mut distances = BTreeMap::new();
     let mut all_cities = Vec::new();
 
@@ -41,21 +30,9 @@ fn parse_input<'a>(v: Vec<String>) -> Option<(Vec<String>, BTreeMap<(String, Str
     Some((all_cities, distances))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2015::read_stdin_lines()?;
-    let (all_cities, input) = parse_input(input).unwrap();
-
-    let (p1, p2) = find_minmax_route(&input, &all_cities);
-
-    println!("Part 1 {}", p1);
-    println!("Part 2 {}", p2);
-
-    Ok(())
-}
-
-fn find_minmax_route(input: &BTreeMap<(String, String), usize>, all_cities: &Vec<String>) -> (usize, usize) {
+fn find_minmax_route(input: &Distances, all_cities: &[String]) -> (usize, usize) {
     let index_distances = input
-        .into_iter()
+        .iter()
         .map(|((a, b), dist)| {
             (
                 (
@@ -82,3 +59,26 @@ fn find_minmax_route(input: &BTreeMap<(String, String), usize>, all_cities: &Vec
         _ => panic!()
     }
 }
+
+struct Day09;
+
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = (Vec<String>, Distances);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        parse_input(aoclib::read_nonempty_lines(input))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid route"))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        find_minmax_route(&input.1, &input.0).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        find_minmax_route(&input.1, &input.0).1
+    }
+}
+
+aoclib::aoc_main!(Day09);
@@ -1,11 +1,10 @@
 use std::io;
 
-fn part1(inp: &Vec<String>) {
-    let s: u32 = inp.iter()
+fn part1(inp: &[String]) -> u32 {
+    inp.iter()
         .filter_map(|l| l.parse::<u32>().ok())
         .map(|w| w / 3 - 2)
-        .sum();
-    println!("Part 1: {}", s);
+        .sum()
 }
 
 fn full_fuel2(module: u32) -> u32 {
@@ -17,19 +16,31 @@ fn full_fuel2(module: u32) -> u32 {
     } 
 }
 
-fn part2(inp: &Vec<String>) {
-    let s: u32 = inp.iter()
+fn part2(inp: &[String]) -> u32 {
+    inp.iter()
         .filter_map(|l| l.parse::<u32>().ok())
-        .map(|w| full_fuel2(w))
-        .sum();
-    println!("Part 2: {}", s);
+        .map(full_fuel2)
+        .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_lines()?;
+struct Day01;
 
-    part1(&input);
-    part2(&input);
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    Ok(())
-}
\ No newline at end of file
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day01);
@@ -4,8 +4,8 @@ const ADD: usize = 1usize;
 const MUL: usize = 2usize;
 const HALT: usize = 99usize;
 
-fn run(inp: &Vec<usize>, a: usize, b: usize) -> Option<usize> {
-    let mut ins = inp.clone();
+fn run(inp: &[usize], a: usize, b: usize) -> Option<usize> {
+    let mut ins = inp.to_vec();
 
     // Initialize
     ins[1] = a;
@@ -36,32 +36,48 @@ fn run(inp: &Vec<usize>, a: usize, b: usize) -> Option<usize> {
     }
 }
 
-fn part1(inp: &Vec<usize>) -> Option<usize> {
-    run(inp, 12, 2)
+fn part1(inp: &[usize]) -> usize {
+    run(inp, 12, 2).unwrap()
 }
 
-fn part2(inp: &Vec<usize>) -> Option<usize> {
+fn part2(inp: &[usize]) -> usize {
     for i in 0..=99 {
         for j in 0..=99 {
             let r = run(inp, i, j);
             if Some(19690720) == r {
-                return Some(100 * i + j);
+                return 100 * i + j;
             }
         }
     }
 
-    None
+    panic!("No noun and verb produce 19690720")
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string().map(|l| {
-        l.split(",")
-            .map(|i| i.parse::<usize>().expect("Non-numeric input"))
-            .collect::<Vec<usize>>()
-    })?;
+struct Day02;
 
-    println!("Part 1: {:?}", part1(&input));
-    println!("Part 2: {:?}", part2(&input));
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        input
+            .trim()
+            .split(',')
+            .map(|i| {
+                i.parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day02);
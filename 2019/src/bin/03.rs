@@ -1,13 +1,12 @@
-use std::io;
 use std::collections::HashMap;
-use std::iter::repeat;
+use std::io;
 
 fn get_wire_path(wire: &str) -> HashMap<(i32, i32), u32> {
     let mut wire_path: HashMap<(i32, i32), u32> = HashMap::new();
     wire.trim()
-        .split(",")
+        .split(',')
         .map(|p| (p.chars().next().unwrap(), p[1..].parse::<usize>().unwrap()))
-        .flat_map(|(wd, wl)| repeat(wd).take(wl))
+        .flat_map(|(wd, wl)| std::iter::repeat_n(wd, wl))
         .scan(((0i32, 0i32), 0u32), |((x, y), c), m| {
             *c += 1;
             match m {
@@ -20,28 +19,51 @@ fn get_wire_path(wire: &str) -> HashMap<(i32, i32), u32> {
             Some(((*x, *y), *c))
         })
         .for_each(|(k, c)| {
-            if !wire_path.contains_key(&k) {
-                wire_path.insert(k, c);
-            }
+            wire_path.entry(k).or_insert(c);
         });
     wire_path
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_lines()?;
-
-    let wire1_path = get_wire_path(&input[0]);
-    let wire2_path = get_wire_path(&input[1]);
+type WirePaths = (HashMap<(i32, i32), u32>, HashMap<(i32, i32), u32>);
 
-    let part1 = wire1_path.keys()
+fn part1((wire1_path, wire2_path): &WirePaths) -> u32 {
+    wire1_path
+        .keys()
         .filter(|k| wire2_path.contains_key(k))
-        .map(|(x,y)| x.abs()+y.abs())
-        .min().map(|s| s as u32);
-    let part2 = wire1_path.iter()
+        .map(|(x, y)| x.abs() + y.abs())
+        .min()
+        .unwrap() as u32
+}
+
+fn part2((wire1_path, wire2_path): &WirePaths) -> u32 {
+    wire1_path
+        .iter()
         .filter_map(|(k, v)| wire2_path.get(k).map(|v2| *v + *v2))
-        .min();
-    println!("Part 1: {:?}", part1);
-    println!("Part 2: {:?}", part2);
+        .min()
+        .unwrap()
+}
+
+struct Day03;
+
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = WirePaths;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let mut lines = aoclib::nonempty_lines(input);
+        let wire1_path = get_wire_path(lines.next().unwrap_or_default());
+        let wire2_path = get_wire_path(lines.next().unwrap_or_default());
+        Ok((wire1_path, wire2_path))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day03);
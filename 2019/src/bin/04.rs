@@ -28,25 +28,36 @@ fn is_valid(n: u32) -> (bool, bool) {
     (is_increasing(&ns) && has_pair(&ns), is_increasing(&ns) && has_pair_strict(&ns))
 }
 
-fn run(start: u32, end: u32) -> (u32, u32) {
+fn run(&(start, end): &(u32, u32)) -> (u32, u32) {
     (start..=end)
         .map(is_valid)
         .map(|(a, b)| (a as u32, b as u32))
         .fold((0, 0), |(acc_a, acc_b), (a, b)| (acc_a + a, acc_b + b))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+fn parse_input(input: &str) -> (u32, u32) {
+    let mut parsed = input.split('-').filter_map(|s| s.trim().parse::<u32>().ok());
+    (parsed.next().unwrap(), parsed.next().unwrap())
+}
+
+struct Day04;
+
+impl aoclib::solution::Solution for Day04 {
+    type Input<'a> = (u32, u32);
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let mut parsed = input.split("-")
-        .filter_map(|s| s.trim().parse::<u32>().ok());
-    let start = parsed.next().unwrap();
-    let end = parsed.next().unwrap();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    let (part1, part2) = run(start, end);
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        run(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        run(input).1
+    }
+}
 
-    println!("Part 1: {:?}", part1);
-    println!("Part 2: {:?}", part2);
-    
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day04);
@@ -1,19 +1,35 @@
 use std::io;
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string().map(|l| {
-        l.trim().split(",")
-            .map(|i| i.parse::<i64>().expect("Non-number input"))
-            .collect::<Vec<_>>()
-    })?;
-
-    let mut comp1 = aoc2019::IntCodeComputer::new_with_input(&input, vec![1]);
-    comp1.run();
-    println!("Part 1: {:?}", comp1.outputs.back().expect("Empty output"));
-    
-    let mut comp2 = aoc2019::IntCodeComputer::new_with_input(&input, vec![5]);
-    comp2.run();
-    println!("Part 2: {:?}", comp2.outputs);
-
-    Ok(())
-}
\ No newline at end of file
+fn part1(mem: &[i64]) -> i64 {
+    let mut comp = aoc2019::IntCodeComputer::new_with_input(mem, vec![1]);
+    comp.run();
+    *comp.outputs.back().expect("Empty output")
+}
+
+fn part2(mem: &[i64]) -> i64 {
+    let mut comp = aoc2019::IntCodeComputer::new_with_input(mem, vec![5]);
+    comp.run();
+    *comp.outputs.back().expect("Empty output")
+}
+
+struct Day05;
+
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day05);
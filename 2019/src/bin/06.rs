@@ -1,5 +1,5 @@
-use std::io;
 use std::collections::HashMap;
+use std::io;
 
 struct Node<'a> {
     parent_id: Option<&'a str>,
@@ -12,13 +12,13 @@ fn orbit_number(n: &Node, orbits: &HashMap<&str, Node>, h: u32) -> u32 {
         .sum::<u32>()
 }
 
-fn run1(orbits: &HashMap<&str, Node>) -> u32 {
-    orbits.values().filter(|n| n.parent_id == None)
+fn part1(orbits: &HashMap<&str, Node>) -> u32 {
+    orbits.values().filter(|n| n.parent_id.is_none())
         .map(|n| orbit_number(n, orbits, 0))
         .sum()
 }
 
-fn run2(orbits: &HashMap<&str, Node>) -> u32 {
+fn part2(orbits: &HashMap<&str, Node>) -> u32 {
     let start = orbits.get("YOU").unwrap().parent_id.unwrap();
     let end = orbits.get("SAN").unwrap().parent_id.unwrap();
 
@@ -37,12 +37,10 @@ fn run2(orbits: &HashMap<&str, Node>) -> u32 {
     (end_path_pos + start_path.iter().position(|x| *x == lca).unwrap()) as u32
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_lines()?;
-
+fn parse_input(input: &str) -> HashMap<&str, Node<'_>> {
     let mut orbits: HashMap<&str, Node> = HashMap::new();
 
-    input.iter()
+    aoclib::nonempty_lines(input)
         .map(|s| {
             let mut parts = s.split(')');
             (parts.next().unwrap(), parts.next().unwrap())
@@ -60,7 +58,27 @@ fn main() -> io::Result<()> {
             c.parent_id = Some(parent);
         });
 
-    println!("Part 1: {}", run1(&orbits));
-    println!("Part 2: {}", run2(&orbits));
-    Ok(())
-}
\ No newline at end of file
+    orbits
+}
+
+struct Day06;
+
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = HashMap<&'a str, Node<'a>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day06);
@@ -1,7 +1,7 @@
-use std::io;
 use itertools::Itertools;
+use std::io;
 
-fn run(mem: &Vec<i64>, phase_offset: usize) -> i64 {
+fn run(mem: &[i64], phase_offset: usize) -> i64 {
     const AMPS: usize = 5;
     
     let mut max_out = 0;
@@ -32,15 +32,24 @@ fn run(mem: &Vec<i64>, phase_offset: usize) -> i64 {
     max_out
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string().map(|l| {
-        l.split(",")
-            .map(|i| i.trim().parse::<i64>().unwrap())
-            .collect::<Vec<_>>()
-    })?;
+struct Day07;
+
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
 
-    println!("Part 1: {}", run(&input, 0));
-    println!("Part 2: {}", run(&input, 5));
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        run(input, 0)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        run(input, 5)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day07);
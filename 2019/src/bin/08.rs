@@ -1,43 +1,65 @@
-use std::io;
-use std::iter::repeat;
 use itertools::Itertools;
+use std::io;
 
 const W: usize = 25;
 const H: usize = 6;
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+const DIGITS_PER_LAYER: usize = W * H;
 
-    const DIGITS_PER_LAYER: usize = W * H;
-    let layers = input
-        .trim().chars()
-        .chunks(DIGITS_PER_LAYER).into_iter()
+fn parse_layers(input: &str) -> Vec<Vec<char>> {
+    input
+        .trim()
+        .chars()
+        .chunks(DIGITS_PER_LAYER)
+        .into_iter()
         .map(|l| l.collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
+fn part1(layers: &[Vec<char>]) -> usize {
     let min_layer = layers.iter()
         .min_by_key(|l| l.iter().filter(|c| **c == '0').count()).unwrap();
     let min_layer_ones = min_layer.iter().filter(|c| **c == '1').count();
     let min_layer_twos = min_layer.iter().filter(|c| **c == '2').count();
     
-    println!("Part 1: {}", min_layer_ones * min_layer_twos);
+    min_layer_ones * min_layer_twos
+}
 
+fn part2(layers: &[Vec<char>]) -> aoclib::Answer {
     // Flatten the layers
     let rendered = layers.iter()
-        .fold(repeat('2').take(DIGITS_PER_LAYER).collect::<Vec<_>>(), 
+        .fold(std::iter::repeat_n('2', DIGITS_PER_LAYER).collect::<Vec<_>>(), 
             |acc, l| {
                 l.iter().zip(acc).map(|(a, b)| {
                     if b == '2' { *a } else { b }
                 }).collect()
             });
 
-    for row in rendered.chunks(W).into_iter() {
-        println!("{}", row.iter().map(|c| match c {
-            '0' => ' ',
-            '1' => '#',
-            _ => '.',
-        }).collect::<String>());
+    let rows = rendered
+        .chunks(W)
+        .map(|row| row.iter().map(|c| *c == '1').collect())
+        .collect::<Vec<Vec<bool>>>();
+    aoclib::Answer::bitmap(&rows).read_letters()
+}
+
+struct Day08;
+
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = aoclib::Answer;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_layers(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoclib::Answer {
+        part2(input)
     }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day08);
@@ -1,17 +1,29 @@
 use std::io;
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+fn run(mem: &[i64], input: i64) -> i64 {
+    let mut c = aoc2019::IntCodeComputer::new_with_input(mem, vec![input]);
+    c.run();
+    *c.outputs.back().expect("Empty output")
+}
 
-    let mem = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+struct Day09;
 
-    let mut c = aoc2019::IntCodeComputer::new_with_input(&mem, vec![1]);
-    c.run();
-    println!("{:?}", c.outputs);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
 
-    let mut c = aoc2019::IntCodeComputer::new_with_input(&mem, vec![2]);
-    c.run();
-    println!("{:?}", c.outputs);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        run(input, 1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        run(input, 2)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day09);
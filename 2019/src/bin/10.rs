@@ -41,13 +41,13 @@ fn angle(cx: usize, cy: usize, tx: usize, ty: usize) -> f32 {
     let dx = tx as i32 - cx as i32;
     let dy = cy as i32 - ty as i32;
 
-    let angle = (-(dy as f32).atan2(dx as f32) + f32::consts::FRAC_PI_2 + (2.0 * f32::consts::PI)) % (2.0*f32::consts::PI);
     
-    angle
+    
+    (-(dy as f32).atan2(dx as f32) + f32::consts::FRAC_PI_2 + (2.0 * f32::consts::PI)) % (2.0*f32::consts::PI)
 }
 
 
-fn seen_asteroids(x: usize, y: usize, map: &Vec<Vec<char>>) -> Vec<(usize, usize, f32)> {
+fn seen_asteroids(x: usize, y: usize, map: &[Vec<char>]) -> Vec<(usize, usize, f32)> {
     let mut r = 1usize;
     let mut seen_roids = Vec::new();
 
@@ -67,7 +67,7 @@ fn seen_asteroids(x: usize, y: usize, map: &Vec<Vec<char>>) -> Vec<(usize, usize
     seen_roids
 }
 
-fn run1(map: &Vec<Vec<char>>) -> (usize, (usize, usize)) {
+fn run1(map: &[Vec<char>]) -> (usize, (usize, usize)) {
 
     let mut top = 0usize;
     let mut top_coord = (0usize, 0usize);
@@ -75,7 +75,7 @@ fn run1(map: &Vec<Vec<char>>) -> (usize, (usize, usize)) {
     for y in 0..map.len() {
         for x in 0..map[y].len() {
             if map[y][x] == '#' {
-                let asts = seen_asteroids(x, y, &map).len();
+                let asts = seen_asteroids(x, y, map).len();
                 if asts > top {
                     top = asts;
                     top_coord = (x, y);
@@ -87,7 +87,7 @@ fn run1(map: &Vec<Vec<char>>) -> (usize, (usize, usize)) {
     (top, top_coord)
 }
 
-fn part2(x: usize, y: usize, map: &Vec<Vec<char>>) -> (usize, usize, f32) {
+fn vaporized_200th(x: usize, y: usize, map: &[Vec<char>]) -> (usize, usize, f32) {
     let mut seen = seen_asteroids(x, y, map);
 
     seen.sort_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap());
@@ -95,18 +95,34 @@ fn part2(x: usize, y: usize, map: &Vec<Vec<char>>) -> (usize, usize, f32) {
     seen[199]
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_lines()?;
+fn part1(map: &[Vec<char>]) -> usize {
+    run1(map).0
+}
+
+fn part2(map: &[Vec<char>]) -> usize {
+    let (_, (best_x, best_y)) = run1(map);
+    let (x, y, _) = vaporized_200th(best_x, best_y, map);
+    x * 100 + y
+}
+
+struct Day10;
 
-    let map = input.into_iter()
-        .map(|l| l.chars().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let (part1, (best_x, best_y)) = run1(&map);
-    println!("{:?}", part1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::nonempty_lines(input).map(|l| l.chars().collect()).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let (part2_x, part2_y, _) = part2(best_x, best_y, &map);
-    println!("{}", part2_x * 100 + part2_y);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day10);
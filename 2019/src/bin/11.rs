@@ -1,18 +1,16 @@
-use std::io;
-use std::collections::HashMap;
 use num_complex::Complex;
+use std::collections::HashMap;
+use std::io;
 
-extern crate num_complex;
-
-fn run(mem: &Vec<i64>, init: i64, visualize: bool) {
-    let mut c = aoc2019::IntCodeComputer::new(&mem);
+fn run(mem: &[i64], init: i64) -> HashMap<(i32, i32), i64> {
+    let mut c = aoc2019::IntCodeComputer::new(mem);
     let mut x = 0i32;
     let mut y = 0i32;
     let mut dir = Complex::new(0, 1);
     let mut panels: HashMap<(i32, i32), i64> = HashMap::new();
     panels.insert((x, y), init);
     while c.run() {
-        while c.outputs.len() > 0 {
+        while !c.outputs.is_empty() {
             let new_c = c.outputs.pop_front().unwrap();
             let turn = c.outputs.pop_front().unwrap();
 
@@ -26,34 +24,48 @@ fn run(mem: &Vec<i64>, init: i64, visualize: bool) {
         c.inputs.push_back(*color);
     }
 
-    println!("{:?}", panels.len());
-
-    if visualize {
-        let min_x = *panels.keys().map(|(x, _)| x).min().unwrap();
-        let max_x = *panels.keys().map(|(x, _)| x).max().unwrap();
-        let min_y = *panels.keys().map(|(_, y)| y).min().unwrap();
-        let max_y = *panels.keys().map(|(_, y)| y).max().unwrap();
-
-        for yy in min_y..=max_y {
-            for xx in min_x..=max_x {
-                if panels.get(&(xx, yy)).unwrap_or(&0) == &1 {
-                    print!("#");
-                } else {
-                    print!(" ");
-                }
-            }
-            println!();
-        }
-    }
+    panels
+}
+
+fn part1(mem: &[i64]) -> usize {
+    run(mem, 0).len()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+fn part2(mem: &[i64]) -> aoclib::Answer {
+    let panels = run(mem, 1);
+    let min_x = *panels.keys().map(|(x, _)| x).min().unwrap();
+    let max_x = *panels.keys().map(|(x, _)| x).max().unwrap();
+    let min_y = *panels.keys().map(|(_, y)| y).min().unwrap();
+    let max_y = *panels.keys().map(|(_, y)| y).max().unwrap();
 
-    let mem = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    let rows = (min_y..=max_y)
+        .map(|yy| {
+            (min_x..=max_x)
+                .map(|xx| panels.get(&(xx, yy)).unwrap_or(&0) == &1)
+                .collect()
+        })
+        .collect::<Vec<Vec<bool>>>();
+    aoclib::Answer::bitmap(&rows).read_letters()
+}
+
+struct Day11;
+
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = aoclib::Answer;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
 
-    run(&mem, 0, false);
-    run(&mem, 1, true);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoclib::Answer {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day11);
@@ -1,9 +1,8 @@
-use std::io;
-use regex;
-use itertools::Itertools;
-use std::fmt::Debug;
 use aoc2019::OrderingExt;
+use itertools::Itertools;
 use num_integer::lcm;
+use std::fmt::Debug;
+use std::io;
 
 #[derive(PartialEq)]
 #[derive(Clone)]
@@ -56,12 +55,12 @@ fn parse_moon(s: &str) -> Moon {
     let k = captures.next().and_then(|c| c[0].parse::<i32>().ok()).unwrap();
 
     Moon {
-        position: Vector3 { i: i, j: j, k: k },
+        position: Vector3 { i, j, k },
         velocity: Vector3 { i: 0, j: 0, k: 0 }
     }
 }
 
-fn run1(moons: &Vec<Moon>) -> i32 {
+fn part1(moons: &[Moon]) -> i32 {
     let mut ms: Vec<Moon> = moons.to_vec();
 
     let steps = 1000;
@@ -73,7 +72,7 @@ fn run1(moons: &Vec<Moon>) -> i32 {
     ms.iter().map(|m| m.energy()).sum()
 }
 
-fn step(moons: &mut Vec<Moon>) {
+fn step(moons: &mut [Moon]) {
     for (ai, bi) in (0..moons.len()).tuple_combinations() {
         let a = &moons[ai];
         let b = &moons[bi];
@@ -92,7 +91,7 @@ fn step(moons: &mut Vec<Moon>) {
     }
 }
 
-fn find_dimen_loop<F: Fn(&Vector3)->i32>(moons: &Vec<Moon>, get_dim: F) -> usize {
+fn find_dimen_loop<F: Fn(&Vector3)->i32>(moons: &[Moon], get_dim: F) -> usize {
     let mut search = moons.to_vec();
 
     for i in 1.. {
@@ -107,7 +106,7 @@ fn find_dimen_loop<F: Fn(&Vector3)->i32>(moons: &Vec<Moon>, get_dim: F) -> usize
     unreachable!();
 }
 
-fn run2(moons: &Vec<Moon>) -> usize {
+fn part2(moons: &[Moon]) -> usize {
     let loop_i = find_dimen_loop(moons, |v| v.i);
     let loop_j = find_dimen_loop(moons, |v| v.j);
     let loop_k = find_dimen_loop(moons, |v| v.k);
@@ -115,15 +114,24 @@ fn run2(moons: &Vec<Moon>) -> usize {
     lcm(lcm(loop_i, loop_j), loop_k)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_lines()?;
+struct Day12;
 
-    let moons = input.into_iter()
-        .map(|s| parse_moon(&s))
-        .collect::<Vec<_>>();
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = Vec<Moon>;
+    type Part1 = i32;
+    type Part2 = usize;
 
-    println!("Part 1: {:?}", run1(&moons));
-    println!("Part 2: {:?}", run2(&moons));
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::nonempty_lines(input).map(parse_moon).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day12);
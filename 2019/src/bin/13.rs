@@ -1,9 +1,9 @@
-use std::io;
+use aoc2019::OrderingExt;
 use itertools::Itertools;
 use std::collections::HashMap;
-use aoc2019::OrderingExt;
+use std::io;
 
-fn part1(mem: &Vec<i64>) -> Option<usize> {
+fn part1(mem: &[i64]) -> usize {
     let mut comp = aoc2019::IntCodeComputer::new(mem);
 
     comp.run();
@@ -14,13 +14,13 @@ fn part1(mem: &Vec<i64>) -> Option<usize> {
         framebuf.insert((*x, *y), *id);
     }
 
-    Some(framebuf.values()
+    framebuf.values()
         .filter(|v| **v == 2)
-        .count())
+        .count()
 }
 
 
-fn part2(mem: &Vec<i64>) -> Option<i64> {
+fn part2(mem: &[i64]) -> i64 {
     let mut mem = mem.to_vec();
     mem[0] = 2;
 
@@ -50,16 +50,27 @@ fn part2(mem: &Vec<i64>) -> Option<i64> {
         }
     }
 
-    Some(score)
+    score
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+struct Day13;
+
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = i64;
 
-    let mem = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    println!("Part 1: {:?}", part1(&mem));
-    println!("Part 2: {:?}", part2(&mem));
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day13);
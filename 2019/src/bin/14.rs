@@ -1,8 +1,7 @@
-use std::collections::VecDeque;
+use fraction::{GenericFraction, ToPrimitive};
 use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
 use std::io;
-use std::collections::HashMap;
-use fraction::{ToPrimitive, GenericFraction};
 
 struct Recipe<'a> {
     target: &'a str,
@@ -10,10 +9,10 @@ struct Recipe<'a> {
     ings: HashMap<&'a str, u32>,
 }
 
-fn parse_recipe(l: &str) -> Option<Recipe> {
+fn parse_recipe(l: &str) -> Option<Recipe<'_>> {
     let (ings, target) = l.split(" => ").tuples().next()?;
 
-    let mut target_iter = target.trim().split(" ");
+    let mut target_iter = target.trim().split(' ');
     let target_amt = target_iter.next().and_then(|s| s.parse().ok())?;
     let target = target_iter.next()?;
 
@@ -25,9 +24,9 @@ fn parse_recipe(l: &str) -> Option<Recipe> {
         .collect::<HashMap<&str, u32>>();
     
     Some(Recipe {
-        target: target,
-        target_amt: target_amt,
-        ings: ings
+        target,
+        target_amt,
+        ings,
     })
 }
 
@@ -43,11 +42,11 @@ fn run1(recipes: &HashMap<&str, Recipe>, order: &VecDeque<&str>, fuel: u32) -> u
 
         let r = recipes.get(key).unwrap();
         let amt: GenericFraction<u128> =
-            (*amounts.get(key).unwrap() / r.target_amt.into()).ceil() * r.target_amt.into();
+            (*amounts.get(key).unwrap() / GenericFraction::<u128>::from(r.target_amt)).ceil() * GenericFraction::<u128>::from(r.target_amt);
 
         for (ing, ing_amt) in r.ings.iter() {
             let ing_amt: GenericFraction<u128> = (*ing_amt).into();
-            let new_amt: GenericFraction<u128> = ing_amt * amt / r.target_amt.into();
+            let new_amt: GenericFraction<u128> = ing_amt * amt / GenericFraction::<u128>::from(r.target_amt);
             *amounts.entry(ing).or_insert(0.into()) += new_amt;
         }
     }
@@ -71,7 +70,7 @@ fn run2(recipes: &HashMap<&str, Recipe>, order: &VecDeque<&str>) -> u32 {
 
         for (ing, ing_amt) in r.ings.iter() {
             let ing_amt: GenericFraction<u128> = (*ing_amt).into();
-            let new_amt: GenericFraction<u128> = ing_amt * (amt / r.target_amt.into());
+            let new_amt: GenericFraction<u128> = ing_amt * (amt / GenericFraction::<u128>::from(r.target_amt));
             *amounts.entry(ing).or_insert(0.into()) += new_amt;
         }
     }
@@ -79,7 +78,7 @@ fn run2(recipes: &HashMap<&str, Recipe>, order: &VecDeque<&str>) -> u32 {
     let total_ore: GenericFraction<u128> = 1000000000000u128.into();
     let mut perfect_max = (total_ore / amounts.remove("ORE").unwrap()).floor().to_u32().unwrap();
 
-    while run1(recipes, order, perfect_max) as u64 > 1000000000000u64 {
+    while run1(recipes, order, perfect_max) > 1000000000000u64 {
         perfect_max -= 1;
     }
 
@@ -102,7 +101,7 @@ fn top_sort<'a>(recipes: &HashMap<&'a str, Recipe<'a>>) -> VecDeque<&'a str> {
             }
         }
         sorted.push_front(k);
-    };
+    }
 
     for k in recipes.keys() {
         visit(k, &mut sorted, recipes);
@@ -111,19 +110,36 @@ fn top_sort<'a>(recipes: &HashMap<&'a str, Recipe<'a>>) -> VecDeque<&'a str> {
     sorted
 }
 
-fn main() -> io::Result<()> {
-
-    let input = aoc2019::read_stdin_lines()?;
+type Reactions<'a> = (HashMap<&'a str, Recipe<'a>>, VecDeque<&'a str>);
 
-    let recipes = input.iter()
-        .map(|l| parse_recipe(&l).unwrap())
-        .map(|r| (r.target, r))
-        .collect::<HashMap<&str, Recipe>>();
+fn parse_input(input: &str) -> Option<Reactions<'_>> {
+    let recipes = aoclib::nonempty_lines(input)
+        .map(|l| parse_recipe(l).map(|r| (r.target, r)))
+        .collect::<Option<HashMap<&str, Recipe>>>()?;
 
     let order = top_sort(&recipes);
+    Some((recipes, order))
+}
 
-    println!("{:?}", run1(&recipes, &order, 1));
-    println!("{:?}", run2(&recipes, &order));
+struct Day14;
+
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = Reactions<'a>;
+    type Part1 = u64;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        parse_input(input)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid reaction"))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        run1(&input.0, &input.1, 1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        run2(&input.0, &input.1)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day14);
@@ -1,29 +1,16 @@
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io;
-use std::collections::{VecDeque, HashMap, HashSet};
 
 const NORTH: i64 = 1;
 const SOUTH: i64 = 2;
 const WEST: i64 = 3;
 const EAST: i64 = 4;
 
-fn print_map(map: &HashMap<(i32, i32), char>) {
-    let min_y = *map.keys().map(|(_, y)| y).min().unwrap();
-    let max_y = *map.keys().map(|(_, y)| y).max().unwrap();
-    let min_x = *map.keys().map(|(x, _)| x).min().unwrap();
-    let max_x = *map.keys().map(|(x, _)| x).max().unwrap();
-    for y in min_y..=max_y {
-        for x in min_x..=max_x {
-            print!("{}", map.get(&(x, y)).cloned().unwrap_or(' '));
-        }
-        println!();
-    }
-}
-
 fn find_ox_spread_time(map: &HashMap<(i32, i32), char>) -> usize {
     let mut q = VecDeque::new();
     let mut v = HashSet::new();
     let mut max_d = 0;
-    let (&ox_coords, _) = map.iter().filter(|(_, v)| **v == 'X').next().unwrap();
+    let (&ox_coords, _) = map.iter().find(|(_, v)| **v == 'X').unwrap();
     q.push_back((0, ox_coords));
 
     while let Some((d, (x, y))) = q.pop_front() {
@@ -34,32 +21,28 @@ fn find_ox_spread_time(map: &HashMap<(i32, i32), char>) -> usize {
 
         max_d = d.max(max_d);
 
-        if !v.contains(&(x-1, y)) {
-            if *map.get(&(x-1, y)).unwrap_or(&'#') != '#' {
+        if !v.contains(&(x-1, y))
+            && *map.get(&(x-1, y)).unwrap_or(&'#') != '#' {
                 q.push_back((d+1, (x-1, y)));
             }
-        }
-        if !v.contains(&(x+1, y)) {
-            if *map.get(&(x+1, y)).unwrap_or(&'#') != '#' {
+        if !v.contains(&(x+1, y))
+            && *map.get(&(x+1, y)).unwrap_or(&'#') != '#' {
                 q.push_back((d+1, (x+1, y)));
             }
-        }
-        if !v.contains(&(x, y-1)) {
-            if *map.get(&(x, y-1)).unwrap_or(&'#') != '#' {
+        if !v.contains(&(x, y-1))
+            && *map.get(&(x, y-1)).unwrap_or(&'#') != '#' {
                 q.push_back((d+1, (x, y-1)));
             }
-        }
-        if !v.contains(&(x, y+1)) {
-            if *map.get(&(x, y+1)).unwrap_or(&'#') != '#' {
+        if !v.contains(&(x, y+1))
+            && *map.get(&(x, y+1)).unwrap_or(&'#') != '#' {
                 q.push_back((d+1, (x, y+1)));
             }
-        }
     }
 
     max_d
 }
 
-fn run(mem: &Vec<i64>) -> (usize, usize) {
+fn run(mem: &[i64]) -> (usize, usize) {
     let mut cmp = aoc2019::IntCodeComputer::new(mem);
     let mut map = HashMap::new();
     // DFS
@@ -113,20 +96,29 @@ fn run(mem: &Vec<i64>) -> (usize, usize) {
         .or(visit(&mut cmp, &mut map, 1, 0, EAST, 1))
         .or(visit(&mut cmp, &mut map, 0, -1, NORTH, 1))
         .or(visit(&mut cmp, &mut map, 0, 1, SOUTH, 1));
-    print_map(&map);
 
     let ox_spread_time = find_ox_spread_time(&map);
     (found.expect("no generator distance"), ox_spread_time)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+struct Day15;
+
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let mem = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
 
-    let (part1, part2) = run(&mem);
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input).1
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day15);
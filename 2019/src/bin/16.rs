@@ -1,20 +1,18 @@
-use std::io;
 use itertools::Itertools;
-use std::iter::repeat;
-use num_integer::lcm;
+use std::io;
 use std::num::Wrapping;
 
 fn pattern<'a>(i: &'a usize) -> impl Iterator<Item=i8> + 'a {
     let base = vec![0i8, 1, 0, -1];
-    let p = base.into_iter()
-        .flat_map(move |x| repeat(x).take(*i))
-        .cycle()
-        .skip(1);
+    
 
-    p
+    base.into_iter()
+        .flat_map(move |x| std::iter::repeat_n(x, *i))
+        .cycle()
+        .skip(1)
 }
 
-fn run1(input: &Vec<i8>) -> Vec<i8> {
+fn run1(input: &[i8]) -> Vec<i8> {
 
     let mut last_round = input.to_vec();
     for _ in 0..100 {
@@ -35,7 +33,7 @@ fn run1(input: &Vec<i8>) -> Vec<i8> {
 }
 
 
-fn run2(input: &Vec<i8>, offset: usize) -> Vec<i32> {
+fn run2(input: &[i8], offset: usize) -> Vec<i32> {
     let mut last_round = input.iter().cycle().take(10000*input.len()).skip(offset).copied().map(|x| x as i32).collect::<Vec<i32>>();
 
     // when the offset is greater than (input.len() / 2), the following is true:
@@ -59,32 +57,41 @@ fn run2(input: &Vec<i8>, offset: usize) -> Vec<i32> {
     last_round
 }
 
-fn main() -> io::Result<()> {
+fn part1(input: &[i8]) -> String {
+    let p1 = run1(input);
+    p1.iter().map(|n| n.to_string()).take(8).join("")
+}
 
-    let input = aoc2019::read_stdin_to_string()?;
-    let input = input.trim()
-        .bytes()
-        .map(|c| (c - b'0') as i8)
-        .collect::<Vec<_>>();
+fn part2(input: &[i8]) -> String {
+    let offset = input.iter().take(7).map(|n| n.to_string()).join("").parse::<usize>().unwrap();
 
-    let p1 = run1(&input);
-    println!("Part 1: {}", p1.iter().map(|n| n.to_string()).take(8).join(""));
+    let p2_total_size = 10000 * input.len();
+    if offset < p2_total_size / 2 {
+        panic!("Offset {} is in the first half of the signal", offset);
+    }
 
+    let p2 = run2(input, offset);
+    p2.iter().take(8).map(|n| n.to_string()).join("")
+}
 
-    //let p2 = run2(&input);
-    let offset = input.iter().take(7).map(|n| n.to_string()).join("").parse::<usize>().unwrap();
-    println!("Part 2 offset: {}", offset);
+struct Day16;
 
-    let p2_total_size = 10000 * input.len();
-    println!("Part 2 total size: {}", p2_total_size);
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = Vec<i8>;
+    type Part1 = String;
+    type Part2 = String;
 
-    if offset < p2_total_size / 2 {
-        panic!();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.trim().bytes().map(|c| (c - b'0') as i8).collect())
     }
 
-    let p2 = run2(&input, offset);
+    fn part1(input: &Self::Input<'_>) -> String {
+        part1(input)
+    }
 
-    println!("Part 2 result: {}", p2.iter().take(8).map(|n| n.to_string()).join(""));
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day16);
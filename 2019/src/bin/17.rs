@@ -1,5 +1,5 @@
-use std::io;
 use itertools::Itertools;
+use std::io;
 
 #[derive(Copy)]
 #[derive(Clone)]
@@ -8,15 +8,6 @@ enum Dir {
     Up, Down, Left, Right,
 }
 
-fn turn_left(dir: Dir) -> Dir {
-    match dir {
-        Dir::Up => Dir::Left,
-        Dir::Left => Dir::Down,
-        Dir::Down => Dir::Right,
-        Dir::Right => Dir::Up,
-    }
-}
-
 fn turn_right(dir: Dir) -> Dir {
     match dir {
         Dir::Up => Dir::Right,
@@ -35,15 +26,14 @@ fn next_coord(x: usize, y: usize, w: usize, h: usize, dir: Dir) -> Option<(usize
     }
 }
 
-fn run1(mem: &Vec<i64>) {
+/// Returns the alignment checksum and the full scaffold route, which the
+/// movement functions in `part2` were compressed from by hand.
+fn run1(mem: &[i64]) -> (usize, Vec<String>) {
     let mut cmp = aoc2019::IntCodeComputer::new(mem);
     cmp.run();
 
     let output_str = cmp.outputs.iter().map(|b| *b as u8 as char).collect::<String>();
-    let output = output_str.trim().split("\n").collect::<Vec<_>>();
-    for l in &output {
-        println!("{}", l);
-    }
+    let output = output_str.trim().split('\n').collect::<Vec<_>>();
 
     let (w, h) = (output[0].len(), output.len());
     let mut checksum = 0;
@@ -59,8 +49,6 @@ fn run1(mem: &Vec<i64>) {
         }
     }
 
-    println!("{}", checksum);
-
     let mut dir = Dir::Up;
     let (mut x, mut y) = start_point;
     let mut d = 0;
@@ -91,10 +79,10 @@ fn run1(mem: &Vec<i64>) {
             dir = turn_right(dir);
         }
     }
-    println!("{:?}", instructions);
+    (checksum, instructions)
 }
 
-fn run2(mem: &Vec<i64>) {
+fn part2(mem: &[i64]) -> i64 {
     let mut mem = mem.to_vec();
     mem[0] = 2;
     
@@ -111,17 +99,28 @@ fn run2(mem: &Vec<i64>) {
     }
 
     comp.run();
-    println!("{:?}", comp.outputs.back());
+    *comp.outputs.back().expect("No output")
 }
 
 
-fn main() -> io::Result<()> {
-    let input = aoc2019::read_stdin_to_string()?;
+struct Day17;
+
+impl aoclib::solution::Solution for Day17 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = i64;
 
-    let mem: Vec<i64> = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
 
-    run1(&mem);
-    run2(&mem);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run1(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day17);
@@ -1,9 +1,10 @@
-use std::io;
-use std::collections::{BTreeSet, BTreeMap, HashMap, VecDeque, HashSet, BinaryHeap};
-use std::cmp::{Ordering, Reverse};
 use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::io;
 
-fn run1(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, usize)>) {
+fn part1((map, start, keys): &Vault) -> usize {
+    let start = *start;
     let all_keys = keys.iter()
         .filter(|(c, _, _)| c.is_lowercase())
         .map(|(c, _, _)| *c)
@@ -28,8 +29,7 @@ fn run1(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
             collected_keys.insert(curr);
 
             if collected_keys.len() >= all_keys.len() {
-                println!("Part 1: {}", d);
-                break;
+                return d;
             }
         }
 
@@ -64,10 +64,15 @@ fn run1(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
             }
         }
     }
-    
+
+    panic!("Not all keys are reachable")
 }
 
-fn advance(map: &Vec<Vec<char>>, collected_keys: &BTreeSet<char>, all_keys: &Vec<char>, all_doors: &Vec<char>, x: usize, y: usize, d: usize, came_from: (usize, usize)) -> Option<Vec<((usize, usize), usize, BTreeSet<char>)>> {
+/// A key reachable from a robot: its position, distance and the keys held.
+type Reachable = ((usize, usize), usize, BTreeSet<char>);
+
+#[allow(clippy::too_many_arguments)]
+fn advance(map: &[Vec<char>], collected_keys: &BTreeSet<char>, all_keys: &[char], all_doors: &[char], x: usize, y: usize, d: usize, came_from: (usize, usize)) -> Option<Vec<Reachable>> {
     let curr = map[y][x];
     if curr == '#' || (all_doors.contains(&curr) && !collected_keys.contains(&curr.to_lowercase().next().unwrap())) {
         return None;
@@ -76,7 +81,7 @@ fn advance(map: &Vec<Vec<char>>, collected_keys: &BTreeSet<char>, all_keys: &Vec
     if all_keys.contains(&curr) && !collected_keys.contains(&curr) {
         let mut collected_keys = collected_keys.clone();
         collected_keys.insert(curr);
-        return Some(vec![((x, y), d, collected_keys)]);
+        Some(vec![((x, y), d, collected_keys)])
     } else {
         let mut new_states = vec![];
 
@@ -104,7 +109,7 @@ fn advance(map: &Vec<Vec<char>>, collected_keys: &BTreeSet<char>, all_keys: &Vec
             }
         }
 
-        return Some(new_states)
+        Some(new_states)
     }
 }
 
@@ -128,7 +133,8 @@ impl PartialOrd for QueueItem {
 }
 
 
-fn run2(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, usize)>) {
+fn part2((map, start, keys): &Vault) -> usize {
+    let start = *start;
     let all_keys = keys.iter()
         .filter(|(c, _, _)| c.is_lowercase())
         .map(|(c, _, _)| *c)
@@ -137,7 +143,7 @@ fn run2(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
         .filter(|(c, _, _)| c.is_uppercase())
         .map(|(c, _, _)| *c)
         .collect::<Vec<_>>();
-    let mut map = map.clone();
+    let mut map = map.to_vec();
 
     map[start.1][start.0] = '#';
     map[start.1-1][start.0] = '#';
@@ -156,7 +162,7 @@ fn run2(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
     ];
     queue.push_back(QueueItem(start_coords, 0, BTreeSet::new(), (BTreeSet::new(), 0)));
 
-    while let Some(QueueItem(coords, d, collected_keys, prev)) = queue.pop_front() {
+    while let Some(QueueItem(coords, d, collected_keys, _)) = queue.pop_front() {
         if let Some(min_d) = visited_states.get(&(collected_keys.clone(), coords.clone())) {
             if *min_d <= d {
                 continue;
@@ -165,8 +171,7 @@ fn run2(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
         visited_states.insert((collected_keys.clone(), coords.clone()), d);
 
         if collected_keys.len() >= all_keys.len() {
-            println!("Part 2: {}", d);
-            return;
+            return d;
         }
 
         for q in 0..4 {
@@ -195,10 +200,11 @@ fn run2(map: &Vec<Vec<char>>, start: (usize, usize), keys: &Vec<(char, usize, us
         qv.sort_by_key(|qi| qi.1);
         queue = VecDeque::from(qv);
     }
+
+    panic!("Not all keys are reachable")
 }
 
-fn fill_map(map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let mut map = map.clone();
+fn fill_map(mut map: Vec<Vec<char>>) -> Vec<Vec<char>> {
 
     let mut any_found = true;
     while any_found {
@@ -227,16 +233,13 @@ fn fill_map(map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
         }
     }
 
-    for l in &map {
-        println!("{}", l.iter().collect::<String>());
-    }
-
     map
 }
 
-fn main() -> io::Result<()> {
-    let input_lines = aoc2019::read_stdin_lines()?;
-    let map = input_lines.into_iter().map(
+type Vault = (Vec<Vec<char>>, (usize, usize), Vec<(char, usize, usize)>);
+
+fn parse_input(input: &str) -> Vault {
+    let map = aoclib::nonempty_lines(input).map(
         |l| l.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
 
     let start = map.iter().enumerate().filter_map(|(y, l)| l.iter().position(|c| *c == '@').map(|x| (x,y))).next().expect("No start point found");
@@ -246,10 +249,29 @@ fn main() -> io::Result<()> {
             .map(move |(x, c)| (c, x, y)))
         .collect::<Vec<_>>();
 
-    let map = fill_map(&map);
+    let map = fill_map(map);
+
+    (map, start, keys)
+}
+
+struct Day18;
+
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = Vault;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    run1(&map, start, &keys);
-    run2(&map, start, &keys);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day18);
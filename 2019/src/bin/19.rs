@@ -1,8 +1,8 @@
-use std::io;
-use aoc2019::{IntCodeComputer, read_stdin_to_string};
+use aoc2019::IntCodeComputer;
 use itertools::Itertools;
+use std::io;
 
-fn check_coord(x: usize, y: usize, mem: &Vec<i64>) -> bool {
+fn check_coord(x: usize, y: usize, mem: &[i64]) -> bool {
     let mut cmp = IntCodeComputer::new_with_input(mem, vec![x as i64, y as i64]);
     cmp.run();
     let r = *cmp.outputs.front().expect("No output!");
@@ -10,27 +10,14 @@ fn check_coord(x: usize, y: usize, mem: &Vec<i64>) -> bool {
     r == 1
 }
 
-fn run1(mem: &Vec<i64>) {
-    let mut s = 0;
-
-    let mut pic: [[char; 50]; 50] = [[' '; 50]; 50];
-
-    for (x, y) in (0usize..50).cartesian_product(0usize..50) {
-        let r = check_coord(x, y, mem);
-        pic[y][x] = if r { '#' } else { '.' };
-        if r {
-            s += 1;
-        }
-    }
-
-    for l in 0..50 {
-        println!("{}", &pic[l].iter().collect::<String>());
-    }
-
-    println!("Part 1: {}", s);
+fn part1(mem: &[i64]) -> usize {
+    (0usize..50)
+        .cartesian_product(0usize..50)
+        .filter(|&(x, y)| check_coord(x, y, mem))
+        .count()
 }
 
-fn row_last_x(row: usize, prev_row_last: usize, mem: &Vec<i64>) -> usize {
+fn row_last_x(row: usize, prev_row_last: usize, mem: &[i64]) -> usize {
     let mut last = prev_row_last;
     while !check_coord(last, row, mem) {
         last += 1;
@@ -42,7 +29,7 @@ fn row_last_x(row: usize, prev_row_last: usize, mem: &Vec<i64>) -> usize {
     last - 1
 }
 
-fn run2(mem: &Vec<i64>) {
+fn part2(mem: &[i64]) -> usize {
     let mut row = 3;
     let mut row_last = 0;
     while row_last < 100 {
@@ -52,8 +39,7 @@ fn run2(mem: &Vec<i64>) {
 
     loop {
         if check_coord(row_last - 99, row, mem) && check_coord(row_last - 99, row + 99, mem) && check_coord(row_last, row+99, mem) {
-            println!("{}", (row_last - 99) * 10000  + row);
-            break;
+            return (row_last - 99) * 10000 + row;
         }
 
         row += 1;
@@ -61,13 +47,24 @@ fn run2(mem: &Vec<i64>) {
     }
 }
 
-fn main() -> io::Result<()>  {
-    let input = read_stdin_to_string()?;
+struct Day19;
+
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let mem: Vec<i64> = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
 
-    run1(&mem);
-    run2(&mem);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day19);
@@ -1,12 +1,14 @@
-use std::io;
-use aoc2019::{read_stdin_lines};
-use std::collections::{VecDeque, BTreeMap,BTreeSet};
 use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::io;
 
-fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, usize), (usize, usize)>, (usize, usize), (usize, usize)) {
-    let hole_x_start = (&input[input.len() / 2][2..]).chars().enumerate()
+type Maze = Vec<Vec<Option<bool>>>;
+type Warps = BTreeMap<(usize, usize), (usize, usize)>;
+
+fn parse_maze(input: Vec<String>) -> (Maze, Warps, (usize, usize), (usize, usize)) {
+    let hole_x_start = input[input.len() / 2][2..].chars().enumerate()
         .skip_while(|(_, c)| *c == '.' || *c == '#').map(|(i, _)| i).next().unwrap();
-    let hole_x_end = (&input[input.len() / 2][2..]).chars().enumerate()
+    let hole_x_end = input[input.len() / 2][2..].chars().enumerate()
         .skip_while(|(_, c)| *c == '.' || *c == '#')
         .skip_while(|(_, c)| *c != '.' && *c != '#')
         .map(|(i, _)| i).next().unwrap() - 1;
@@ -24,7 +26,7 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
 
     let maze = input[2..(input.len()-2)].iter().enumerate()
         .map(|(row, l)| {
-            (&l[2..(l.len()-2)]).chars().enumerate().map(|(col, c)| {
+            l[2..(l.len()-2)].chars().enumerate().map(|(col, c)| {
                 if row >= hole_y_start && row <= hole_y_end && col >= hole_x_start && col <= hole_x_end {
                     None
                 } else {
@@ -45,7 +47,7 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
         } else if &l[..2] == "ZZ" {
             end = (0, i);
         } else if &l[..2] != "  " {
-            warps.insert((&l[..2]).to_owned(), (0, i));
+            warps.insert(l[..2].to_owned(), (0, i));
         }
 
         let e = l.len() - 2;
@@ -54,7 +56,7 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
         } else if &l[e..] == "ZZ" {
             end = (e-3, i);
         } else if &l[e..] != "  " {
-            warps.insert((&l[e..]).to_owned(), (e-3, i));
+            warps.insert(l[e..].to_owned(), (e-3, i));
         }
     }
 
@@ -88,14 +90,14 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
     for (i, l) in input.iter().skip(2+hole_y_start).take(hole_y_end-hole_y_start+1).enumerate() {
         if &l[1+hole_x_start..=1+hole_x_start] == "." {
             let name = &l[2+hole_x_start..hole_x_start+4];
-            let pair = warps.remove(name).expect(&format!("No name match found for {}", &name));
+            let pair = warps.remove(name).unwrap_or_else(|| panic!("No name match found for {}", &name));
             warp_pairs.insert(pair, (hole_x_start-1, i+hole_y_start));
             warp_pairs.insert((hole_x_start-1, i+hole_y_start), pair);
         }
 
         if &l[hole_x_end+3..=hole_x_end+3] == "." {
             let name = &l[hole_x_end+1..hole_x_end+3];
-            let pair = warps.remove(name).expect(&format!("No name match found for {}", &name));
+            let pair = warps.remove(name).unwrap_or_else(|| panic!("No name match found for {}", &name));
             warp_pairs.insert(pair, (hole_x_end+1, i+hole_y_start));
             warp_pairs.insert((hole_x_end+1, i+hole_y_start), pair);
         }
@@ -105,7 +107,7 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
     for (i, c) in l.chars().skip(2).enumerate().skip(hole_x_start).take(hole_x_end-hole_x_start+1) {
         if c == '.' {
             let name = [input[hole_y_start+2].chars().nth(i+2).unwrap(), input[hole_y_start+3].chars().nth(i+2).unwrap()].iter().collect::<String>();
-            let pair = warps.remove(&name).expect(&format!("No name match found for {}", &name));
+            let pair = warps.remove(&name).unwrap_or_else(|| panic!("No name match found for {}", &name));
 
             warp_pairs.insert(pair, (i, hole_x_start-1));
             warp_pairs.insert((i, hole_x_start-1), pair);
@@ -115,7 +117,7 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
     for (i, c) in l.chars().skip(2).enumerate().skip(hole_x_start).take(hole_x_end-hole_x_start+1) {
         if c == '.' {
             let name = [input[hole_y_end+1].chars().nth(i+2).unwrap(), input[hole_y_end+2].chars().nth(i+2).unwrap()].iter().collect::<String>();
-            let pair = warps.remove(&name).expect(&format!("No name match found for {}", &name));
+            let pair = warps.remove(&name).unwrap_or_else(|| panic!("No name match found for {}", &name));
 
             warp_pairs.insert(pair, (i, hole_y_end+1));
             warp_pairs.insert((i, hole_y_end+1), pair);
@@ -125,13 +127,13 @@ fn parse_maze(input: Vec<String>) -> (Vec<Vec<Option<bool>>>, BTreeMap<(usize, u
     (maze, warp_pairs, start, end)
 }
 
-fn fill_maze(mut maze: Vec<Vec<Option<bool>>>) -> Vec<Vec<Option<bool>>> {
+fn fill_maze(mut maze: Maze) -> Maze {
     let mut any_filled = true;
     while any_filled {
         any_filled = false;
         for (y, x) in (1..maze.len()-1).cartesian_product(1..maze[0].len()-1) {
             let mut c = 0;
-            if maze[y][x] == None || maze[y][x] == Some(true) {
+            if maze[y][x].is_none() || maze[y][x] == Some(true) {
                 continue;
             }
             if maze[y][x-1] == Some(true) {
@@ -157,7 +159,8 @@ fn fill_maze(mut maze: Vec<Vec<Option<bool>>>) -> Vec<Vec<Option<bool>>> {
     maze
 }
 
-fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize, usize)>, start: (usize, usize), end: (usize, usize)) {
+fn part1((maze, warps, start, end): &Donut) -> usize {
+    let (start, end) = (*start, *end);
     let mut visited = BTreeSet::new();
     let mut queue = VecDeque::new();
 
@@ -170,13 +173,12 @@ fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
         }
 
         if (x, y) == end {
-            println!("Part 1: {}", d);
-            return;
+            return d;
         }
 
         visited.insert((x, y));
 
-        let coord = if (x == 0 || maze[y][x-1] == None) {
+        let coord = if x == 0 || maze[y][x-1].is_none() {
             if (x, y) != start {
                 Some(*warps.get(&(x, y)).unwrap())
             } else { None }
@@ -191,7 +193,7 @@ fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (x == w-1 || maze[y][x+1] == None) {
+        let coord = if x == w-1 || maze[y][x+1].is_none() {
             if (x, y) != start {
                 Some(*warps.get(&(x, y)).unwrap())
             } else { None }
@@ -206,7 +208,7 @@ fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (y == 0 || maze[y-1][x] == None) {
+        let coord = if y == 0 || maze[y-1][x].is_none() {
             if (x, y) != start {
                 Some(*warps.get(&(x, y)).unwrap())
             } else { None }
@@ -221,7 +223,7 @@ fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (y == h-1 || maze[y+1][x] == None) {
+        let coord = if y == h-1 || maze[y+1][x].is_none() {
             if (x, y) != start {
                 Some(*warps.get(&(x, y)).unwrap())
             } else { None }
@@ -236,9 +238,12 @@ fn run1(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
     }
+
+    panic!("No route to the exit")
 }
 
-fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize, usize)>, start: (usize, usize), end: (usize, usize)) {
+fn part2((maze, warps, start, end): &Donut) -> usize {
+    let (start, end) = (*start, *end);
     let mut visited = BTreeSet::new();
     let mut queue = VecDeque::new();
 
@@ -251,13 +256,12 @@ fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
         }
 
         if (x, y) == end && level == 0 {
-            println!("Part 2: {}", d);
-            return;
+            return d;
         }
 
         visited.insert((x, y, level));
 
-        let coord = if x == 0 || maze[y][x-1] == None {
+        let coord = if x == 0 || maze[y][x-1].is_none() {
             warps.get(&(x, y)).copied().map(|(wx, wy)| (wx, wy, if x == 0 { level - 1 } else { level + 1 }))
         } else if let Some(false) = maze[y][x-1] {
             Some((x-1, y, level))
@@ -270,7 +274,7 @@ fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (x == w-1 || maze[y][x+1] == None) {
+        let coord = if x == w-1 || maze[y][x+1].is_none() {
             warps.get(&(x, y)).copied().map(|(wx, wy)| (wx, wy, if x == w-1 { level - 1 } else { level + 1 }))
         } else if let Some(false) = maze[y][x+1] {
             Some((x+1, y, level))
@@ -283,7 +287,7 @@ fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (y == 0 || maze[y-1][x] == None) {
+        let coord = if y == 0 || maze[y-1][x].is_none() {
             warps.get(&(x, y)).copied().map(|(wx, wy)| (wx, wy, if y == 0 { level - 1 } else { level + 1 }))
         } else if let Some(false) = maze[y-1][x] {
             Some((x, y-1, level))
@@ -296,7 +300,7 @@ fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
 
-        let coord = if (y == h-1 || maze[y+1][x] == None) {
+        let coord = if y == h-1 || maze[y+1][x].is_none() {
             warps.get(&(x, y)).copied().map(|(wx, wy)| (wx, wy, if y == h-1 { level - 1 } else { level + 1 }))
         } else if let Some(false) = maze[y+1][x] {
             Some((x, y+1, level))
@@ -309,18 +313,40 @@ fn run2(maze: &Vec<Vec<Option<bool>>>, warps: &BTreeMap<(usize, usize), (usize,
             }
         }
     }
+
+    panic!("No route to the exit")
 }
 
-fn main() -> io::Result<()> {
-    let input = read_stdin_lines()?;
+type Donut = (Maze, Warps, (usize, usize), (usize, usize));
+
+fn parse_input(input: &str) -> Donut {
+    let lines = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.to_owned())
+        .collect();
+    let (maze, warps, start, end) = parse_maze(lines);
+    (fill_maze(maze), warps, start, end)
+}
 
-    let (maze, warps, start, end) = parse_maze(input);
-    let maze = fill_maze(maze);
+struct Day20;
 
-    println!("{:?}", &warps);
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = Donut;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    run1(&maze, &warps, start, end);
-    run2(&maze, &warps, start, end);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day20);
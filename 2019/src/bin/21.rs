@@ -1,41 +1,29 @@
+use aoc2019::IntCodeComputer;
 use std::io;
-use aoc2019::{read_stdin_to_string, IntCodeComputer};
 
 
-fn run1(mem: &Vec<i64>) {
-
+/// Runs the springscript program and returns the hull damage it reports.
+fn run(mem: &[i64], program: &str) -> i64 {
     let mut comp = IntCodeComputer::new(mem);
     comp.run();
+    comp.outputs.clear();
 
-    while let Some(c) = comp.outputs.pop_front() {
-        print!("{}", (c as u8) as char);
-    }
-
-    let input = "NOT A J\nNOT B T\nOR T J\nNOT C T\nOR T J\nAND D J\nWALK\n";
-
-    input.bytes().map(|b| b as i64).for_each(|b| comp.inputs.push_back(b));
+    program.bytes().map(|b| b as i64).for_each(|b| comp.inputs.push_back(b));
 
     comp.run();
 
-    while let Some(c) = comp.outputs.pop_front() {
-        if c > 127 || c < 0 {
-            println!("Part 1: {}", c);
-            return;
-        }
-        print!("{}", (c as u8) as char);
-    }
+    comp.outputs
+        .into_iter()
+        .find(|c| !(0..=127).contains(c))
+        .expect("The springdroid fell into space")
 }
 
-fn run2(mem: &Vec<i64>) {
-
-    let mut comp = IntCodeComputer::new(mem);
-    comp.run();
-
-    while let Some(c) = comp.outputs.pop_front() {
-        print!("{}", (c as u8) as char);
-    }
+fn part1(mem: &[i64]) -> i64 {
+    run(mem, "NOT A J\nNOT B T\nOR T J\nNOT C T\nOR T J\nAND D J\nWALK\n")
+}
 
-    let input = "NOT A J
+fn part2(mem: &[i64]) -> i64 {
+    let program = "NOT A J
 NOT B T
 OR T J
 NOT C T
@@ -50,27 +38,27 @@ OR H T
 AND T J
 RUN\n";
 
-    input.bytes().map(|b| b as i64).for_each(|b| comp.inputs.push_back(b));
-
-    comp.run();
-
-    while let Some(c) = comp.outputs.pop_front() {
-        if c > 127 || c < 0 {
-            println!("Part 2: {}", c);
-            return;
-        }
-        print!("{}", (c as u8) as char);
-    }
+    run(mem, program)
 }
 
+struct Day21;
 
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
 
-fn main() -> io::Result<()> {
-    let input = read_stdin_to_string()?;
-    let mem: Vec<i64> = input.trim().split(",").map(|i| i.parse().unwrap()).collect();
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input)
+    }
 
-    run1(&mem);
-    run2(&mem);
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day21);
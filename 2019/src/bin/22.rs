@@ -1,7 +1,6 @@
-use std::{io, convert::TryInto};
-use std::collections::VecDeque;
-use aoc2019::read_stdin_lines;
 use num_bigint::{BigInt, ToBigInt};
+use std::collections::VecDeque;
+use std::io;
 
 #[derive(Clone)]
 #[derive(Copy)]
@@ -29,7 +28,7 @@ impl Op {
                 if *offset > 0 {
                     deck.rotate_left(*offset as usize);
                 } else {
-                    deck.rotate_right(offset.abs() as usize);
+                    deck.rotate_right(offset.unsigned_abs() as usize);
                 }
                 deck
             },
@@ -46,18 +45,17 @@ impl Op {
     }
 }
 
-fn run1(ops: &Vec<Op>) {
+fn part1(ops: &[Op]) -> usize {
     let mut deck = (0..10007).collect::<VecDeque<_>>();
 
     for op in ops {
         deck = op.run(deck);
     }
     
-    let pos = deck.iter().position(|c| *c == 2019).unwrap();
-    println!("Part 1: {}", pos);
+    deck.iter().position(|c| *c == 2019).unwrap()
 }
 
-fn run2(ops: &Vec<Op>) -> i128 {
+fn part2(ops: &[Op]) -> i128 {
     // This is directly adopted from reddit comments
 
     let deck_size = 119315717514047i128;
@@ -70,7 +68,7 @@ fn run2(ops: &Vec<Op>) -> i128 {
     for op in ops {
         match op {
             Op::Reverse => {
-                incr = incr * -1;
+                incr *= -1;
                 first = (first + incr) % deck_size;
             },
             Op::Cut(x) => {
@@ -123,9 +121,7 @@ fn run2(ops: &Vec<Op>) -> i128 {
     let incr = incr.modpow(&iters, &deck_size);
     let incr: i128 = incr.try_into().unwrap();
 
-    let res = ((first + 2020 * incr) % deck_size).try_into().unwrap();
-    println!("Part 2: {}", res);
-    res
+    ((first + 2020 * incr) % deck_size).try_into().unwrap()
 }
 
 fn parse_ops(input: Vec<String>) -> Vec<Op> {
@@ -145,14 +141,24 @@ fn parse_ops(input: Vec<String>) -> Vec<Op> {
     }).collect()
 }
 
-fn main() -> io::Result<()> {
+struct Day22;
 
-    let input = read_stdin_lines()?;
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = Vec<Op>;
+    type Part1 = usize;
+    type Part2 = i128;
 
-    let ops = parse_ops(input);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_ops(aoclib::read_nonempty_lines(input)))
+    }
 
-    run1(&ops);
-    run2(&ops);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i128 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day22);
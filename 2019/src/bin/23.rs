@@ -1,18 +1,15 @@
-
+use aoc2019::IntCodeComputer;
+use std::collections::VecDeque;
 use std::io;
-use std::collections::{BTreeMap, VecDeque};
-
-use aoc2019::{read_stdin_to_string, IntCodeComputer};
-
 
-fn run1(mem: &Vec<i64>) -> i64 {
+fn part1(mem: &[i64]) -> i64 {
 
     let mut comps: Vec<IntCodeComputer> = (0..50)
         .map(|i| IntCodeComputer::new_with_input(mem, vec![i]))
         .collect();
 
     let mut packet_queues = Vec::with_capacity(50);
-    packet_queues.resize_with(50, || VecDeque::new());
+    packet_queues.resize_with(50, VecDeque::new);
 
     loop {
         for (i, c) in comps.iter_mut().enumerate() {
@@ -41,14 +38,14 @@ fn run1(mem: &Vec<i64>) -> i64 {
     }
 }
 
-fn run2(mem: &Vec<i64>) -> i64 {
+fn part2(mem: &[i64]) -> i64 {
 
     let mut comps: Vec<IntCodeComputer> = (0..50)
         .map(|i| IntCodeComputer::new_with_input(mem, vec![i]))
         .collect();
 
     let mut packet_queues = Vec::with_capacity(50);
-    packet_queues.resize_with(50, || VecDeque::new());
+    packet_queues.resize_with(50, VecDeque::new);
 
     let mut nat_packet = None;
     let mut last_nat_y = None;
@@ -102,21 +99,28 @@ fn run2(mem: &Vec<i64>) -> i64 {
         }
     }
 
-    0
+    unreachable!()
 }
 
-fn main() -> io::Result<()> {
+struct Day23;
 
-    let input = read_stdin_to_string()?;
-    let mem: Vec<i64> = input.trim()
-        .split(",")
-        .map(|i| i.parse().unwrap())
-        .collect();
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoc2019::parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input)
+    }
 
-    let res1 = run1(&mem);
-    println!("Part 1: {}", res1);
-    let res2 = run2(&mem);
-    println!("Part 2: {}", res2);
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-} 
\ No newline at end of file
+aoclib::aoc_main!(Day23);
+ 
@@ -1,13 +1,11 @@
-use aoc2019::read_stdin_lines;
-use std::io;
-use std::vec::Vec;
 use std::collections::HashSet;
+use std::io;
 
-fn part1(map: Vec<bool>, w: usize, h: usize) -> u32 {
+fn part1(&(ref map, w, h): &Eris) -> u32 {
 
     let mut seen = HashSet::new();
 
-    let mut prev = map;
+    let mut prev = map.clone();
 
     while !seen.contains(&prev) {
         let next = next(&prev, w, h);
@@ -21,13 +19,13 @@ fn part1(map: Vec<bool>, w: usize, h: usize) -> u32 {
         .sum()
 }
 
-fn part2(map: Vec<bool>, w: usize, h: usize) -> u32 {
+fn part2(&(ref map, w, h): &Eris) -> u32 {
 
     let mut prev = Vec::new();
     let mut min_level = 102;
     let mut max_level = 102;
-    prev.resize_with(204, || Vec::new());
-    prev[102] = map;
+    prev.resize_with(204, Vec::new);
+    prev[102] = map.clone();
     
 
     for _ in 0..200 {
@@ -43,7 +41,7 @@ fn part2(map: Vec<bool>, w: usize, h: usize) -> u32 {
         .sum()
 }
 
-fn next(prev: &Vec<bool>, w: usize, h: usize) -> Vec<bool> {
+fn next(prev: &[bool], w: usize, h: usize) -> Vec<bool> {
     let mut next = Vec::with_capacity(prev.len());
 
     for (idx, cell) in prev.iter().enumerate() {
@@ -60,9 +58,9 @@ fn next(prev: &Vec<bool>, w: usize, h: usize) -> Vec<bool> {
     next
 }
 
-fn next2(prev: &Vec<Vec<bool>>, w: usize, h: usize, min_level: usize, max_level: usize) -> (Vec<Vec<bool>>, usize, usize) {
+fn next2(prev: &[Vec<bool>], w: usize, h: usize, min_level: usize, max_level: usize) -> (Vec<Vec<bool>>, usize, usize) {
     let mut next = Vec::new();
-    next.resize_with(prev.len(), || Vec::new());
+    next.resize_with(prev.len(), Vec::new);
 
     for l in min_level..=max_level {
         for (idx, cell) in prev[l].iter().enumerate() {
@@ -128,7 +126,7 @@ fn next2(prev: &Vec<Vec<bool>>, w: usize, h: usize, min_level: usize, max_level:
 fn adjacent_indices(idx: usize, w: usize, h: usize) -> [Option<usize>; 4] {
     let up = if idx > w-1 { Some(idx - w) } else { None };
     let down = if idx < (h-1) * w { Some(idx + w) } else { None };
-    let left = if (idx % w) > 0 { Some(idx - 1) } else { None };
+    let left = if !idx.is_multiple_of(w) { Some(idx - 1) } else { None };
     let right = if (idx % w) < (w-1) { Some(idx + 1) } else { None };
 
     [up, down, left, right]
@@ -197,10 +195,12 @@ fn adjacent_indices2(coord: (usize, usize),  w: usize, h: usize) -> Vec<(usize,
     res
 }
 
-fn main() -> io::Result<()> {
-    let input_lines = read_stdin_lines()?;
+type Eris = (Vec<bool>, usize, usize);
+
+fn parse_input(input: &str) -> Eris {
+    let input_lines = aoclib::nonempty_lines(input).collect::<Vec<_>>();
     let (w, h) = (input_lines.first().unwrap().trim().len(), input_lines.len());
-    let input = input_lines
+    let map = input_lines
         .iter()
         .flat_map(|l| {
             l.chars()
@@ -212,41 +212,66 @@ fn main() -> io::Result<()> {
         })
         .collect::<Vec<bool>>();
 
-    println!("Part 1: {}", part1(input.clone(), w, h));
-    println!("Part 2: {}", part2(input, w, h));
+    (map, w, h)
+}
+
+struct Day24;
+
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = Eris;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day24);
 
-    Ok(())
+aoclib::example_tests! {
+    Day24;
+    example1: include_str!("inputs/24.example1.txt"), part1 = 2129920;
 }
 
+
 #[test]
 fn test_adjacent_indices() {
     let res0 = adjacent_indices(0, 5, 5);
-    assert_eq!(2, res0.iter().filter(|&&x| x == None).count());
-    assert!(res0.iter().any(|&x| x == Some(1)));
-    assert!(res0.iter().any(|&x| x == Some(5)));
+    assert_eq!(2, res0.iter().filter(|&&x| x.is_none()).count());
+    assert!(res0.contains(&Some(1)));
+    assert!(res0.contains(&Some(5)));
 
     let res5 = adjacent_indices(5, 5, 5);
-    assert_eq!(1, res5.iter().filter(|&&x| x == None).count());
-    assert!(res5.iter().any(|&x| x == Some(6)));
-    assert!(res5.iter().any(|&x| x == Some(0)));
-    assert!(res5.iter().any(|&x| x == Some(10)));
+    assert_eq!(1, res5.iter().filter(|&&x| x.is_none()).count());
+    assert!(res5.contains(&Some(6)));
+    assert!(res5.contains(&Some(0)));
+    assert!(res5.contains(&Some(10)));
 
     let res4 = adjacent_indices(4, 5, 5);
-    assert_eq!(2, res4.iter().filter(|&&x| x == None).count());
-    assert!(res4.iter().any(|&x| x == Some(3)));
-    assert!(res4.iter().any(|&x| x == Some(9)));
+    assert_eq!(2, res4.iter().filter(|&&x| x.is_none()).count());
+    assert!(res4.contains(&Some(3)));
+    assert!(res4.contains(&Some(9)));
 
     let res24 = adjacent_indices(24, 5, 5);
-    assert_eq!(2, res24.iter().filter(|&&x| x == None).count());
-    assert!(res24.iter().any(|&x| x == Some(23)));
-    assert!(res24.iter().any(|&x| x == Some(19)));
+    assert_eq!(2, res24.iter().filter(|&&x| x.is_none()).count());
+    assert!(res24.contains(&Some(23)));
+    assert!(res24.contains(&Some(19)));
 
     let res12 = adjacent_indices(12, 5, 5);
-    assert_eq!(0, res12.iter().filter(|&&x| x == None).count());
-    assert!(res12.iter().any(|&x| x == Some(11)));
-    assert!(res12.iter().any(|&x| x == Some(13)));
-    assert!(res12.iter().any(|&x| x == Some(7)));
-    assert!(res12.iter().any(|&x| x == Some(17)));
+    assert_eq!(0, res12.iter().filter(|&&x| x.is_none()).count());
+    assert!(res12.contains(&Some(11)));
+    assert!(res12.contains(&Some(13)));
+    assert!(res12.contains(&Some(7)));
+    assert!(res12.contains(&Some(17)));
 }
 
 #[test]
@@ -304,4 +329,4 @@ fn test_adjacent_indices2() {
     assert!(res13.contains(&(2, 14)));
     assert!(res13.contains(&(2, 19)));
     assert!(res13.contains(&(2, 24)));
-}
\ No newline at end of file
+}
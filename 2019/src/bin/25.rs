@@ -1,9 +1,10 @@
-use aoc2019::{IntCodeComputer};
-use std::io::{Result, stdin, BufRead};
-use std::fs;
-use std::env::args;
+//! Day 25 is a text adventure: the droid is steered by typing commands on
+//! stdin, so the program is read from the input file instead.
 
-fn part1(mem: &Vec<i64>) {
+use aoc2019::IntCodeComputer;
+use std::io::{self, stdin, BufRead};
+
+fn part1(mem: &[i64]) {
     let mut comp = IntCodeComputer::new(mem);
 
     while comp.run() {
@@ -18,16 +19,12 @@ fn part1(mem: &Vec<i64>) {
     println!("{}", comp.outputs.drain(..).map(|x| x as u8 as char).collect::<String>());
 }
 
+fn main() -> io::Result<()> {
+    let path = aoclib::get_input_filename()?
+        .unwrap_or_else(|| aoclib::get_test_input_file!(25).to_owned());
+    let mem = aoc2019::parse_program(&std::fs::read_to_string(path)?)?;
 
-fn main() -> Result<()> {
-    let path = args().skip(1).next().unwrap();
-    let input = fs::read_to_string(path)?;
-    let mem: Vec<i64> = input.trim()
-        .split(",")
-        .map(|i| i.parse().unwrap())
-        .collect();
-    
     part1(&mem);
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,23 +1,126 @@
-use std::io::{Read, self};
-use std::iter::Iterator;
-use std::collections::VecDeque;
+//! Advent of Code 2019 solutions, registered for the `aoc` runner, and the
+//! Intcode computer shared by many of the days.
+
+// Lets the day modules refer to the Intcode computer as `aoc2019::...` both
+// when built as binaries and when included in this library.
+extern crate self as aoc2019;
+
+use aoclib::solution::Entry;
 use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::io;
 
-pub fn read_stdin_to_string() -> io::Result<String> {
-    let mut buffer = String::new();
-    io::stdin().read_to_string(&mut buffer)?;
-    Ok(buffer.to_owned())
-}
+#[path = "bin/01.rs"]
+pub mod day01;
 
-pub fn read_stdin_lines() -> io::Result<Vec<String>> {
-    let input = read_stdin_to_string()?;
-    Ok(input.lines().filter_map(|l| {
-        if l.is_empty() {
-            None
-        } else {
-            Some(l.to_owned())
-        }
-    }).collect())
+#[path = "bin/02.rs"]
+pub mod day02;
+
+#[path = "bin/03.rs"]
+pub mod day03;
+
+#[path = "bin/04.rs"]
+pub mod day04;
+
+#[path = "bin/05.rs"]
+pub mod day05;
+
+#[path = "bin/06.rs"]
+pub mod day06;
+
+#[path = "bin/07.rs"]
+pub mod day07;
+
+#[path = "bin/08.rs"]
+pub mod day08;
+
+#[path = "bin/09.rs"]
+pub mod day09;
+
+#[path = "bin/10.rs"]
+pub mod day10;
+
+#[path = "bin/11.rs"]
+pub mod day11;
+
+#[path = "bin/12.rs"]
+pub mod day12;
+
+#[path = "bin/13.rs"]
+pub mod day13;
+
+#[path = "bin/14.rs"]
+pub mod day14;
+
+#[path = "bin/15.rs"]
+pub mod day15;
+
+#[path = "bin/16.rs"]
+pub mod day16;
+
+#[path = "bin/17.rs"]
+pub mod day17;
+
+#[path = "bin/18.rs"]
+pub mod day18;
+
+#[path = "bin/19.rs"]
+pub mod day19;
+
+#[path = "bin/20.rs"]
+pub mod day20;
+
+#[path = "bin/21.rs"]
+pub mod day21;
+
+#[path = "bin/22.rs"]
+pub mod day22;
+
+#[path = "bin/23.rs"]
+pub mod day23;
+
+#[path = "bin/24.rs"]
+pub mod day24;
+
+// Day 25 is an interactive text adventure, played by hand with its binary.
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2019, 1, day01::SOLUTION),
+    Entry::new(2019, 2, day02::SOLUTION),
+    Entry::new(2019, 3, day03::SOLUTION),
+    Entry::new(2019, 4, day04::SOLUTION),
+    Entry::new(2019, 5, day05::SOLUTION),
+    Entry::new(2019, 6, day06::SOLUTION),
+    Entry::new(2019, 7, day07::SOLUTION),
+    Entry::new(2019, 8, day08::SOLUTION),
+    Entry::new(2019, 9, day09::SOLUTION),
+    Entry::new(2019, 10, day10::SOLUTION),
+    Entry::new(2019, 11, day11::SOLUTION),
+    Entry::new(2019, 12, day12::SOLUTION),
+    Entry::new(2019, 13, day13::SOLUTION),
+    Entry::new(2019, 14, day14::SOLUTION),
+    Entry::new(2019, 15, day15::SOLUTION),
+    Entry::new(2019, 16, day16::SOLUTION),
+    Entry::new(2019, 17, day17::SOLUTION),
+    Entry::new(2019, 18, day18::SOLUTION),
+    Entry::new(2019, 19, day19::SOLUTION),
+    Entry::new(2019, 20, day20::SOLUTION),
+    Entry::new(2019, 21, day21::SOLUTION),
+    Entry::new(2019, 22, day22::SOLUTION),
+    Entry::new(2019, 23, day23::SOLUTION),
+    Entry::new(2019, 24, day24::SOLUTION),
+];
+
+/// Parses a comma-separated Intcode program.
+pub fn parse_program(input: &str) -> io::Result<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|i| {
+            i.trim()
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
 }
 
 const ADD: i64 = 1;
@@ -60,13 +163,13 @@ pub struct IntCodeComputer {
 }
 
 impl IntCodeComputer {
-    pub fn new(mem: &Vec<i64>) -> IntCodeComputer {
+    pub fn new(mem: &[i64]) -> IntCodeComputer {
         IntCodeComputer::new_with_input(mem, vec![])
     }
 
-    pub fn new_with_input(mem: &Vec<i64>, initial_inputs: Vec<i64>) -> IntCodeComputer {
+    pub fn new_with_input(mem: &[i64], initial_inputs: Vec<i64>) -> IntCodeComputer {
         let mut c = IntCodeComputer {
-            mem: mem.clone(),
+            mem: mem.to_vec(),
             ip: 0,
             inputs: VecDeque::from(initial_inputs),
             outputs: VecDeque::new(),
@@ -80,13 +183,13 @@ impl IntCodeComputer {
         loop {
             match self.get_instruction() {
                 Operation::Add(a, b, dest) => {
-                    let dest = self.get_addr(dest) as usize;
-                    self.mem[dest as usize] = self.get_val(a) + self.get_val(b);
+                    let dest = self.get_addr(dest);
+                    self.mem[dest] = self.get_val(a) + self.get_val(b);
                     self.ip += 4;
                 },
                 Operation::Mul(a, b, dest) => {
-                    let dest = self.get_addr(dest) as usize;
-                    self.mem[dest as usize] = self.get_val(a) * self.get_val(b);
+                    let dest = self.get_addr(dest);
+                    self.mem[dest] = self.get_val(a) * self.get_val(b);
                     self.ip += 4;
                 },
                 Operation::Halt => return false,
@@ -97,20 +200,20 @@ impl IntCodeComputer {
                 Operation::Input(dest) => {
                     if let Some(i) = self.inputs.pop_front() {
                         self.ip += 2;
-                        let dest = self.get_addr(dest) as usize;
-                        self.mem[dest as usize] = i;
+                        let dest = self.get_addr(dest);
+                        self.mem[dest] = i;
                     } else {
                         return true;
                     }
                 },
                 Operation::Equal(a, b, dest) => {
-                    let dest = self.get_addr(dest) as usize;
-                    self.mem[dest as usize] = if self.get_val(a) == self.get_val(b) { 1 } else { 0 };
+                    let dest = self.get_addr(dest);
+                    self.mem[dest] = if self.get_val(a) == self.get_val(b) { 1 } else { 0 };
                     self.ip += 4;
                 },
                 Operation::LessThan(a, b, dest) => {
-                    let dest = self.get_addr(dest) as usize;
-                    self.mem[dest as usize] = if self.get_val(a) < self.get_val(b) { 1 } else { 0 };
+                    let dest = self.get_addr(dest);
+                    self.mem[dest] = if self.get_val(a) < self.get_val(b) { 1 } else { 0 };
                     self.ip += 4;
                 },
                 Operation::JumpIfFalse(a, d) => {
@@ -209,4 +312,4 @@ impl OrderingExt for Ordering {
             _ => 0
         }
     }
-}
\ No newline at end of file
+}
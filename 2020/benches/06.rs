@@ -9,7 +9,7 @@ pub fn part2(input: &str) -> usize {
         .map(|g| {
             g.lines()
                 .map(|l| l.chars().collect::<BTreeSet<_>>())
-                .fold1(|acc, x| acc.intersection(&x).copied().collect::<BTreeSet<_>>())
+                .reduce(|acc, x| acc.intersection(&x).copied().collect::<BTreeSet<_>>())
                 .map_or(0, |s| s.len())
         })
         .sum()
@@ -23,7 +23,7 @@ pub fn part2_vec(input: &str) -> usize {
         .map(|g| {
             g.lines()
                 .map(|l| l.chars().collect::<Vec<_>>())
-                .fold1(|acc, x|
+                .reduce(|acc, x|
                     acc.into_iter().filter(|c| x.contains(c)).collect::<Vec<_>>())
                 .map_or(0, |s| s.len())
         })
@@ -37,7 +37,7 @@ pub fn part2_vec_mut(input: &str) -> usize {
         .map(|g| {
             g.lines()
                 .map(|l| l.chars().collect_vec())
-                .fold1(|mut acc, x| {
+                .reduce(|mut acc, x| {
                     acc.retain(|c| x.contains(c)); acc
                 })
                 .map_or(0, |s| s.len())
@@ -45,7 +45,8 @@ pub fn part2_vec_mut(input: &str) -> usize {
         .sum()
 }
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
 
 
 pub fn criterion_benchmark(c: &mut Criterion) {
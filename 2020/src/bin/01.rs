@@ -1,6 +1,6 @@
 use std::io;
 
-fn part1(input: &Vec<i32>) -> i32 {
+fn part1(input: &[i32]) -> i32 {
     // let (x, y) = input.iter().tuple_combinations()
     //     .filter(|(&x, &y)| x + y == 2020)
     //     .next().unwrap();
@@ -16,7 +16,7 @@ fn part1(input: &Vec<i32>) -> i32 {
     unreachable!()
 }
 
-fn part2(input: &Vec<i32>) -> i32 {
+fn part2(input: &[i32]) -> i32 {
     for i in 0..input.len() {
         for j in 0..input.len() {
             if input[i] + input[j] > 2020 {
@@ -32,14 +32,24 @@ fn part2(input: &Vec<i32>) -> i32 {
     unreachable!()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_ints_from_stdin()?;
+struct Day01;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day01);
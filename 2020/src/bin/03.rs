@@ -1,13 +1,13 @@
 use std::io;
 
-fn part1(input: &Vec<String>) -> Option<usize> {
+fn part1(input: &[String]) -> Option<usize> {
     trees(input, 3, 1)
 }
 
-fn trees(input: &Vec<String>, dx: usize, dy: usize) -> Option<usize> {
+fn trees(input: &[String], dx: usize, dy: usize) -> Option<usize> {
     let mut x = dx;
     let mut trees = 0;
-    let w = input.get(0)?.len();
+    let w = input.first()?.len();
 
     for y in (dy..input.len()).step_by(dy) {
         if input[y].chars().nth(x)? == '#' {
@@ -20,7 +20,7 @@ fn trees(input: &Vec<String>, dx: usize, dy: usize) -> Option<usize> {
     Some(trees)
 }
 
-fn part2(input: &Vec<String>) -> Option<usize> {
+fn part2(input: &[String]) -> Option<usize> {
     let res = trees(input, 1, 1)?
         * trees(input, 3, 1)?
         * trees(input, 5, 1)?
@@ -30,14 +30,24 @@ fn part2(input: &Vec<String>) -> Option<usize> {
     Some(res)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
+struct Day03;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1.unwrap());
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2.unwrap());
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input).unwrap()
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input).unwrap()
+    }
 }
+
+aoclib::aoc_main!(Day03);
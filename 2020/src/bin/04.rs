@@ -36,7 +36,7 @@ fn validate2(p: &str) -> bool {
     let mut remaining = PFIELDS.clone();
 
     for part in parts {
-        let (left, right) = aoc2020::split_to_tuple2(part, ":").unwrap();
+        let (left, right) = aoclib::split_to_tuple2(part, ":").unwrap();
         assert!(remaining.remove(left));
 
         let res = if left == "byr" {
@@ -46,15 +46,10 @@ fn validate2(p: &str) -> bool {
         } else if left == "eyr" {
             validate_num(right, 2020, 2030)
         } else if left == "hgt" {
-            let n: i32 = aoc2020::read_ints_from_string(right)[0];
-            if right.ends_with("cm") && (n < 150 || n > 193) {
-                false
-            } else if right.ends_with("in") && (n < 59 || n > 76) {
-                false
-            } else if !(right.ends_with("cm") || right.ends_with("in")) {
-                false
-            } else {
-                true
+            match aoclib::parse_int_array(right, false) {
+                Ok([n]) if right.ends_with("cm") => (150..=193).contains(&n),
+                Ok([n]) if right.ends_with("in") => (59..=76).contains(&n),
+                _ => false,
             }
         } else if left == "hcl" {
             lazy_static! {
@@ -82,26 +77,33 @@ fn validate2(p: &str) -> bool {
 
     if remaining.len() > 1 {
         false
-    } else if remaining.len() == 1 && *remaining.iter().next().unwrap() != "cid" {
-        false
     } else {
-        true
+        !(remaining.len() == 1 && *remaining.iter().next().unwrap() != "cid")
     }
 }
 
-fn run(input: &Vec<&str>, validator: fn(&str) -> bool) -> usize {
-    input.into_iter().filter(|&&s| validator(s)).count()
+fn run(input: &[&str], validator: fn(&str) -> bool) -> usize {
+    input.iter().filter(|&&s| validator(s)).count()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
-    let passports = input.split("\n\n").collect();
+struct Day04;
+
+impl aoclib::solution::Solution for Day04 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = run(&passports, validate1);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.split("\n\n").collect())
+    }
 
-    let p2 = run(&passports, validate2);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, validate1)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, validate2)
+    }
 }
+
+aoclib::aoc_main!(Day04);
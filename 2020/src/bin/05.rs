@@ -8,11 +8,11 @@ fn seat_id2(s: &str) -> usize {
     })
 }
 
-fn part1(input: &Vec<String>) -> usize {
+fn part1(input: &[String]) -> usize {
     input.iter().map(|s| seat_id2(s)).max().unwrap()
 }
 
-fn part2(input: &Vec<String>) -> usize {
+fn part2(input: &[String]) -> usize {
     let seats = input.iter().map(|s| seat_id2(s)).collect::<BTreeSet<_>>();
 
     seats
@@ -24,14 +24,24 @@ fn part2(input: &Vec<String>) -> usize {
         .unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
+struct Day05;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day05);
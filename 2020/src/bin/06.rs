@@ -1,21 +1,20 @@
 use itertools::Itertools;
 use std::io;
 
-fn part1(input: &str) -> usize {
-    input
-        .split("\n\n")
+fn part1(groups: &[&str]) -> usize {
+    groups
+        .iter()
         .map(|g| g.chars().filter(|c| c.is_alphabetic()).unique().count())
         .sum()
 }
 
-fn part2(input: &str) -> usize {
-    let groups = input.split("\n\n");
-
+fn part2(groups: &[&str]) -> usize {
     groups
+        .iter()
         .map(|g| {
             g.lines()
                 .map(|l| l.chars().collect_vec())
-                .fold1(|acc, x| {
+                .reduce(|acc, x| {
                     acc.into_iter()
                         .filter(|c| x.contains(c))
                         .collect_vec()
@@ -25,14 +24,24 @@ fn part2(input: &str) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
+struct Day06;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::split_sections(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day06);
@@ -45,13 +45,13 @@ fn part1(g: &HashMap<&str, Bag>) -> usize {
     containing_count("shiny gold", &mut g) - 1
 }
 
-fn parse_line<'a>(l: &'a str) -> Option<(&'a str, Vec<(usize, &'a str)>)> {
+fn parse_line(l: &str) -> Option<(&str, Vec<(usize, &str)>)> {
     lazy_static! {
         static ref REL: Regex = Regex::new(r"^(.+?) bags?").unwrap();
         static ref RER: Regex = Regex::new(r"(\d+?) (.+?) bags?").unwrap();
     }
 
-    let (l, r) = aoc2020::split_to_tuple2(l, " contain ")?;
+    let (l, r) = aoclib::split_to_tuple2(l, " contain ")?;
     let lcolor = &REL.captures(l)?.get(1)?.as_str();
     let rcolors = RER
         .captures_iter(r)
@@ -61,20 +61,19 @@ fn parse_line<'a>(l: &'a str) -> Option<(&'a str, Vec<(usize, &'a str)>)> {
     Some((lcolor, rcolors))
 }
 
-fn build_graph(input: &Vec<String>) -> HashMap<&str, Bag> {
+fn build_graph(input: &str) -> HashMap<&str, Bag<'_>> {
     let mut m: HashMap<&str, Bag> = HashMap::new();
 
-    input
-        .iter()
+    aoclib::nonempty_lines(input)
         .map(|s| parse_line(s).unwrap())
         .for_each(|(l, r)| {
-            let lb = m.entry(l).or_insert_with(|| Bag::new());
+            let lb = m.entry(l).or_insert_with(Bag::new);
             for rb in &r {
                 lb.children.push(*rb);
             }
 
             for (_, rb) in r {
-                let b = m.entry(rb).or_insert_with(|| Bag::new());
+                let b = m.entry(rb).or_insert_with(Bag::new);
                 b.parents.push(l);
             }
         });
@@ -90,18 +89,27 @@ fn bag_count(b: &Bag, g: &HashMap<&str, Bag>) -> usize {
 }
 
 fn part2(g: &HashMap<&str, Bag>) -> usize {
-    bag_count(g.get("shiny gold").unwrap(), &g) - 1
+    bag_count(g.get("shiny gold").unwrap(), g) - 1
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
-    let graph = build_graph(&input);
+struct Day07;
+
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = HashMap<&'a str, Bag<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&graph);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(build_graph(input))
+    }
 
-    let p2 = part2(&graph);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day07);
@@ -13,11 +13,20 @@ enum ParseInstructionError {
     UnknownOp(String)
 }
 
+impl std::fmt::Display for ParseInstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseInstructionError::InvalidFormat(msg) => write!(f, "Invalid instruction: {}", msg),
+            ParseInstructionError::UnknownOp(op) => write!(f, "Unknown operation {}", op),
+        }
+    }
+}
+
 impl FromStr for Instruction {
     type Err = ParseInstructionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (ins, arg) = aoc2020::split_to_tuple2(s, " ")
+        let (ins, arg) = aoclib::split_to_tuple2(s, " ")
             .ok_or(ParseInstructionError::InvalidFormat("Split failed"))?;
         let arg = arg.parse::<i64>()
             .map_err(|_| ParseInstructionError::InvalidFormat("Argument parse failed"))?;
@@ -41,7 +50,7 @@ struct Interpreter {
 impl Interpreter {
     fn new(program: Vec<Instruction>) -> Interpreter {
         Interpreter {
-            program: program,
+            program,
             acc: 0,
             ip: 0
         }
@@ -103,25 +112,34 @@ fn part2(input: Interpreter) -> i64 {
                 }
             }
 
-            return None;
+            None
         })
         .next()
         .unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
-    let program = input
-        .into_iter()
-        .map(|l| l.parse().unwrap())
-        .collect::<Vec<_>>();
-    let interpreter = Interpreter::new(program);
+struct Day08;
 
-    let p1 = part1(interpreter.clone());
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Interpreter;
+    type Part1 = i64;
+    type Part2 = i64;
 
-    let p2 = part2(interpreter);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let program = aoclib::nonempty_lines(input)
+            .map(|l| l.parse())
+            .collect::<Result<_, ParseInstructionError>>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        Ok(Interpreter::new(program))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input.clone())
+    }
 }
+
+aoclib::aoc_main!(Day08);
@@ -3,13 +3,11 @@ use itertools::{Itertools, MinMaxResult};
 
 const PREAMBLE: usize = 25;
 
-fn part1(input: &Vec<i64>) -> i64 {
+fn part1(input: &[i64]) -> i64 {
     for i in PREAMBLE..input.len() {
         let valid = input.iter().copied()
             .skip(i - PREAMBLE).take(PREAMBLE)
-            .tuple_combinations()
-            .filter(|(l, r)| l + r == input[i])
-            .next().is_some();
+            .tuple_combinations().find(|(l, r)| l + r == input[i]).is_some();
         if !valid {
             return input[i];
         }
@@ -19,7 +17,7 @@ fn part1(input: &Vec<i64>) -> i64 {
 }
 
 
-fn part2(input: &Vec<i64>) -> i64 {
+fn part2(input: &[i64]) -> i64 {
     let part1_res = part1(input);
 
     let minmaxres= (0..input.len())
@@ -40,14 +38,24 @@ fn part2(input: &Vec<i64>) -> i64 {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_ints_from_stdin()?;
+struct Day09;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day09);
@@ -1,7 +1,7 @@
 use std::io;
 
-fn part1(input: &Vec<usize>) -> usize {
-    let mut input = input.clone();
+fn part1(input: &[usize]) -> usize {
+    let mut input = input.to_vec();
     input.sort();
     input.insert(0, 0);
 
@@ -15,7 +15,7 @@ fn part1(input: &Vec<usize>) -> usize {
     l * (r + 1)
 }
 
-fn valid(input: &Vec<usize>, start_index: usize, mem: &mut Vec<Option<usize>>) -> usize {
+fn valid(input: &[usize], start_index: usize, mem: &mut Vec<Option<usize>>) -> usize {
     if let Some(x) = mem[start_index] {
         return x;
     }
@@ -36,8 +36,8 @@ fn valid(input: &Vec<usize>, start_index: usize, mem: &mut Vec<Option<usize>>) -
     res
 }
 
-fn part2(input: &Vec<usize>) -> usize {
-    let mut input = input.clone();
+fn part2(input: &[usize]) -> usize {
+    let mut input = input.to_vec();
     input.sort();
     input.insert(0, 0);
 
@@ -45,14 +45,24 @@ fn part2(input: &Vec<usize>) -> usize {
     valid(&input, 0, &mut mem)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_ints_from_stdin()?;
+struct Day10;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day10);
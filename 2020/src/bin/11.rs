@@ -36,7 +36,7 @@ fn valid_coord(x: i64, y: i64, w: i64, h: i64) -> bool {
     x >= 0 && x < w && y >= 0 && y < h
 }
 
-fn visible_coords(x: usize, y: usize, inp: &Vec<Vec<char>>) -> Vec<(usize, usize)> {
+fn visible_coords(x: usize, y: usize, inp: &[Vec<char>]) -> Vec<(usize, usize)> {
     let mut res = Vec::new();
     let dirs = &[
         (-1i64, 0i64), (-1, -1), (0, -1), (1, -1),
@@ -63,8 +63,8 @@ fn visible_coords(x: usize, y: usize, inp: &Vec<Vec<char>>) -> Vec<(usize, usize
     res
 }
 
-fn part1(input: &Vec<Vec<char>>) -> usize {
-    let mut prev_state = input.clone();
+fn part1(input: &[Vec<char>]) -> usize {
+    let mut prev_state = input.to_vec();
     let w = input[0].len();
     let h = input.len();
     loop {
@@ -104,8 +104,8 @@ fn part1(input: &Vec<Vec<char>>) -> usize {
         .sum::<usize>()
 }
 
-fn part2(input: &Vec<Vec<char>>) -> usize {
-    let mut prev_state = input.clone();
+fn part2(input: &[Vec<char>]) -> usize {
+    let mut prev_state = input.to_vec();
     let w = input[0].len();
     let h = input.len();
     loop {
@@ -145,17 +145,24 @@ fn part2(input: &Vec<Vec<char>>) -> usize {
         .sum::<usize>()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
-    let input = input.into_iter()
-        .map(|s| s.chars().collect::<Vec<_>>())
-        .collect();
+struct Day11;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::nonempty_lines(input).map(|s| s.chars().collect()).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day11);
@@ -5,9 +5,9 @@ fn mv(x: i32, y: i32, dir: Complex<i32>, amt: i32) -> (i32, i32) {
     (x + amt * dir.re, y + amt * -dir.im)
 }
 
-fn part1(input: &Vec<(char, i32)>) -> i32 {
+fn part1(input: &[(char, i32)]) -> i32 {
     let (x, y, _) = input
-        .into_iter()
+        .iter()
         .fold((0, 0, Complex::new(1, 0)), |(accx, accy, accdir), (c, amt)| {
             let (x, y) = match c {
                 'N' => mv(accx, accy, Complex::new(0, 1), *amt),
@@ -28,8 +28,8 @@ fn part1(input: &Vec<(char, i32)>) -> i32 {
     x.abs() + y.abs()
 }
 
-fn part2(input: &Vec<(char, i32)>) -> i32 {
-    let (x, y, _) = input.into_iter().fold(
+fn part2(input: &[(char, i32)]) -> i32 {
+    let (x, y, _) = input.iter().fold(
         (0, 0, Complex::new(10,1)),
         |(accx, accy, accwp), (c, amt)| {
             let wp = match c {
@@ -53,24 +53,31 @@ fn part2(input: &Vec<(char, i32)>) -> i32 {
     x.abs() + y.abs()
 }
 
-fn parse_input() -> std::io::Result<Vec<(char, i32)>> {
-    let lines = aoc2020::read_stdin_lines()?;
-
-    Ok(lines
-        .iter()
+fn parse_input(input: &str) -> Vec<(char, i32)> {
+    aoclib::nonempty_lines(input)
         .map(|l| l.split_at(1))
-        .map(|(l, r)| (l.chars().nth(0).unwrap(), r.parse::<i32>().unwrap()))
-        .collect::<Vec<_>>())
+        .map(|(l, r)| (l.chars().next().unwrap(), r.parse::<i32>().unwrap()))
+        .collect::<Vec<_>>()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input()?;
+struct Day12;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = Vec<(char, i32)>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day12);
@@ -1,25 +1,26 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
-fn part1(input: &Vec<String>) -> usize {
-    let t0: usize = input[0].parse().unwrap();
-    let ids: Vec<usize> = aoc2020::read_ints_from_string(&input[1]);
+/// The earliest departure time and the bus IDs, `None` for the `x`s.
+type Notes = (usize, Vec<Option<usize>>);
 
+fn part1((t0, ids): &Notes) -> usize {
     let (id, w) = ids
-        .into_iter()
-        .map(|id| (id, id - t0 % id))
+        .iter()
+        .flatten()
+        .map(|&id| (id, id - t0 % id))
         .min_by_key(|(_, w)| *w)
         .unwrap();
 
     id * w
 }
 
-fn part2(input: &Vec<String>) -> usize {
-    let input = input[1]
-        .split(",")
-        .map(|x| x.parse::<usize>())
+fn part2((_, ids): &Notes) -> usize {
+    let input = ids
+        .iter()
         .enumerate()
-        .filter_map(|(i, x)| x.ok().map(|y| (i, y)));
+        .filter_map(|(i, x)| x.map(|y| (i, y)));
 
     input
         .fold((1, 1), |(min, step), (skip, interval)| {
@@ -34,14 +35,39 @@ fn part2(input: &Vec<String>) -> usize {
         .0
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
+fn parse_input(input: &str) -> Result<Notes, InputError> {
+    let mut lines = aoclib::nonempty_lines(input);
+    let [t0] = aoclib::parse_int_array(lines.next().unwrap_or_default(), false)?;
+    let ids = lines
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|id| match id {
+            "x" => Ok(None),
+            _ => aoclib::parse_int_array(id, false).map(|[id]| Some(id)),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((t0, ids))
+}
+
+struct Day13;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Notes;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day13);
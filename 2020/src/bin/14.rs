@@ -1,7 +1,8 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::{collections::HashMap, io};
 
-fn part1(input: &Vec<Instr>) -> usize {
+fn part1(input: &[Instr]) -> usize {
     let mut mask_bits = 0usize;
     let mut mask_values = 0usize;
     let mut mem: HashMap<usize, usize> = HashMap::new();
@@ -26,7 +27,7 @@ fn part1(input: &Vec<Instr>) -> usize {
     mem.values().sum()
 }
 
-fn part2(input: &Vec<Instr>) -> usize {
+fn part2(input: &[Instr]) -> usize {
     let mut mask_bits = 0usize;
     let mut mask_or_value = 0usize;
     let mut mem: HashMap<usize, usize> = HashMap::new();
@@ -67,31 +68,37 @@ enum Instr {
     Store(usize, usize),
 }
 
-fn parse_input(inp: &Vec<String>) -> Vec<Instr> {
-    inp.into_iter()
+fn parse_input(inp: &[String]) -> Result<Vec<Instr>, InputError> {
+    inp.iter()
         .map(|l| {
-            if l.starts_with("mask") {
+            Ok(if l.starts_with("mask") {
                 Instr::Mask(l.split_at(7).1.to_string())
             } else {
-                let (addr, val) = aoc2020::read_ints_from_string(l)
-                    .into_iter()
-                    .collect_tuple()
-                    .unwrap();
+                let [addr, val] = aoclib::parse_int_array(l, false)?;
                 Instr::Store(addr, val)
-            }
+            })
         })
-        .collect_vec()
+        .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
-    let input = parse_input(&input);
+struct Day14;
+
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = Vec<Instr>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_nonempty_lines(input))?)
+    }
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day14);
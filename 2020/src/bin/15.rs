@@ -1,6 +1,6 @@
 use std::{num::NonZeroUsize, io};
 
-fn run(input: &Vec<usize>, iters: usize) -> usize {
+fn run(input: &[usize], iters: usize) -> usize {
     let mut mem: Vec<Option<NonZeroUsize>> = vec![None; iters]; 
 
     for (i, &n) in input.iter().enumerate() {
@@ -20,14 +20,24 @@ fn run(input: &Vec<usize>, iters: usize) -> usize {
     next
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_ints_from_stdin()?;
+struct Day15;
 
-    let p1 = run(&input, 2020);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&input, 30_000_000);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, 2020)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, 30_000_000)
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day15);
@@ -1,10 +1,12 @@
+use aoclib::InputError;
 use itertools::Itertools;
-use regex::Regex;
 use std::io;
 
-fn part1((rules, _, others): &(Vec<Rule>, Vec<usize>, Vec<Vec<usize>>)) -> usize {
+type Notes<'a> = (Vec<Rule<'a>>, Vec<usize>, Vec<Vec<usize>>);
+
+fn part1((rules, _, others): &Notes) -> usize {
     others
-        .into_iter()
+        .iter()
         .flatten()
         .filter(|&&x| !rules.iter().any(|r| field_valid(r, x)))
         .sum()
@@ -15,9 +17,9 @@ fn field_valid(rule: &Rule, field: usize) -> bool {
         || (rule.range2.0..=rule.range2.1).contains(&field)
 }
 
-fn part2((rules, own, others): &(Vec<Rule>, Vec<usize>, Vec<Vec<usize>>)) -> usize {
+fn part2((rules, own, others): &Notes) -> usize {
     let others = others
-        .into_iter()
+        .iter()
         .filter(|&x| x.iter().all(|y| rules.iter().any(|r| field_valid(r, *y))))
         .collect_vec();
 
@@ -25,10 +27,8 @@ fn part2((rules, own, others): &(Vec<Rule>, Vec<usize>, Vec<Vec<usize>>)) -> usi
 
     let mut pos = 0usize;
     loop {
-        if names[pos] != None {
-            if names.iter().all(|x| *x != None) {
-                break;
-            }
+        if names[pos].is_some() && names.iter().all(|x| x.is_some()) {
+            break;
         }
 
         let mut candidates = rules
@@ -52,7 +52,7 @@ fn part2((rules, own, others): &(Vec<Rule>, Vec<usize>, Vec<Vec<usize>>)) -> usi
         .enumerate()
         .filter(|(_, r)| r.unwrap().field.starts_with("departure"))
         .map(|(i, _)| own[i])
-        .fold1(|a, b| a * b)
+        .reduce(|a, b| a * b)
         .unwrap()
 }
 
@@ -63,10 +63,7 @@ struct Rule<'a> {
     range2: (usize, usize),
 }
 
-fn parse(inp: &str) -> (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>) {
-    lazy_static::lazy_static! {
-        static ref RULE_RE: Regex = Regex::new(r"([a-z ]+): (\d+)-(\d+) or (\d+)-(\d+)").unwrap();
-    }
+fn parse_input(inp: &str) -> Result<Notes<'_>, InputError> {
     let mut parts = inp.split("\n\n");
 
     let rules = parts
@@ -74,43 +71,47 @@ fn parse(inp: &str) -> (Vec<Rule>, Vec<usize>, Vec<Vec<usize>>) {
         .unwrap()
         .lines()
         .map(|l| {
-            let c = RULE_RE.captures(l).unwrap();
-            Rule {
-                field: c.get(1).unwrap().as_str(),
-                range1: (
-                    c.get(2).unwrap().as_str().parse().unwrap(),
-                    c.get(3).unwrap().as_str().parse().unwrap(),
-                ),
-                range2: (
-                    c.get(4).unwrap().as_str().parse().unwrap(),
-                    c.get(5).unwrap().as_str().parse().unwrap(),
-                ),
-            }
+            let re = aoclib::regex!(r"([a-z ]+): (\d+)-(\d+) or (\d+)-(\d+)");
+            let (field, lo1, hi1, lo2, hi2) = aoclib::re::parse(re, l)?;
+            Ok(Rule {
+                field,
+                range1: (lo1, hi1),
+                range2: (lo2, hi2),
+            })
         })
-        .collect_vec();
+        .collect::<Result<_, InputError>>()?;
 
-    let own = aoc2020::read_ints_from_string(parts.next().unwrap().lines().nth(1).unwrap());
+    let own = aoclib::parse_ints(parts.next().unwrap().lines().nth(1).unwrap(), false)?;
 
     let nearby = parts
         .next()
         .unwrap()
         .lines()
         .skip(1)
-        .map(|l| aoc2020::read_ints_from_string(l))
-        .collect_vec();
+        .map(|l| aoclib::parse_ints(l, false))
+        .collect::<Result<_, _>>()?;
 
-    (rules, own, nearby)
+    Ok((rules, own, nearby))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
-    let input = parse(&input);
+struct Day16;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = Notes<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day16);
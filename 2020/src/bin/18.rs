@@ -45,7 +45,7 @@ fn parse_expr(input: &str, part2: bool) -> Vec<char> {
     output
 }
 
-fn run(input: &Vec<String>, part2: bool) -> usize {
+fn run(input: &[String], part2: bool) -> usize {
     input
         .iter()
         .map(|l| {
@@ -72,23 +72,41 @@ fn run(input: &Vec<String>, part2: bool) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let inp = aoc2020::read_stdin_lines()?;
+struct Day18;
 
-    let p1 = run(&inp, false);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&inp, true);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, false)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, true)
+    }
+}
+
+aoclib::aoc_main!(Day18);
 
-    Ok(())
+aoclib::example_tests! {
+    Day18;
+    example1: "1 + 2 * 3 + 4 * 5 + 6", part1 = 71, part2 = 231;
+    example2: "2 * 3 + (4 * 5)", part1 = 26, part2 = 46;
+    example3: "5 + (8 * 3 + 9 + 3 * 4 * 3)", part1 = 437, part2 = 1445;
+    example4: "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", part1 = 13632, part2 = 23340;
 }
 
 #[cfg(test)]
 mod test {
     use itertools::Itertools;
 
-    use crate::parse_expr;
+    use super::parse_expr;
 
     #[test]
     fn test_parse_expr() {
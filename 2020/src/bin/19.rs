@@ -3,11 +3,11 @@ use regex::Regex;
 use std::{collections::HashMap, io};
 
 fn part1(input: &str) -> usize {
-    let (rules, strings) = aoc2020::split_to_tuple2(input, "\n\n").unwrap();
+    let (rules, strings) = aoclib::split_to_tuple2(input, "\n\n").unwrap();
 
     let raw: HashMap<usize, &str> = rules
         .lines()
-        .map(|l| aoc2020::split_to_tuple2(l, ": ").unwrap())
+        .map(|l| aoclib::split_to_tuple2(l, ": ").unwrap())
         .map(|(idx, r)| (idx.parse().unwrap(), r))
         .collect();
 
@@ -19,11 +19,11 @@ fn part1(input: &str) -> usize {
 }
 
 fn part2(input: &str) -> usize {
-    let (rules, strings) = aoc2020::split_to_tuple2(input, "\n\n").unwrap();
+    let (rules, strings) = aoclib::split_to_tuple2(input, "\n\n").unwrap();
 
     let mut raw: HashMap<usize, &str> = rules
         .lines()
-        .map(|l| aoc2020::split_to_tuple2(l, ": ").unwrap())
+        .map(|l| aoclib::split_to_tuple2(l, ": ").unwrap())
         .map(|(idx, r)| (idx.parse().unwrap(), r))
         .collect();
 
@@ -116,7 +116,7 @@ fn get_rule2(raw_rules: &HashMap<usize, &str>, idx: usize, stack_size: usize) ->
                 .split(" ")
                 .map(|r| get_rule2(raw_rules, r.parse().unwrap(), stack_size - 1))
                 .collect_vec();
-            if rs.iter().any(|x| x == &None) {
+            if rs.iter().any(|x| x.is_none()) {
                 None
             } else {
                 Some(rs.into_iter().map(|x| x.unwrap()).join(""))
@@ -134,14 +134,24 @@ fn get_rule2(raw_rules: &HashMap<usize, &str>, idx: usize, stack_size: usize) ->
     Some(rule)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
+struct Day19;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day19);
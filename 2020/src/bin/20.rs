@@ -1,26 +1,18 @@
+use aoclib::transform::Dihedral;
+use aoclib::InputError;
 use itertools::Itertools;
+use ndarray::Array2;
 use std::collections::HashMap;
 use std::io;
-use aoc2020::UnwrapOptionIterator;
+use aoclib::UnwrapOptionIterator;
 
-type Grid = Vec<Vec<bool>>;
+type Grid = Array2<bool>;
 
 fn grid_rotations(g: &Grid) -> Vec<Grid> {
-    let r1 = rotate(&g);
-    let r2 = rotate(&r1);
-    let flipped = flip_vertical(&g);
-    let fr1 = rotate(&flipped);
-    let fr2 = rotate(&fr1);
-    vec![
-        g.clone(),
-        rotate(&r2),
-        r2,
-        r1,
-        flipped,
-        rotate(&fr2),
-        fr2,
-        fr1,
-    ]
+    Dihedral::ALL
+        .iter()
+        .map(|t| t.transform_array(g.view()))
+        .collect_vec()
 }
 
 fn tile_rotations(t: &Tile) -> Vec<Tile> {
@@ -30,17 +22,17 @@ fn tile_rotations(t: &Tile) -> Vec<Tile> {
         .collect_vec()
 }
 
-fn stitch(tiles: &Vec<Vec<Tile>>) -> Grid {
-    let tile_side = &tiles[0][0].grid.len();
+fn stitch(tiles: &[Vec<Tile>]) -> Grid {
+    let tile_side = tiles[0][0].grid.nrows();
     let cropped_side = tile_side - 2;
     let full_side = tiles.len() * cropped_side;
-    let mut grid = vec![vec![false; full_side]; full_side];
+    let mut grid = Array2::from_elem((full_side, full_side), false);
     for ty in 0..tiles.len() {
         for tx in 0..tiles.len() {
             for y in 0..cropped_side {
                 for x in 0..cropped_side {
-                    grid[(ty * cropped_side) + y][(tx * cropped_side) + x] =
-                        tiles[ty][tx].grid[1 + y][1 + x];
+                    grid[((ty * cropped_side) + y, (tx * cropped_side) + x)] =
+                        tiles[ty][tx].grid[(1 + y, 1 + x)];
                 }
             }
         }
@@ -128,9 +120,7 @@ fn part1(input: &HashMap<usize, Tile>) -> Option<(usize, Grid)> {
         }
     }
 
-    let corner = input.values()
-        .filter(|t| t.edges().iter().map(|e| side_counts[e]).sum::<u8>() <= 6)
-        .next()?;
+    let corner = input.values().find(|t| t.edges().iter().map(|e| side_counts[e]).sum::<u8>() <= 6)?;
 
     let ids_remaining = input.keys().copied().collect_vec();
     for rot in tile_rotations(corner) {
@@ -172,52 +162,29 @@ fn part2(input: Grid) -> usize {
     let mh = 3;
 
     for mut rot in grid_rotations(&input) {
-        let zero_coords = (0..=rot.len() - mw + 1)
-            .cartesian_product(0..=rot.len() - mh + 1)
-            .filter(|(xx, yy)| monster_pixels.iter().all(|(mx, my)| rot[my + yy][mx + xx]))
+        let zero_coords = (0..=rot.ncols() - mw)
+            .cartesian_product(0..=rot.nrows() - mh)
+            .filter(|(xx, yy)| monster_pixels.iter().all(|(mx, my)| rot[(my + yy, mx + xx)]))
             .flat_map(|(xx, yy)| {
                 monster_pixels
                     .iter()
                     .map(move |(mx, my)| (mx + xx, my + yy))
             })
             .collect_vec();
-        if zero_coords.len() == 0 {
+        if zero_coords.is_empty() {
             continue;
         }
 
         for (xx, yy) in zero_coords {
-            rot[yy][xx] = false;
+            rot[(yy, xx)] = false;
         }
 
-        return rot
-            .into_iter()
-            .flat_map(|l| l.into_iter())
-            .filter(|x| *x)
-            .count();
+        return rot.iter().filter(|x| **x).count();
     }
 
     unreachable!("Sea monsters not found")
 }
 
-fn rotate(grid: &Grid) -> Grid {
-    let mut new_grid: Grid = Vec::new();
-    for old_x in 0..grid.len() {
-        let mut l = Vec::new();
-        for old_y in (0..grid.len()).rev() {
-            l.push(grid[old_y][old_x]);
-        }
-        new_grid.push(l);
-    }
-
-    new_grid
-}
-
-fn flip_vertical(grid: &Grid) -> Grid {
-    let mut new = grid.clone();
-    new.reverse();
-    new
-}
-
 #[derive(Clone)]
 struct Tile {
     id: usize,
@@ -226,19 +193,19 @@ struct Tile {
 
 impl Tile {
     fn left_edge(&self) -> Vec<bool> {
-        (0..10).map(|i| self.grid[i][0]).collect()
+        self.grid.column(0).to_vec()
     }
 
     fn right_edge(&self) -> Vec<bool> {
-        (0..10).map(|i| self.grid[i][9]).collect()
+        self.grid.column(9).to_vec()
     }
 
     fn top_edge(&self) -> Vec<bool> {
-        self.grid[0].clone()
+        self.grid.row(0).to_vec()
     }
 
     fn bottom_edge(&self) -> Vec<bool> {
-        self.grid[9].clone()
+        self.grid.row(9).to_vec()
     }
 
     fn edges(&self) -> [Vec<bool>; 4] {
@@ -246,36 +213,40 @@ impl Tile {
     }
 }
 
-fn read_tile(inp: &str) -> Option<Tile> {
-    let mut line_iter = inp.lines();
+fn read_tile(section: aoclib::Section) -> Result<Tile, InputError> {
+    let [id] = aoclib::parse_int_array(section.header.unwrap_or_default(), false)?;
 
-    let id: usize = *aoc2020::read_ints_from_string(line_iter.next()?).first()?;
+    let grid = aoclib::array_from_rows(section.lines().map(|l| l.chars().map(|c| c == '#')))?;
 
-    let grid = line_iter
-        .map(|l| l.chars().map(|c| c == '#').collect_vec())
-        .collect_vec();
-
-    Some(Tile { id, grid })
+    Ok(Tile { id, grid })
 }
 
-fn read_input() -> io::Result<HashMap<usize, Tile>> {
-    Ok(aoc2020::read_stdin_to_string()?
-        .trim()
-        .split("\n\n")
-        .map(|s| read_tile(s).unwrap())
-        .map(|t| (t.id, t))
-        .collect())
+fn read_input(input: &str) -> Result<HashMap<usize, Tile>, InputError> {
+    aoclib::read_string_sections(input)
+        .into_iter()
+        .map(|s| read_tile(s).map(|t| (t.id, t)))
+        .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = read_input()?;
+struct Day20;
 
-    let p1 = part1(&input).unwrap();
-    println!("Part 1: {}", p1.0);
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = HashMap<usize, Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(p1.1);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(read_input(input)?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input).unwrap().0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(part1(input).unwrap().1)
+    }
 }
 
+aoclib::aoc_main!(Day20);
+
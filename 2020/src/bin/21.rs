@@ -15,11 +15,11 @@ struct Food {
     known_allergens: Vec<String>,
 }
 
-fn set_ing_allergen(foods: &mut Vec<Food>, ing_name: &str, allergen: &String) {
+fn set_ing_allergen(foods: &mut [Food], ing_name: &str, allergen: &str) {
     for food in foods.iter_mut() {
         for i in food.ingredients.iter_mut() {
             if i.name == ing_name {
-                i.allergen = Some(allergen.clone());
+                i.allergen = Some(allergen.to_owned());
             }
         }
     }
@@ -55,7 +55,7 @@ fn resolve(mut input: Vec<Food>) -> (Vec<Food>, Vec<String>) {
                         .cloned()
                         .collect::<HashSet<_>>()
                 })
-                .fold1(|acc, ings| acc.intersection(&ings).cloned().collect::<HashSet<_>>());
+                .reduce(|acc, ings| acc.intersection(&ings).cloned().collect::<HashSet<_>>());
             if let Some(candidates) = candidates {
                 if let Ok(ing) = candidates.into_iter().exactly_one() {
                     resolved.push(allergen.clone());
@@ -68,7 +68,7 @@ fn resolve(mut input: Vec<Food>) -> (Vec<Food>, Vec<String>) {
             break;
         }
         
-        remaining_allergens.retain(|al| !resolved.contains(&al));
+        remaining_allergens.retain(|al| !resolved.contains(al));
     }
 
     // Assign allergens which appear once and only have one possible ingredient
@@ -78,7 +78,7 @@ fn resolve(mut input: Vec<Food>) -> (Vec<Food>, Vec<String>) {
         }
 
         // find the food where this is
-        let food = input.iter().filter(|f| f.known_allergens.contains(al)).next().unwrap();
+        let food = input.iter().find(|f| f.known_allergens.contains(al)).unwrap();
         if let Ok(cand) = food.ingredients.iter().filter(|ing| ing.allergen.is_none()).map(|i| i.name.clone()).exactly_one() {
             set_ing_allergen(&mut input, &cand, al);
         }
@@ -87,7 +87,7 @@ fn resolve(mut input: Vec<Food>) -> (Vec<Food>, Vec<String>) {
     (input, remaining_allergens)
 }
 
-fn part1(input: &Vec<Food>, remaining_allergens: &Vec<String>) -> usize {
+fn part1(input: &[Food], remaining_allergens: &[String]) -> usize {
     input.iter()
         .filter(|f| !f.known_allergens.iter().any(|al| remaining_allergens.contains(al)))
         .flat_map(|f| f.ingredients.iter())
@@ -105,15 +105,12 @@ fn part2(input: Vec<Food>) -> String {
         .join(",")
 }
 
-fn parse_input() -> io::Result<Vec<Food>> {
-    let input = aoc2020::read_stdin_lines()?;
-    Ok(input
-        .into_iter()
+fn parse_input(input: &str) -> Vec<Food> {
+    aoclib::nonempty_lines(input)
         .map(|l| {
             let (ings, allergens) =
-                aoc2020::split_to_tuple2(&l.trim_end_matches(')'), " (contains ").unwrap();
+                aoclib::split_to_tuple2(l.trim_end_matches(')'), " (contains ").unwrap();
             let ings = ings
-                .trim()
                 .split_whitespace()
                 .map(|n| Ingredient {
                     name: n.to_string(),
@@ -127,18 +124,27 @@ fn parse_input() -> io::Result<Vec<Food>> {
                 known_allergens,
             }
         })
-        .collect_vec())
+        .collect_vec()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input()?;
-    let (input, remaining) = resolve(input);
+struct Day21;
 
-    let p1 = part1(&input, &remaining);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = (Vec<Food>, Vec<String>);
+    type Part1 = usize;
+    type Part2 = String;
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(resolve(parse_input(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input.0.clone())
+    }
 }
+
+aoclib::aoc_main!(Day21);
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use std::{collections::HashSet, io};
 use std::collections::VecDeque;
 
@@ -68,17 +69,37 @@ fn part2(p1: VecDeque<usize>, p2: VecDeque<usize>) -> usize {
     score(&winning_deck)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
-    let (pl1, pl2) = aoc2020::split_to_tuple2(&input, "\n\n").unwrap();
-    let pl1 = aoc2020::read_ints_from_string(pl1.strip_prefix("Player 1:\n").unwrap()).into_iter().collect();
-    let pl2 = aoc2020::read_ints_from_string(pl2.strip_prefix("Player 2:\n").unwrap()).into_iter().collect();
+type Decks = (VecDeque<usize>, VecDeque<usize>);
 
-    let p1 = part1(&pl1, &pl2);
-    println!("Part 1: {}", p1);
+fn parse_deck(s: &str) -> Result<VecDeque<usize>, InputError> {
+    // Skip the "Player N:" line, its number is not a card
+    let cards = s.split_once('\n').map_or("", |(_, cards)| cards);
+    Ok(aoclib::parse_ints(cards, false)?.into())
+}
+
+fn parse_input(input: &str) -> Result<Decks, InputError> {
+    let (pl1, pl2) = aoclib::split_to_tuple2(input, "\n\n").unwrap();
+    Ok((parse_deck(pl1)?, parse_deck(pl2)?))
+}
 
-    let p2 = part2(pl1, pl2);
-    println!("Part 2: {}", p2);
+struct Day22;
+
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = Decks;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.0.clone(), input.1.clone())
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+aoclib::aoc_main!(Day22);
@@ -34,7 +34,7 @@ fn part1(input: &VecDeque<u8>) -> usize {
 
 fn part2(input: &VecDeque<u8>) -> usize {
     const SIZE: usize = 1_000_000;
-    let input = input.into_iter().map(|x| *x as usize).collect_vec();
+    let input = input.iter().map(|x| *x as usize).collect_vec();
     let mut nexts = [0usize; SIZE+1];
 
     for (l, r) in input.iter().zip(input.iter().skip(1)) {
@@ -42,8 +42,8 @@ fn part2(input: &VecDeque<u8>) -> usize {
     }
     let max = *input.iter().max().unwrap();
     nexts[input[input.len()-1]] = max + 1;
-    for i in max+1..=SIZE {
-        nexts[i] = i+1;
+    for (i, next) in nexts.iter_mut().enumerate().take(SIZE + 1).skip(max + 1) {
+        *next = i + 1;
     }
 
     let mut cur = input[0];
@@ -73,19 +73,32 @@ fn part2(input: &VecDeque<u8>) -> usize {
     a*b
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_to_string()?;
-    let input = input
+fn parse_input(input: &str) -> VecDeque<u8> {
+    input
         .trim()
         .chars()
         .map(|d| d.to_digit(10).unwrap() as u8)
-        .collect::<VecDeque<_>>();
+        .collect()
+}
+
+struct Day23;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = VecDeque<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day23);
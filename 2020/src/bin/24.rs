@@ -82,7 +82,7 @@ impl HexCoord {
         }
     }
 
-    fn neighbors(&self) -> HexCoordNeighbors {
+    fn neighbors(&self) -> HexCoordNeighbors<'_> {
         HexCoordNeighbors {
             orig: self,
             state: 0,
@@ -111,10 +111,10 @@ fn resolve(l: &str) -> HexCoord {
         }
     }
 
-    return coord;
+    coord
 }
 
-fn part1(input: &Vec<String>) -> (usize, HashSet<HexCoord>) {
+fn part1(input: &[String]) -> (usize, HashSet<HexCoord>) {
     let mut tiles = HashMap::new();
     for l in input {
         let c = resolve(l);
@@ -147,7 +147,7 @@ fn part2(mut tiles: HashSet<HexCoord>) -> usize {
                 .map(|c| tiles.contains(&c) as u8)
                 .sum();
 
-            let tile = match tiles.contains(&c) {
+            let tile = match tiles.contains(c) {
                 true if neighbour_count == 0 || neighbour_count > 2 => false,
                 false if neighbour_count == 2 => true,
                 t => t,
@@ -164,14 +164,24 @@ fn part2(mut tiles: HashSet<HexCoord>) -> usize {
     tiles.len()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_stdin_lines()?;
+struct Day24;
 
-    let (p1, tiles) = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(tiles);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input).0
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(part1(input).1)
+    }
 }
+
+aoclib::aoc_main!(Day24);
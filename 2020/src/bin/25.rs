@@ -1,6 +1,6 @@
 use std::io;
 
-fn part1(input: &Vec<usize>) -> usize {
+fn part1(input: &[usize]) -> usize {
     let pub_a = input[0];
     let pub_b = input[1];
     let base = 7usize;
@@ -22,11 +22,24 @@ fn part1(input: &Vec<usize>) -> usize {
     key
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2020::read_ints_from_stdin()?;
+struct Day25;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day25 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = aoclib::solution::NoAnswer;
 
-    Ok(())
-}
\ No newline at end of file
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> aoclib::solution::NoAnswer {
+        aoclib::solution::NoAnswer
+    }
+}
+
+aoclib::aoc_main!(Day25);
@@ -1,105 +1,106 @@
-use itertools::Itertools;
-use num_integer::Integer;
-use regex::Regex;
-use std::{io::{self, Read}};
-use std::iter::Iterator;
-use std::str::FromStr;
-
-pub fn read_stdin_to_string() -> io::Result<String> {
-    let mut buffer = String::new();
-    io::stdin().read_to_string(&mut buffer)?;
-    Ok(buffer.to_owned())
-}
-
-pub fn read_stdin_lines() -> io::Result<Vec<String>> {
-    let input = read_stdin_to_string()?;
-    Ok(input
-        .lines()
-        .filter_map(|l| {
-            if l.is_empty() {
-                None
-            } else {
-                Some(l.to_owned())
-            }
-        })
-        .collect())
-}
-
-pub fn read_ints_from_stdin<T: Integer + FromStr>() -> io::Result<Vec<T>> {
-    let s = read_stdin_to_string()?;
-    Ok(read_ints_from_string(&s))
-}
-
-pub fn read_ints_from_string<T: Integer + FromStr>(s: &str) -> Vec<T> {
-    let re = Regex::new(r"\d+").unwrap();
-    re.find_iter(s)
-        .map(|m| m.as_str())
-        .filter_map(|m| m.parse::<T>().ok())
-        .collect::<Vec<T>>()
-}
-
-pub fn read_regex_matches_from_stdin(regex_pattern: &str) -> io::Result<Vec<String>> {
-    let s = read_stdin_to_string()?;
-    let matches = read_regex_matches_from_string(&s, regex_pattern);
-
-    let res = matches
-        .into_iter()
-        .map(|sm| sm.to_owned())
-        .collect::<Vec<_>>();
-
-    Ok(res)
-}
-
-pub fn read_regex_matches_from_string<'a>(s: &'a str, regex_pattern: &str) -> Vec<&'a str> {
-    let re = Regex::new(regex_pattern).unwrap();
-    re.find_iter(s).map(|m| m.as_str()).collect()
-}
-
-pub fn split_to_tuple2<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str)> {
-    let parts = s.splitn(2, pattern);
-    return parts.collect_tuple();
-}
-
-pub fn split_to_tuple3<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str, &'a str)> {
-    let parts = s.splitn(3, pattern);
-    return parts.collect_tuple();
-}
-
-pub fn split_to_tuple4<'a>(
-    s: &'a str,
-    pattern: &str,
-) -> Option<(&'a str, &'a str, &'a str, &'a str)> {
-    let parts = s.splitn(4, pattern);
-    return parts.collect_tuple();
-}
-
-#[test]
-fn test_read_ints_from_string() {
-    let s = "a123b22 123x02\n123";
-    let res: Vec<i32> = read_ints_from_string(s);
-
-    assert_eq!(5, res.len());
-    assert_eq!(vec![123i32, 22, 123, 02, 123], res);
-}
-
-#[test]
-fn test_read_regex_matches_from_string() {
-    let s = "0.12,1.23,4.2\n111.1,.,111.,.23";
-    let re = r"\d+\.\d+";
-
-    let m = read_regex_matches_from_string(s, re);
-    assert_eq!(vec!["0.12", "1.23", "4.2", "111.1"], m);
-}
-
-pub trait UnwrapOptionIterator<T> {
-    type Output: Iterator<Item = T>;
-    fn unwrap_options(self) -> Self::Output;
-}
-
-impl<T, I: Iterator<Item = Option<T>>> UnwrapOptionIterator<T> for I {
-    type Output = std::iter::Map<Self, fn(Option<T>) -> T>;
-
-    fn unwrap_options(self) -> Self::Output {
-        self.map(|x| x.unwrap())
-    }
-}
\ No newline at end of file
+//! Advent of Code 2020 solutions, registered for the `aoc` runner.
+
+use aoclib::solution::Entry;
+
+#[path = "bin/01.rs"]
+pub mod day01;
+
+#[path = "bin/02.rs"]
+pub mod day02;
+
+#[path = "bin/03.rs"]
+pub mod day03;
+
+#[path = "bin/04.rs"]
+pub mod day04;
+
+#[path = "bin/05.rs"]
+pub mod day05;
+
+#[path = "bin/06.rs"]
+pub mod day06;
+
+#[path = "bin/07.rs"]
+pub mod day07;
+
+#[path = "bin/08.rs"]
+pub mod day08;
+
+#[path = "bin/09.rs"]
+pub mod day09;
+
+#[path = "bin/10.rs"]
+pub mod day10;
+
+#[path = "bin/11.rs"]
+pub mod day11;
+
+#[path = "bin/12.rs"]
+pub mod day12;
+
+#[path = "bin/13.rs"]
+pub mod day13;
+
+#[path = "bin/14.rs"]
+pub mod day14;
+
+#[path = "bin/15.rs"]
+pub mod day15;
+
+#[path = "bin/16.rs"]
+pub mod day16;
+
+#[path = "bin/17.rs"]
+pub mod day17;
+
+#[path = "bin/18.rs"]
+pub mod day18;
+
+#[path = "bin/19.rs"]
+pub mod day19;
+
+#[path = "bin/20.rs"]
+pub mod day20;
+
+#[path = "bin/21.rs"]
+pub mod day21;
+
+#[path = "bin/22.rs"]
+pub mod day22;
+
+#[path = "bin/23.rs"]
+pub mod day23;
+
+#[path = "bin/24.rs"]
+pub mod day24;
+
+#[path = "bin/25.rs"]
+pub mod day25;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2020, 1, day01::SOLUTION),
+    Entry::new(2020, 2, day02::SOLUTION),
+    Entry::new(2020, 3, day03::SOLUTION),
+    Entry::new(2020, 4, day04::SOLUTION),
+    Entry::new(2020, 5, day05::SOLUTION),
+    Entry::new(2020, 6, day06::SOLUTION),
+    Entry::new(2020, 7, day07::SOLUTION),
+    Entry::new(2020, 8, day08::SOLUTION),
+    Entry::new(2020, 9, day09::SOLUTION),
+    Entry::new(2020, 10, day10::SOLUTION),
+    Entry::new(2020, 11, day11::SOLUTION),
+    Entry::new(2020, 12, day12::SOLUTION),
+    Entry::new(2020, 13, day13::SOLUTION),
+    Entry::new(2020, 14, day14::SOLUTION),
+    Entry::new(2020, 15, day15::SOLUTION),
+    Entry::new(2020, 16, day16::SOLUTION),
+    Entry::new(2020, 17, day17::SOLUTION),
+    Entry::new(2020, 18, day18::SOLUTION),
+    Entry::new(2020, 19, day19::SOLUTION),
+    Entry::new(2020, 20, day20::SOLUTION),
+    Entry::new(2020, 21, day21::SOLUTION),
+    Entry::new(2020, 22, day22::SOLUTION),
+    Entry::new(2020, 23, day23::SOLUTION),
+    Entry::new(2020, 24, day24::SOLUTION),
+    Entry::new(2020, 25, day25::SOLUTION),
+];
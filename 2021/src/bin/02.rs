@@ -3,7 +3,7 @@ use std::io;
 fn part1(input: &[String]) -> i32 {
     let (hor, dep) = input
         .iter()
-        .map(|x| aoc2021::split_to_tuple2(x, " ").unwrap())
+        .map(|x| aoclib::split_to_tuple2(x, " ").unwrap())
         .map(|(a, b)| {
             let i = b.parse().unwrap();
             match a {
@@ -22,7 +22,7 @@ fn part1(input: &[String]) -> i32 {
 fn part2(input: &[String]) -> i32 {
     let (hor, _, dep) = input
         .iter()
-        .map(|x| aoc2021::split_to_tuple2(x, " ").unwrap())
+        .map(|x| aoclib::split_to_tuple2(x, " ").unwrap())
         .map(|(a, b)| {
             let i = b.parse().unwrap();
             match a {
@@ -43,14 +43,24 @@ fn part2(input: &[String]) -> i32 {
     hor * dep
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
+struct Day02;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day02);
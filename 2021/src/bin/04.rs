@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use aoclib::InputError;
 use ndarray::Array2;
 use std::io;
 
@@ -72,39 +72,48 @@ impl BingoBoard {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_string()?;
+type Bingo = (Vec<u32>, Vec<BingoBoard>);
+
+fn parse_input(input: &str) -> Result<Bingo, InputError> {
     let mut input = input.split("\n\n");
-    let bingo_nums = aoc2021::read_ints_from_string(input.next().unwrap(), false);
-    let boards: Vec<BingoBoard> = parse_bingo_boards(input);
+    let bingo_nums = aoclib::parse_ints(input.next().unwrap(), false)?;
+    Ok((bingo_nums, parse_bingo_boards(input)?))
+}
+
+struct Day04;
 
-    let p1 = part1(&bingo_nums, &boards);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day04 {
+    type Input<'a> = Bingo;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
 
-    let p2 = part2(&bingo_nums, &boards);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(&input.0, &input.1)
+    }
 }
 
-fn parse_bingo_boards(input: std::str::Split<&str>) -> Vec<BingoBoard> {
+aoclib::aoc_main!(Day04);
+
+fn parse_bingo_boards(input: std::str::Split<&str>) -> Result<Vec<BingoBoard>, InputError> {
     input
         .map(|b| {
-            b.lines()
-                .map(|l| aoc2021::read_ints_from_string::<u32>(l, false))
-                .collect_vec()
-        })
-        .map(|b_rows| {
-            let rc = b_rows.len();
-            let b = Array2::from_shape_vec(
-                (rc, b_rows[0].len()),
-                b_rows.into_iter().flatten().collect(),
-            )
-            .unwrap();
-            BingoBoard {
+            let b_rows = b
+                .lines()
+                .map(|l| aoclib::parse_ints::<u32>(l, false))
+                .collect::<Result<_, _>>()?;
+            let b = aoclib::array_from_vecs(b_rows)?;
+            Ok(BingoBoard {
                 marks: Array2::default(b.raw_dim()),
                 rows: b,
-            }
+            })
         })
         .collect()
 }
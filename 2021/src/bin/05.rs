@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use std::{collections::HashMap, io};
 
 fn run(input: &[Line], part2: bool) -> usize {
@@ -43,28 +44,39 @@ impl Line {
     }
 }
 
-fn parse_lines(lines: &[String]) -> Vec<Line> {
+fn parse_lines(lines: &[String]) -> Result<Vec<Line>, InputError> {
     lines
         .iter()
-        .map(|l| aoc2021::read_ints_from_string(l, false))
-        .map(|l: Vec<i16>| Line {
-            start_x: l[0],
-            start_y: l[1],
-            end_x: l[2],
-            end_y: l[3],
+        .map(|l| {
+            let [start_x, start_y, end_x, end_y] = aoclib::parse_int_array(l, false)?;
+            Ok(Line {
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+            })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let input = parse_lines(&input);
+struct Day05;
 
-    let p1 = run(&input, false);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&input, true);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_lines(&aoclib::read_nonempty_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, false)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, true)
+    }
 }
+
+aoclib::aoc_main!(Day05);
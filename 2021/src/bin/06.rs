@@ -18,14 +18,24 @@ fn run(input: &[u16], rounds: usize) -> usize {
     fish.into_iter().sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_ints(false)?;
+struct Day06;
 
-    let p1 = run(&input, 80);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = Vec<u16>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&input, 256);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, 80)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, 256)
+    }
 }
+
+aoclib::aoc_main!(Day06);
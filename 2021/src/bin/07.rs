@@ -27,14 +27,24 @@ fn part2(input: &[i32]) -> i32 {
         .unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_ints::<i32>(false)?;
+struct Day07;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day07);
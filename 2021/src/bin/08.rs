@@ -5,8 +5,7 @@ fn part1(input: &[String]) -> usize {
     input
         .iter()
         .map(|l| l.split_once(" | ").unwrap().1)
-        .map(|l| l.split(' '))
-        .flatten()
+        .flat_map(|l| l.split(' '))
         .filter(|x| [7, 2, 3, 4].contains(&x.len()))
         .count()
 }
@@ -66,8 +65,7 @@ fn deduce(line: Vec<&str>) -> Option<SevenSegment> {
     let bot_left_bot = line
         .iter()
         .filter(|x| x.len() == 5)
-        .map(|x| x.chars())
-        .flatten()
+        .flat_map(|x| x.chars())
         .filter(|c| ![top_right, bottom_right, top, top_left, middle].contains(c))
         .sorted()
         .collect_vec();
@@ -100,14 +98,24 @@ fn part2(input: &[String]) -> u32 {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
+struct Day08;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = u32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_nonempty_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day08);
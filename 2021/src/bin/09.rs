@@ -1,3 +1,4 @@
+use aoclib::coord2::Coord2;
 use itertools::Itertools;
 use ndarray::Array2;
 use std::{
@@ -5,23 +6,11 @@ use std::{
     io,
 };
 
-fn adjacents(c: (usize, usize), w: usize, h: usize) -> impl Iterator<Item = (usize, usize)> {
-    let (y, x) = c;
-    [
-        if x > 0 { Some((y, x - 1)) } else { None },
-        if x < w - 1 { Some((y, x + 1)) } else { None },
-        if y > 0 { Some((y - 1, x)) } else { None },
-        if y < h - 1 { Some((y + 1, x)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
-}
-
 fn get_low_points(input: &Array2<u32>) -> impl Iterator<Item = ((usize, usize), u32)> + '_ {
     let (w, h) = (input.ncols(), input.nrows());
     input.indexed_iter()
         .filter(move |&(c, &val)| {
-            adjacents(c, w, h).map(|ac| input[ac]).all(|v| v > val)
+            Coord2::from(c).neighbors4((h, w)).map(|ac| input[ac]).all(|v| v > val)
         })
         .map(|(c, v)| (c, *v))
 }
@@ -42,7 +31,9 @@ fn part2(input: &Array2<u32>) -> usize {
             to_visit.push_back(lpc);
             while let Some(vc) = to_visit.pop_front() {
                 visited.insert(vc);
-                let new = adjacents(vc, w, h)
+                let new = Coord2::from(vc)
+                    .neighbors4((h, w))
+                    .map(Coord2::<usize>::usizes)
                     .filter(|&ac| !visited.contains(&ac) && input[ac] != 9);
                 to_visit.extend(new);
             }
@@ -54,14 +45,24 @@ fn part2(input: &Array2<u32>) -> usize {
         .product()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_int_matrix()?;
+struct Day09;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Array2<u32>;
+    type Part1 = u32;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_int_matrix(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day09);
@@ -1,18 +1,7 @@
-use itertools::Itertools;
+use aoclib::coord2::Coord2;
 use ndarray::Array2;
 use std::io;
 
-fn adjacents_with_diagonal(
-    c: (usize, usize),
-    w: usize,
-    h: usize,
-) -> impl Iterator<Item = (usize, usize)> {
-    let (y, x) = c;
-    (y.saturating_sub(1)..=(y + 1).min(h - 1))
-        .cartesian_product(x.saturating_sub(1)..=(x + 1).min(w - 1))
-        .filter(move |c| *c != (y, x))
-}
-
 fn flash(arr: &mut Array2<u32>) -> Array2<bool> {
     let mut flashes: Array2<bool> = Array2::default(arr.raw_dim());
 
@@ -27,7 +16,10 @@ fn flash(arr: &mut Array2<u32>) -> Array2<bool> {
         }
         for c in new_flashes {
             flashes[c] = true;
-            for ac in adjacents_with_diagonal(c, 10, 10) {
+            for ac in Coord2::from(c)
+                .neighbors8(arr.dim())
+                .map(Coord2::<usize>::usizes)
+            {
                 arr[ac] += 1;
             }
         }
@@ -66,14 +58,24 @@ fn part2(input: &Array2<u32>) -> usize {
     unreachable!()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_int_matrix()?;
+struct Day11;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = Array2<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_int_matrix(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day11);
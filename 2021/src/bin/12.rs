@@ -12,11 +12,12 @@ struct Path {
     double_visit_used: bool,
 }
 
-fn parse_input<'a>(input: Vec<String>) -> (Vec<Cave>, usize, usize) {
+type Caves = (Vec<Cave>, usize, usize);
+
+fn parse_input(input: Vec<String>) -> Caves {
     let all_names: Vec<_> = input
         .iter()
-        .map(|l| aoc2021::read_regex_matches_from_string(l, r"\w+"))
-        .flatten()
+        .flat_map(|l| aoclib::read_regex_matches_from_string(l, r"\w+"))
         .unique()
         .collect();
     let mut res: Vec<Cave> = all_names
@@ -28,7 +29,7 @@ fn parse_input<'a>(input: Vec<String>) -> (Vec<Cave>, usize, usize) {
         .collect();
 
     for l in &input {
-        let (start, end) = aoc2021::read_regex_matches_from_string(l, r"\w+")
+        let (start, end) = aoclib::read_regex_matches_from_string(l, r"\w+")
             .into_iter()
             .collect_tuple()
             .unwrap();
@@ -66,7 +67,7 @@ fn f(caves: &[Cave], path: Vec<&Cave>, end: usize) -> usize {
     paths
 }
 
-fn part1(input: &Vec<Cave>, start: usize, end: usize) -> usize {
+fn part1(input: &[Cave], start: usize, end: usize) -> usize {
     f(input, vec![&input[start]], end)
 }
 
@@ -107,7 +108,7 @@ fn f2(caves: &[Cave], path: Path, start: usize, end: usize) -> usize {
     paths
 }
 
-fn part2(input: &Vec<Cave>, start: usize, end: usize) -> usize {
+fn part2(input: &[Cave], start: usize, end: usize) -> usize {
     f2(
         input,
         Path {
@@ -119,15 +120,24 @@ fn part2(input: &Vec<Cave>, start: usize, end: usize) -> usize {
     )
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let (input, start_cave, end_cave) = parse_input(input);
+struct Day12;
+
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = Caves;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&input, start_cave, end_cave);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_nonempty_lines(input)))
+    }
 
-    let p2 = part2(&input, start_cave, end_cave);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, input.1, input.2)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(&input.0, input.1, input.2)
+    }
 }
+
+aoclib::aoc_main!(Day12);
@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use aoclib::{coord2::Coord2, Bounds, InputError};
 use ndarray::Array2;
 use std::io;
 
@@ -22,7 +22,7 @@ fn fold(paper: &Array2<bool>, fold_up: bool, fold_pos: usize) -> Array2<bool> {
     folded
 }
 
-fn part1(paper: &Array2<bool>, folds: &Vec<(bool, usize)>) -> usize {
+fn part1(paper: &Array2<bool>, folds: &[(bool, usize)]) -> usize {
     let (fold_up, fold_pos) = *folds.first().unwrap();
     fold(paper, fold_up, fold_pos)
         .into_iter()
@@ -30,60 +30,59 @@ fn part1(paper: &Array2<bool>, folds: &Vec<(bool, usize)>) -> usize {
         .count()
 }
 
-fn part2(paper: Array2<bool>, folds: &Vec<(bool, usize)>) {
-    let folded = folds
+fn part2(paper: Array2<bool>, folds: &[(bool, usize)]) -> Array2<bool> {
+    folds
         .iter()
-        .fold(paper, |p, &(fold_up, fold_pos)| fold(&p, fold_up, fold_pos));
-
-    for r in folded.rows() {
-        println!(
-            "{}",
-            r.into_iter().map(|&v| if v { '#' } else { ' ' }).join("")
-        );
-    }
+        .fold(paper, |p, &(fold_up, fold_pos)| fold(&p, fold_up, fold_pos))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let (paper, folds) = parse_input(input);
+struct Day13;
 
-    let p1 = part1(&paper, &folds);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Manual;
+    type Part1 = usize;
+    type Part2 = aoclib::Answer;
 
-    println!("Part 2:");
-    part2(paper, &folds);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_nonempty_lines(input))?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> aoclib::Answer {
+        aoclib::Answer::Bitmap(part2(input.0.clone(), &input.1)).read_letters()
+    }
 }
 
-fn parse_input(input: Vec<String>) -> (Array2<bool>, Vec<(bool, usize)>) {
-    let points: Vec<(usize, usize)> = input
-        .iter()
-        .take_while(|l| matches!(l.chars().next(), Some(c) if c.is_digit(10)))
-        .map(|l| {
-            aoc2021::read_ints_from_string(l, false)
-                .into_iter()
-                .collect_tuple()
-                .unwrap()
-        })
-        .collect();
+aoclib::aoc_main!(Day13);
 
-    let max_x = points.iter().max_by_key(|(x, _)| x).unwrap().0;
-    let max_y = points.iter().max_by_key(|(_, y)| y).unwrap().1;
+type Manual = (Array2<bool>, Vec<(bool, usize)>);
 
-    let mut arr: Array2<bool> = Array2::default((max_y + 1, max_x + 1));
-    for (x, y) in points {
-        arr[(y, x)] = true;
-    }
+fn parse_input(input: Vec<String>) -> Result<Manual, InputError> {
+    let points: Vec<[usize; 2]> = input
+        .iter()
+        .take_while(|l| matches!(l.chars().next(), Some(c) if c.is_ascii_digit()))
+        .map(|l| aoclib::parse_int_array(l, false))
+        .collect::<Result<_, _>>()?;
+
+    let points = points.into_iter().map(|[x, y]| Coord2 { y, x });
+    let bounds = Bounds {
+        min: Coord2 { y: 0, x: 0 },
+        ..Bounds::of(points.clone()).unwrap()
+    };
+    let arr = aoclib::array_from_coords_in(bounds, points.map(|c| (c, true)))?;
 
     let folds = input
         .into_iter()
         .skip_while(|l| !l.starts_with('f'))
         .map(|l| {
             let (l, r) = l.split_once('=').unwrap();
-            (l.ends_with('y'), r.parse().unwrap())
+            let [n] = aoclib::parse_int_array(r, false)?;
+            Ok((l.ends_with('y'), n))
         })
-        .collect();
+        .collect::<Result<_, InputError>>()?;
 
-    (arr, folds)
+    Ok((arr, folds))
 }
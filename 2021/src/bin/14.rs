@@ -85,15 +85,24 @@ fn parse_input(mut input: Vec<String>) -> (String, BTreeMap<(char, char), Node>)
     (input.swap_remove(0), rule_dict)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let (init, nodes) = parse_input(input);
+struct Day14;
 
-    let p1 = run(&init, &nodes, 10);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = (String, BTreeMap<(char, char), Node>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&init, &nodes, 40);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_nonempty_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(&input.0, &input.1, 10)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(&input.0, &input.1, 40)
+    }
 }
+
+aoclib::aoc_main!(Day14);
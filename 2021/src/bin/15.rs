@@ -1,28 +1,17 @@
-use itertools::Itertools;
+use aoclib::coord2::Coord2;
 use ndarray::Array2;
 use std::{collections::BinaryHeap, io};
 
-fn adjacents(x: usize, y: usize, w: usize, h: usize) -> impl Iterator<Item = (usize, usize)> {
-    [
-        if x > 0 { Some((x - 1, y)) } else { None },
-        if x < w - 1 { Some((x + 1, y)) } else { None },
-        if y > 0 { Some((x, y - 1)) } else { None },
-        if y < h - 1 { Some((x, y + 1)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
-}
-
 #[derive(PartialEq, Eq)]
 struct DistanceCoord(u32, (usize, usize));
 impl PartialOrd for DistanceCoord {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.0.partial_cmp(&self.0)
+        Some(self.cmp(other))
     }
 }
 impl Ord for DistanceCoord {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.0.partial_cmp(&other.0).unwrap()
+        other.0.cmp(&self.0)
     }
 }
 
@@ -42,8 +31,9 @@ fn run(input: &Array2<u32>) -> u32 {
             return d;
         }
 
-        let adjacents = adjacents(c.1, c.0, input.ncols(), input.nrows())
-            .map(|(x, y)| (y, x))
+        let adjacents = Coord2::from(c)
+            .neighbors4(input.dim())
+            .map(Coord2::<usize>::usizes)
             .filter(|c| !visited[*c]);
         for ac in adjacents {
             let ad = d + input[ac];
@@ -77,19 +67,24 @@ fn part2(input: &Array2<u32>) -> u32 {
     run(&full_input)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let input = input
-        .into_iter()
-        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec();
-    let input = aoc2021::make_2d_array(input).unwrap();
+struct Day15;
 
-    let p1 = run(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = Array2<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_digit_grid(input)?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        run(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day15);
@@ -123,13 +123,24 @@ fn read_packets(input: &str) -> Packet {
     p
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_string()?;
-    let input = input.trim();
-    let root_packet = read_packets(input);
+struct Day16;
 
-    println!("Part 1: {}", root_packet.version_sum());
-    println!("Part 2: {}", root_packet.eval());
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = Packet;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(read_packets(input.trim()))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        input.version_sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        input.eval()
+    }
 }
+
+aoclib::aoc_main!(Day16);
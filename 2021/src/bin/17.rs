@@ -81,15 +81,29 @@ fn part2(x0: i32, x1: i32, y0: i32, y1: i32) -> i32 {
     valid
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_ints::<i32>(true)?;
-    let (x0, x1, y0, y1) = input.into_iter().collect_tuple().unwrap();
-
-    let p1 = part1(x0, x1, y0, y1);
-    println!("Part 1: {}", p1);
+struct Day17;
+
+impl aoclib::solution::Solution for Day17 {
+    type Input<'a> = (i32, i32, i32, i32);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, true)?
+            .into_iter()
+            .collect_tuple()
+            .unwrap())
+    }
 
-    let p2 = part2(x0, x1, y0, y1);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        let &(x0, x1, y0, y1) = input;
+        part1(x0, x1, y0, y1)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        let &(x0, x1, y0, y1) = input;
+        part2(x0, x1, y0, y1)
+    }
 }
+
+aoclib::aoc_main!(Day17);
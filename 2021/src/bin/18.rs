@@ -1,6 +1,8 @@
 /// This is a stupidly overcomplicated solution.
 /// Just wanted to try implementing trees and stuff using Rc<RefCell<T>>.
 /// Will not try again...
+use aoclib::parse::{int, nested, parse_all, Nested};
+use aoclib::InputError;
 use itertools::Itertools;
 use std::{cell::RefCell, fmt::Display, io, rc::Rc};
 
@@ -45,14 +47,10 @@ impl Display for PairNode {
 }
 
 fn add_to_left(target: Rc<RefCell<PairNode>>, val: u32, coming_from: Rc<RefCell<PairNode>>) {
-    let coming_from_right = match &target.borrow().right {
-        SnailfishNumber::Pair(rp) if Rc::ptr_eq(rp, &coming_from) => true,
-        _ => false,
-    };
-    let coming_from_parent = match &target.borrow().parent {
-        Some(pp) if Rc::ptr_eq(pp, &coming_from) => true,
-        _ => false,
-    };
+    let coming_from_right =
+        matches!(&target.borrow().right, SnailfishNumber::Pair(rp) if Rc::ptr_eq(rp, &coming_from));
+    let coming_from_parent =
+        matches!(&target.borrow().parent, Some(pp) if Rc::ptr_eq(pp, &coming_from));
     if coming_from_right {
         let mut node = target.borrow_mut();
         match &node.left {
@@ -79,14 +77,10 @@ fn add_to_left(target: Rc<RefCell<PairNode>>, val: u32, coming_from: Rc<RefCell<
 }
 
 fn add_to_right(target: Rc<RefCell<PairNode>>, val: u32, coming_from: Rc<RefCell<PairNode>>) {
-    let coming_from_left = match &target.borrow().left {
-        SnailfishNumber::Pair(lp) if Rc::ptr_eq(lp, &coming_from) => true,
-        _ => false,
-    };
-    let coming_from_parent = match &target.borrow().parent {
-        Some(pp) if Rc::ptr_eq(pp, &coming_from) => true,
-        _ => false,
-    };
+    let coming_from_left =
+        matches!(&target.borrow().left, SnailfishNumber::Pair(lp) if Rc::ptr_eq(lp, &coming_from));
+    let coming_from_parent =
+        matches!(&target.borrow().parent, Some(pp) if Rc::ptr_eq(pp, &coming_from));
     if coming_from_left {
         let mut node = target.borrow_mut();
         match &node.right {
@@ -185,6 +179,8 @@ impl SnailfishNumber {
         panic!("Top-level regular!")
     }
 
+    // The guard can't borrow the node mutably while it is being matched
+    #[allow(clippy::collapsible_match)]
     fn split(&mut self) -> bool {
         if let SnailfishNumber::Pair(pn) = self {
             let mut pn_mut = pn.borrow_mut();
@@ -192,7 +188,7 @@ impl SnailfishNumber {
                 SnailfishNumber::Regular(x) if x >= 10 => {
                     let mut new_pair = PairNode::new(Some(pn.clone()));
                     new_pair.left = SnailfishNumber::Regular(x / 2);
-                    new_pair.right = SnailfishNumber::Regular((x + 1) / 2);
+                    new_pair.right = SnailfishNumber::Regular(x.div_ceil(2));
                     pn_mut.left = SnailfishNumber::Pair(Rc::new(RefCell::new(new_pair)));
                     return true;
                 }
@@ -207,7 +203,7 @@ impl SnailfishNumber {
                 SnailfishNumber::Regular(x) if x >= 10 => {
                     let mut new_pair = PairNode::new(Some(pn.clone()));
                     new_pair.left = SnailfishNumber::Regular(x / 2);
-                    new_pair.right = SnailfishNumber::Regular((x + 1) / 2);
+                    new_pair.right = SnailfishNumber::Regular(x.div_ceil(2));
                     pn_mut.right = SnailfishNumber::Pair(Rc::new(RefCell::new(new_pair)));
                     return true;
                 }
@@ -256,7 +252,7 @@ impl SnailfishNumber {
     }
 }
 
-fn part1(input: &Vec<SnailfishNumber>) -> u32 {
+fn part1(input: &[SnailfishNumber]) -> u32 {
     input
         .iter()
         .map(|t| t.clone_tree(None))
@@ -265,7 +261,7 @@ fn part1(input: &Vec<SnailfishNumber>) -> u32 {
         .magnitude()
 }
 
-fn part2(input: &Vec<SnailfishNumber>) -> u32 {
+fn part2(input: &[SnailfishNumber]) -> u32 {
     input
         .iter()
         .cartesian_product(input.iter())
@@ -275,39 +271,52 @@ fn part2(input: &Vec<SnailfishNumber>) -> u32 {
         .unwrap()
 }
 
-fn parse_tree(l: &str, parent: Option<Rc<RefCell<PairNode>>>) -> (SnailfishNumber, usize) {
-    if l.starts_with('[') {
-        // parse pair
-        let tree = Rc::new(RefCell::new(PairNode::new(parent)));
-        let (left, llen) = parse_tree(&l[1..], Some(tree.clone()));
-        let (right, rlen) = parse_tree(&l[1 + llen + 1..], Some(tree.clone()));
-        {
-            let mut tree_mut = tree.borrow_mut();
-            tree_mut.left = left;
-            tree_mut.right = right;
+fn build_tree(
+    n: Nested<u32>,
+    parent: Option<Rc<RefCell<PairNode>>>,
+) -> Result<SnailfishNumber, InputError> {
+    match n {
+        Nested::Item(n) => Ok(SnailfishNumber::Regular(n)),
+        Nested::List(pair) => {
+            let len = pair.len();
+            let (left, right) = pair.into_iter().collect_tuple().ok_or_else(|| {
+                InputError::unexpected("a pair", format!("a list of {} elements", len))
+            })?;
+            let tree = Rc::new(RefCell::new(PairNode::new(parent)));
+            let left = build_tree(left, Some(tree.clone()))?;
+            let right = build_tree(right, Some(tree.clone()))?;
+            {
+                let mut tree_mut = tree.borrow_mut();
+                tree_mut.left = left;
+                tree_mut.right = right;
+            }
+            Ok(SnailfishNumber::Pair(tree))
         }
-        (SnailfishNumber::Pair(tree), 3 + llen + rlen)
-    } else {
-        // parse literal
-        (
-            SnailfishNumber::Regular(l.chars().next().unwrap().to_digit(10).unwrap()),
-            1,
-        )
     }
 }
 
-fn main() -> io::Result<()> {
-    let input_str = aoc2021::read_input_lines()?;
-    let input = input_str
-        .into_iter()
-        .map(|l| parse_tree(&l, None).0)
-        .collect_vec();
+fn parse_tree(l: &str) -> io::Result<SnailfishNumber> {
+    Ok(build_tree(parse_all(nested(int), l)?, None)?)
+}
+
+struct Day18;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = Vec<SnailfishNumber>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::nonempty_lines(input).map(parse_tree).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day18);
@@ -1,48 +1,19 @@
+use aoclib::transform::Rotation3;
+use aoclib::InputError;
 use itertools::Itertools;
-use ndarray::{array, Array1, Array2};
+use ndarray::{array, Array1};
 use std::{
     collections::{HashSet, VecDeque},
     io,
 };
 
-fn transformation_matrices() -> Vec<Array2<i32>> {
-    let rotations = [
-        // None
-        array![[1, 0, 0], [0, 1, 0], [0, 0, 1]],
-        // Around x
-        array![[1, 0, 0], [0, 0, -1], [0, 1, 0]],
-        array![[1, 0, 0], [0, -1, 0], [0, 0, -1]],
-        array![[1, 0, 0], [0, 0, 1], [0, -1, 0]],
-        // Around y
-        array![[0, 0, 1], [0, 1, 0], [-1, 0, 0]],
-        array![[-1, 0, 0], [0, 1, 0], [0, 0, -1]],
-        array![[0, 0, -1], [0, 1, 0], [1, 0, 0]],
-        // Around z
-        array![[0, -1, 0], [1, 0, 0], [0, 0, 1]],
-        array![[-1, 0, 0], [0, -1, 0], [0, 0, 1]],
-        array![[0, 1, 0], [-1, 0, 0], [0, 0, 1]],
-    ];
-
-    let rot_combs = rotations
-        .iter()
-        .tuple_combinations()
-        .map(|(a, b)| a.dot(b))
-        .unique()
-        .collect_vec();
-
-    rot_combs
-}
-
-#[test]
-fn test_transformation_matrices() {
-    let ts = transformation_matrices();
-    assert_eq!(24, ts.len());
-}
-
 fn transformed_scanners(scanner: &[Array1<i32>]) -> impl Iterator<Item = Vec<Array1<i32>>> + '_ {
-    transformation_matrices()
-        .into_iter()
-        .map(|t| scanner.iter().map(|c| c.dot(&t)).collect_vec())
+    Rotation3::all().map(|r| {
+        scanner
+            .iter()
+            .map(|c| Array1::from(r.apply_array([c[0], c[1], c[2]]).to_vec()))
+            .collect_vec()
+    })
 }
 
 #[test]
@@ -117,7 +88,6 @@ fn find_translation(
             continue;
         }
 
-        println!("Found x {}", x);
         for y in ref_y_limits.0 - 1001..=ref_y_limits.1 + 1001 {
             let num_matching_y = source
                 .iter()
@@ -128,8 +98,6 @@ fn find_translation(
                 continue;
             }
 
-            println!("Found y {}", y);
-
             for z in ref_z_limits.0 - 1001..=ref_z_limits.1 + 1001 {
                 let num_matching_z = source
                     .iter()
@@ -178,7 +146,6 @@ fn part1(input: &[Vec<Array1<i32>>]) -> (usize, Vec<Array1<i32>>) {
                 ref_y_limits = new_limits.1;
                 ref_z_limits = new_limits.2;
                 translations.push(t);
-                println!("Scanner {} mapped!", i);
                 continue 'scanner_loop;
             }
         }
@@ -216,7 +183,7 @@ fn part2(points: &[Array1<i32>]) -> i32 {
         .unwrap()
 }
 
-fn read_scanners(lines: Vec<String>) -> Vec<Vec<Array1<i32>>> {
+fn read_scanners(lines: Vec<String>) -> Result<Vec<Vec<Array1<i32>>>, InputError> {
     let mut scanners = vec![];
 
     let mut curr_scanner = vec![];
@@ -228,25 +195,32 @@ fn read_scanners(lines: Vec<String>) -> Vec<Vec<Array1<i32>>> {
             continue;
         }
 
-        let c = aoc2021::read_ints_from_string(l, true)
-            .into_iter()
-            .collect::<Array1<_>>();
+        let c = Array1::from(aoclib::parse_ints(l, true)?);
         curr_scanner.push(c);
     }
 
     scanners.push(curr_scanner);
-    scanners
+    Ok(scanners)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let input = read_scanners(input);
+struct Day19;
+
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = Vec<Vec<Array1<i32>>>;
+    type Part1 = usize;
+    type Part2 = i32;
 
-    let (points, translations) = part1(&input);
-    println!("---\nPart 1: {}", points);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(read_scanners(aoclib::read_nonempty_lines(input))?)
+    }
 
-    let p2 = part2(&translations);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input).0
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> i32 {
+        part2(&part1(input).1)
+    }
 }
+
+aoclib::aoc_main!(Day19);
@@ -2,7 +2,7 @@
 use ndarray::{s, Array2};
 use std::io;
 
-fn run(algo: &Vec<bool>, img: &Array2<bool>, outside: bool) -> (Array2<bool>, bool) {
+fn run(algo: &[bool], img: &Array2<bool>, outside: bool) -> (Array2<bool>, bool) {
     let new_outside = if outside {
         *algo.last().unwrap()
     } else {
@@ -23,25 +23,26 @@ fn run(algo: &Vec<bool>, img: &Array2<bool>, outside: bool) -> (Array2<bool>, bo
     (res, new_outside)
 }
 
-fn part1(algo: &Vec<bool>, img: &Array2<bool>) -> usize {
+fn part1(algo: &[bool], img: &Array2<bool>) -> usize {
     // First
     let (img, outside) = run(algo, img, false);
 
     // Second
     let (img, outside) = run(algo, &img, outside);
 
-    assert_eq!(false, outside);
+    assert!(!outside);
 
     img.into_iter().filter(|x| *x).count()
 }
 
-fn part2(algo: &Vec<bool>, img: Array2<bool>) -> usize {
+fn part2(algo: &[bool], img: Array2<bool>) -> usize {
     let img = (0..50)
         .fold((img, false), |(img, outside), _| run(algo, &img, outside))
         .0;
     img.into_iter().filter(|x| *x).count()
 }
 
+#[allow(clippy::reversed_empty_ranges)] // negative ndarray slice bounds count from the end
 fn pad<T: Default + Clone + Copy>(arr: &Array2<T>, val: T) -> Array2<T> {
     let mut padded = Array2::default((arr.nrows() + 4, arr.ncols() + 4));
     padded.fill(val);
@@ -52,6 +53,8 @@ fn pad<T: Default + Clone + Copy>(arr: &Array2<T>, val: T) -> Array2<T> {
 
 #[test]
 fn test_pad() {
+    use ndarray::array;
+
     let a = array![[1, 2]];
 
     let p = pad(&a, 0);
@@ -71,27 +74,40 @@ fn test_pad() {
 fn parse_input(input: Vec<String>) -> (Vec<bool>, Array2<bool>) {
     let algo = input[0].chars().map(|c| c == '#').collect();
 
-    let img_w = input[1].len();
-    let img_h = input.len() - 1;
-    let img: Vec<_> = input
-        .iter()
-        .skip(1)
-        .flat_map(|s| s.chars())
-        .map(|c| c == '#')
-        .collect();
+    let img = aoclib::array_from_rows(
+        input
+            .iter()
+            .skip(1)
+            .map(|s| s.chars().map(|c| c == '#')),
+    )
+    .unwrap();
 
-    (algo, Array2::from_shape_vec((img_w, img_h), img).unwrap())
+    (algo, img)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let (algo, img) = parse_input(input);
+struct Day20;
+
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = (Vec<bool>, Array2<bool>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_nonempty_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
 
-    let p1 = part1(&algo, &img);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(&input.0, input.1.clone())
+    }
+}
 
-    let p2 = part2(&algo, img);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day20);
 
-    Ok(())
+aoclib::example_tests! {
+    Day20;
+    example1: include_str!("inputs/20.example1.txt"), part1 = 35, part2 = 3351;
 }
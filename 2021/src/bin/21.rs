@@ -24,7 +24,6 @@ fn part1(mut p1_pos: u32, mut p2_pos: u32) -> u32 {
                 p1_pos = 10;
             }
             p1 += p1_pos;
-            println!("p1 to {}, score {}", p1_pos, p1);
             if p1 >= 1000 {
                 return (1 + i) as u32 * 3 * p2;
             }
@@ -35,13 +34,16 @@ fn part1(mut p1_pos: u32, mut p2_pos: u32) -> u32 {
     unreachable!()
 }
 
+/// Wins for both players by (score 1, score 2, position 1, position 2, turn).
+type WinCache = HashMap<(u8, u8, u8, u8, bool), (usize, usize)>;
+
 fn p2_run(
     p1: u8,
     p2: u8,
     p1_pos: u8,
     p2_pos: u8,
     turn: bool,
-    cache: &mut HashMap<(u8, u8, u8, u8, bool), (usize, usize)>,
+    cache: &mut WinCache,
 ) -> (usize, usize) {
     if let Some(cached) = cache.get(&(p1, p2, p1_pos, p2_pos, turn)) {
         return *cached;
@@ -88,16 +90,26 @@ fn part2(p1_pos: u32, p2_pos: u32) -> usize {
     p1_wins.max(p2_wins)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let p1_start = input[0].chars().last().unwrap().to_digit(10).unwrap();
-    let p2_start = input[1].chars().last().unwrap().to_digit(10).unwrap();
+struct Day21;
+
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = (u32, u32);
+    type Part1 = u32;
+    type Part2 = usize;
 
-    let p1 = part1(p1_start, p2_start);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let mut starts =
+            aoclib::nonempty_lines(input).map(|l| l.chars().last().unwrap().to_digit(10).unwrap());
+        Ok((starts.next().unwrap(), starts.next().unwrap()))
+    }
 
-    let p2 = part2(p1_start, p2_start);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input.0, input.1)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.0, input.1)
+    }
 }
+
+aoclib::aoc_main!(Day21);
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
@@ -184,26 +185,35 @@ fn part2(input: &[(bool, Vec<i32>)]) -> usize {
     run(input)
 }
 
-fn read_input() -> io::Result<Vec<(bool, Vec<i32>)>> {
-    Ok(aoc2021::read_input_lines()?
-        .into_iter()
-        .map(|l| {
-            (
-                l.starts_with("on"),
-                aoc2021::read_ints_from_string(&l, true),
-            )
-        })
-        .collect())
+fn parse_input(input: &str) -> Result<Vec<(bool, Vec<i32>)>, InputError> {
+    aoclib::nonempty_lines(input)
+        .map(|l| Ok((l.starts_with("on"), aoclib::parse_ints(l, true)?)))
+        .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = read_input()?;
+struct Day22;
+
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = Vec<(bool, Vec<i32>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day22);
 
-    Ok(())
+aoclib::example_tests! {
+    Day22;
+    example1: include_str!("inputs/22.example1.txt"), part1 = 39, part2 = 39;
 }
@@ -1,3 +1,4 @@
+use aoclib::coord2::Coord2;
 use itertools::Itertools;
 use ndarray::Array2;
 use std::{
@@ -12,6 +13,28 @@ enum Square {
     Room(char),
 }
 
+/// A character of the input map
+#[derive(Clone, Copy, Default)]
+enum Tile {
+    #[default]
+    Wall,
+    Open,
+    Amphipod(char),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '#' | ' ' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Open),
+            'A'..='D' => Ok(Tile::Amphipod(c)),
+            _ => Err(c),
+        }
+    }
+}
+
 #[derive(Clone, Eq, Hash, PartialEq)]
 struct State(BTreeMap<(usize, usize), char>);
 
@@ -26,39 +49,16 @@ fn amphipod_move_cost(ap: char) -> usize {
 }
 
 fn abs_diff(a: usize, b: usize) -> usize {
-    if a > b {
-        a - b
-    } else {
-        b - a
-    }
-}
-
-fn adjacents(x: usize, y: usize, w: usize, h: usize) -> impl Iterator<Item = (usize, usize)> {
-    [
-        if x > 0 { Some((y, x - 1)) } else { None },
-        if x < w - 1 { Some((y, x + 1)) } else { None },
-        if y > 0 { Some((y - 1, x)) } else { None },
-        if y < h - 1 { Some((y + 1, x)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
+    a.abs_diff(b)
 }
 
 fn cost(ap: char, from: (usize, usize), to: (usize, usize)) -> usize {
     let unit = amphipod_move_cost(ap);
 
-    let x = if from.1 > to.1 {
-        from.1 - to.1
-    } else {
-        to.1 - from.1
-    };
+    let x = from.1.abs_diff(to.1);
     
     let y = if x == 0 {
-        if from.0 > to.0 {
-            from.0 - to.0
-        } else {
-            to.0 - from.0
-        }
+        from.0.abs_diff(to.0)
     } else {
         from.0 - 1 + to.0 - 1
     };
@@ -97,7 +97,7 @@ fn reachable_positions(
     loop {
         let new_res = res
             .iter()
-            .flat_map(|&(y, x)| adjacents(x, y, grid.ncols(), grid.nrows()))
+            .flat_map(|&c| Coord2::from(c).neighbors4(grid.dim()).map(Coord2::<usize>::usizes))
             .chain(res.iter().copied())
             .unique()
             .filter(|c| *c != pos && !matches!(grid[*c], Square::Wall) && !state.0.contains_key(c))
@@ -131,7 +131,7 @@ fn amphipod_room_x_coord(ap: char) -> usize {
 struct CostState(usize, usize, State);
 impl PartialOrd for CostState {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        (other.0 + other.1).partial_cmp(&(self.0 + self.1))
+        Some(self.cmp(other))
     }
 }
 impl Ord for CostState {
@@ -166,7 +166,6 @@ fn run(grid: &Array2<Square>, init: &State) -> usize {
         }
         //print_state(grid, &state);
         if is_final(&state, grid) {
-            println!();
             return cost;
         }
 
@@ -203,50 +202,70 @@ fn is_final(state: &State, grid: &Array2<Square>) -> bool {
         .all(|(c, &ap)| matches!(grid[*c], Square::Room(rap) if rap == ap))
 }
 
-fn read_grid(lines: &[String]) -> (Array2<Square>, State) {
-    let w = lines[0].len();
-    let h = lines.len();
-
-    let mut arr = Array2::from_elem((h, w), Square::Wall);
-
+fn read_grid(tiles: &Array2<Tile>) -> (Array2<Square>, State) {
     let mut amphipods = BTreeMap::new();
-
-    for (y, l) in lines.iter().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            let sq = match c {
-                '#' | ' ' => Square::Wall,
-                '.' if y == 1 => Square::Corridor,
-                _ => {
-                    amphipods.insert((y, x), c);
-                    Square::Room(match x {
-                        3 => 'A',
-                        5 => 'B',
-                        7 => 'C',
-                        9 => 'D',
-                        _ => panic!("Invalid room x coordinate"),
-                    })
-                }
-            };
-            arr[(y, x)] = sq;
+    let arr = Array2::from_shape_fn(tiles.dim(), |(y, x)| match tiles[(y, x)] {
+        Tile::Wall => Square::Wall,
+        Tile::Open if y == 1 => Square::Corridor,
+        tile => {
+            if let Tile::Amphipod(ap) = tile {
+                amphipods.insert((y, x), ap);
+            }
+            Square::Room(match x {
+                3 => 'A',
+                5 => 'B',
+                7 => 'C',
+                9 => 'D',
+                _ => panic!("Invalid room x coordinate"),
+            })
         }
-    }
+    });
 
     (arr, State(amphipods))
 }
 
-fn main() -> io::Result<()> {
-    let mut input = aoc2021::read_input_lines()?;
-    let (arr, amphipods) = read_grid(&input);
+fn part1(input: &Array2<Tile>) -> usize {
+    let (arr, amphipods) = read_grid(input);
+    run(&arr, &amphipods)
+}
 
-    let p1 = run(&arr, &amphipods);
-    println!("Part 1: {}", p1);
+/// The rows unfolded from the diagram for part 2, inserted after the
+/// first row of rooms
+const UNFOLDED_ROWS: [&str; 2] = ["  #D#C#B#A#", "  #D#B#A#C#"];
+
+fn part2(input: &Array2<Tile>) -> usize {
+    let (h, w) = input.dim();
+    let unfolded = Array2::from_shape_fn((h + 2, w), |(y, x)| match y {
+        0..=2 => input[(y, x)],
+        3 | 4 => UNFOLDED_ROWS[y - 3]
+            .chars()
+            .nth(x)
+            .map_or(Tile::Wall, |c| Tile::try_from(c).unwrap()),
+        _ => input[(y - 2, x)],
+    });
+
+    let (arr, amphipods) = read_grid(&unfolded);
+    run(&arr, &amphipods)
+}
+
+struct Day23;
 
-    input.insert(3, "  #D#C#B#A#".to_string());
-    input.insert(4, "  #D#B#A#C#".to_string());
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = Array2<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let (arr, amphipods) = read_grid(&input);
-    let p2 = run(&arr, &amphipods);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ragged_grid(input)?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day23);
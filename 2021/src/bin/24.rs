@@ -120,7 +120,7 @@ fn read_program(prg: &[String]) -> Vec<Instr> {
             if l.starts_with("inp") {
                 return Instr::Inp(l.chars().last().unwrap());
             }
-            let (ins, target, operand) = aoc2021::split_to_tuple3(l, " ").unwrap();
+            let (ins, target, operand) = aoclib::split_to_tuple3(l, " ").unwrap();
             let operand = if operand.starts_with(|c: char| c.is_alphabetic()) {
                 Operand::Var(operand.chars().next().unwrap())
             } else {
@@ -140,15 +140,24 @@ fn read_program(prg: &[String]) -> Vec<Instr> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoc2021::read_input_lines()?;
-    let prg = read_program(&input);
+struct Day24;
 
-    let p1 = part1(&prg);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = Vec<Instr>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&prg);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(read_program(&aoclib::read_nonempty_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
 }
+
+aoclib::aoc_main!(Day24);
@@ -1,5 +1,4 @@
 use std::io;
-use itertools::Itertools;
 use ndarray::Array2;
 
 
@@ -52,12 +51,24 @@ fn part1(input: &Array2<char>) -> usize {
 
 
 
-fn main() -> io::Result<()> {
-    let input: Vec<Vec<_>> = aoc2021::read_input_lines()?.into_iter().map(|l| l.chars().collect()).collect();
-    let input = Array2::from_shape_vec((input.len(), input[0].len()), input.into_iter().flatten().collect_vec()).unwrap();
+struct Day25;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day25 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = aoclib::solution::NoAnswer;
 
-    Ok(())
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> aoclib::solution::NoAnswer {
+        aoclib::solution::NoAnswer
+    }
 }
+
+aoclib::aoc_main!(Day25);
@@ -1,197 +1,106 @@
-use itertools::Itertools;
-use ndarray::Array2;
-use num_integer::Integer;
-use regex::Regex;
-use std::io::{self, Read};
-use std::iter::Iterator;
-use std::str::FromStr;
-
-pub fn get_input_filename() -> Option<String> {
-    let args: Vec<_> = std::env::args().collect();
-    match args.len() {
-        2 => args.into_iter().nth(1),
-        1 => None,
-        _ => panic!("Invalid number of arguments ({})", args.len() - 1),
-    }
-}
-
-pub fn read_input_string() -> io::Result<String> {
-    match get_input_filename() {
-        Some(path) => std::fs::read_to_string(path),
-        None => read_stdin_to_string(),
-    }
-}
-
-pub fn read_stdin_to_string() -> io::Result<String> {
-    let mut buffer = String::new();
-    io::stdin().read_to_string(&mut buffer)?;
-    Ok(buffer.to_owned())
-}
-
-pub fn read_input_lines() -> io::Result<Vec<String>> {
-    match get_input_filename() {
-        Some(path) => read_file_lines(&path),
-        None => read_stdin_lines(),
-    }
-}
-
-pub fn read_file_lines(filename: &str) -> io::Result<Vec<String>> {
-    let input = std::fs::read_to_string(filename)?;
-    Ok(input
-        .lines()
-        .filter_map(|l| {
-            if l.is_empty() {
-                None
-            } else {
-                Some(l.to_owned())
-            }
-        })
-        .collect())
-}
-
-pub fn read_stdin_lines() -> io::Result<Vec<String>> {
-    let input = read_stdin_to_string()?;
-    Ok(input
-        .lines()
-        .filter_map(|l| {
-            if l.is_empty() {
-                None
-            } else {
-                Some(l.to_owned())
-            }
-        })
-        .collect())
-}
-
-pub fn read_input_ints<T: Integer + FromStr>(signed: bool) -> io::Result<Vec<T>> {
-    match get_input_filename() {
-        Some(path) => read_ints_from_file(&path, signed),
-        None => read_ints_from_stdin(signed),
-    }
-}
-
-pub fn read_ints_from_stdin<T: Integer + FromStr>(signed: bool) -> io::Result<Vec<T>> {
-    let s = read_stdin_to_string()?;
-    Ok(read_ints_from_string(&s, signed))
-}
-
-pub fn read_ints_from_file<T: Integer + FromStr>(
-    filename: &str,
-    signed: bool,
-) -> io::Result<Vec<T>> {
-    let s = std::fs::read_to_string(filename)?;
-    Ok(read_ints_from_string(&s, signed))
-}
-
-pub fn read_ints_from_string<T: Integer + FromStr>(s: &str, signed: bool) -> Vec<T> {
-    let re = Regex::new(if signed { r"-?\d+" } else { r"\d+" }).unwrap();
-    re.find_iter(s)
-        .map(|m| m.as_str())
-        .filter_map(|m| m.parse::<T>().ok())
-        .collect::<Vec<T>>()
-}
-
-pub fn read_input_regex_matches(regex_pattern: &str) -> io::Result<Vec<String>> {
-    match get_input_filename() {
-        Some(path) => read_regex_matches_from_file(&path, regex_pattern),
-        None => read_regex_matches_from_stdin(regex_pattern),
-    }
-}
-
-pub fn read_regex_matches_from_stdin(regex_pattern: &str) -> io::Result<Vec<String>> {
-    let s = read_stdin_to_string()?;
-    let matches = read_regex_matches_from_string(&s, regex_pattern);
-
-    let res = matches.into_iter().map(|sm| sm.to_owned()).collect();
-
-    Ok(res)
-}
-
-pub fn read_regex_matches_from_file(
-    filename: &str,
-    regex_pattern: &str,
-) -> io::Result<Vec<String>> {
-    let s = std::fs::read_to_string(filename)?;
-    let matches = read_regex_matches_from_string(&s, regex_pattern);
-
-    let res = matches.into_iter().map(|sm| sm.to_owned()).collect();
-
-    Ok(res)
-}
-
-pub fn read_regex_matches_from_string<'a>(s: &'a str, regex_pattern: &str) -> Vec<&'a str> {
-    let re = Regex::new(regex_pattern).unwrap();
-    re.find_iter(s).map(|m| m.as_str()).collect()
-}
-
-pub fn read_input_char_matrix() -> io::Result<Array2<char>> {
-    let lines = read_input_lines()?;
-    let h = lines.len();
-    let w = lines[0].len();
-
-    Array2::from_shape_vec((h, w), lines.iter().flat_map(|l| l.chars()).collect())
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
-}
-
-pub fn read_input_int_matrix<T: Integer + From<u32>>() -> io::Result<Array2<T>> {
-    let cm = read_input_char_matrix()?;
-    if !cm.iter().all(|&c| c.is_digit(10)) {
-        Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Matrix char not a digit"))
-    } else {
-        Ok(cm.map(|&c| c.to_digit(10).unwrap().into()))
-    }
-}
-
-pub fn split_to_tuple2<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str)> {
-    s.split_once(pattern)
-}
-
-pub fn split_to_tuple3<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str, &'a str)> {
-    let parts = s.splitn(3, pattern);
-    parts.collect_tuple()
-}
-
-pub fn split_to_tuple4<'a>(
-    s: &'a str,
-    pattern: &str,
-) -> Option<(&'a str, &'a str, &'a str, &'a str)> {
-    let parts = s.splitn(4, pattern);
-    parts.collect_tuple()
-}
-
-#[test]
-fn test_read_ints_from_string() {
-    let s = "a123b22 123x02\n123-22";
-    let res: Vec<i32> = read_ints_from_string(s, true);
-
-    assert_eq!(vec![123i32, 22, 123, 2, 123, -22], res);
-}
-
-#[test]
-fn test_read_regex_matches_from_string() {
-    let s = "0.12,1.23,4.2\n111.1,.,111.,.23";
-    let re = r"\d+\.\d+";
-
-    let m = read_regex_matches_from_string(s, re);
-    assert_eq!(vec!["0.12", "1.23", "4.2", "111.1"], m);
-}
-
-pub trait UnwrapOptionIterator<T> {
-    type Output: Iterator<Item = T>;
-    fn unwrap_options(self) -> Self::Output;
-}
-
-impl<T, I: Iterator<Item = Option<T>>> UnwrapOptionIterator<T> for I {
-    type Output = std::iter::Map<Self, fn(Option<T>) -> T>;
-
-    fn unwrap_options(self) -> Self::Output {
-        self.map(|x| x.unwrap())
-    }
-}
-
-pub fn make_2d_array<T>(v: Vec<Vec<T>>) -> Option<Array2<T>> {
-    let ncols = v[0].len();
-    let nrows = v.len();
-
-    Array2::from_shape_vec((ncols, nrows), v.into_iter().flatten().collect_vec()).ok()
-}
+//! Advent of Code 2021 solutions, registered for the `aoc` runner.
+
+use aoclib::solution::Entry;
+
+#[path = "bin/01.rs"]
+pub mod day01;
+
+#[path = "bin/02.rs"]
+pub mod day02;
+
+#[path = "bin/03.rs"]
+pub mod day03;
+
+#[path = "bin/04.rs"]
+pub mod day04;
+
+#[path = "bin/05.rs"]
+pub mod day05;
+
+#[path = "bin/06.rs"]
+pub mod day06;
+
+#[path = "bin/07.rs"]
+pub mod day07;
+
+#[path = "bin/08.rs"]
+pub mod day08;
+
+#[path = "bin/09.rs"]
+pub mod day09;
+
+#[path = "bin/10.rs"]
+pub mod day10;
+
+#[path = "bin/11.rs"]
+pub mod day11;
+
+#[path = "bin/12.rs"]
+pub mod day12;
+
+#[path = "bin/13.rs"]
+pub mod day13;
+
+#[path = "bin/14.rs"]
+pub mod day14;
+
+#[path = "bin/15.rs"]
+pub mod day15;
+
+#[path = "bin/16.rs"]
+pub mod day16;
+
+#[path = "bin/17.rs"]
+pub mod day17;
+
+#[path = "bin/18.rs"]
+pub mod day18;
+
+#[path = "bin/19.rs"]
+pub mod day19;
+
+#[path = "bin/20.rs"]
+pub mod day20;
+
+#[path = "bin/21.rs"]
+pub mod day21;
+
+#[path = "bin/22.rs"]
+pub mod day22;
+
+#[path = "bin/23.rs"]
+pub mod day23;
+
+#[path = "bin/24.rs"]
+pub mod day24;
+
+#[path = "bin/25.rs"]
+pub mod day25;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2021, 1, day01::SOLUTION),
+    Entry::new(2021, 2, day02::SOLUTION),
+    Entry::new(2021, 3, day03::SOLUTION),
+    Entry::new(2021, 4, day04::SOLUTION),
+    Entry::new(2021, 5, day05::SOLUTION),
+    Entry::new(2021, 6, day06::SOLUTION),
+    Entry::new(2021, 7, day07::SOLUTION),
+    Entry::new(2021, 8, day08::SOLUTION),
+    Entry::new(2021, 9, day09::SOLUTION),
+    Entry::new(2021, 10, day10::SOLUTION),
+    Entry::new(2021, 11, day11::SOLUTION),
+    Entry::new(2021, 12, day12::SOLUTION),
+    Entry::new(2021, 13, day13::SOLUTION),
+    Entry::new(2021, 14, day14::SOLUTION),
+    Entry::new(2021, 15, day15::SOLUTION),
+    Entry::new(2021, 16, day16::SOLUTION),
+    Entry::new(2021, 17, day17::SOLUTION),
+    Entry::new(2021, 18, day18::SOLUTION),
+    Entry::new(2021, 19, day19::SOLUTION),
+    Entry::new(2021, 20, day20::SOLUTION),
+    Entry::new(2021, 21, day21::SOLUTION),
+    Entry::new(2021, 22, day22::SOLUTION),
+    Entry::new(2021, 23, day23::SOLUTION),
+    Entry::new(2021, 24, day24::SOLUTION),
+    Entry::new(2021, 25, day25::SOLUTION),
+];
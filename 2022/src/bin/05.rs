@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
@@ -6,8 +7,9 @@ use std::io;
 struct Crate(char);
 type Stack = Vec<Crate>;
 struct Step(usize, usize, usize);
+type Procedure = (Vec<Stack>, Vec<Step>);
 
-fn parse_input(input: &[String]) -> (Vec<Stack>, Vec<Step>) {
+fn parse_input(input: &[String]) -> Result<Procedure, InputError> {
     let rows: Vec<_> = input
         .iter()
         .take_while(|l| !l.is_empty())
@@ -40,18 +42,19 @@ fn parse_input(input: &[String]) -> (Vec<Stack>, Vec<Step>) {
         .iter()
         .skip_while(|l| !l.is_empty())
         .skip(1)
-        .map(|l| aoclib::read_ints_from_string(l, false))
-        .map(|l| Step(l[0], l[1], l[2]))
-        .collect();
+        .map(|l| {
+            let [count, from, to] = aoclib::parse_int_array(l, false)?;
+            Ok(Step(count, from, to))
+        })
+        .collect::<Result<_, InputError>>()?;
 
-    (stacks, steps)
+    Ok((stacks, steps))
 }
 
-fn part1(input: &[String]) -> String {
-    let (mut stacks, steps) = parse_input(input);
+fn part1((stacks, steps): &Procedure) -> String {
+    let mut stacks = stacks.clone();
 
-    for step in steps {
-        let Step(count, from, to) = step;
+    for &Step(count, from, to) in steps {
         for _ in 0..count {
             let cr = stacks[from - 1].pop().expect("Nothing to pop in a stack!");
             stacks[to - 1].push(cr);
@@ -64,11 +67,10 @@ fn part1(input: &[String]) -> String {
         .collect()
 }
 
-fn part2(input: &[String]) -> String {
-    let (mut stacks, steps) = parse_input(input);
+fn part2((stacks, steps): &Procedure) -> String {
+    let mut stacks = stacks.clone();
 
-    for step in steps {
-        let Step(count, from, to) = step;
+    for &Step(count, from, to) in steps {
         let from_stack = &mut stacks[from - 1];
         let mov = from_stack.split_off(from_stack.len() - count);
         stacks[to - 1].extend(mov);
@@ -80,16 +82,31 @@ fn part2(input: &[String]) -> String {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day05;
+
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> String {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day05);
 
-    Ok(())
+aoclib::example_tests! {
+    Day05;
+    example1: include_str!("inputs/05.example1.txt"), part1 = "CMZ", part2 = "MCD";
 }
 
 #[cfg(test)]
@@ -98,6 +115,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(5)).unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, "TPGVQPFDH");
@@ -23,16 +23,35 @@ fn part2(input: &str) -> usize {
         + 14
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
+struct Day06;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = String;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day06);
 
-    Ok(())
+aoclib::example_tests! {
+    Day06;
+    example1: "mjqjpqmgbljsphdztnvjfqwrcgsmlb", part1 = 7, part2 = 19;
+    example2: "bvwbjplbgvbhsrlpgdmjqwftvncz", part1 = 5, part2 = 23;
+    example3: "nppdvjthqldpwncqszvftbrmjlhg", part1 = 6, part2 = 23;
+    example4: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", part1 = 10, part2 = 29;
+    example5: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", part1 = 11, part2 = 26;
 }
 #[cfg(test)]
 mod test {
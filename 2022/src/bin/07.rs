@@ -111,18 +111,32 @@ fn walk_dirs(root: &Dir) -> Vec<usize> {
     entries
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let root = parse_input(input);
-    let dir_sizes = walk_dirs(&root);
+struct Day07;
 
-    let p1 = part1(dir_sizes.clone());
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(dir_sizes);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let root = parse_input(aoclib::read_string_lines(input));
+        Ok(walk_dirs(&root))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
+}
+
+aoclib::aoc_main!(Day07);
 
-    Ok(())
+aoclib::example_tests! {
+    Day07;
+    example1: include_str!("inputs/07.example1.txt"), part1 = 95437, part2 = 24933642;
 }
 
 #[cfg(test)]
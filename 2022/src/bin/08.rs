@@ -90,16 +90,31 @@ fn get_score(dir: Dir, arr: ArrayView1<u32>, start: usize, h: u32) -> usize {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_int_matrix()?;
+struct Day08;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Array2<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_int_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day08);
 
-    Ok(())
+aoclib::example_tests! {
+    Day08;
+    example1: include_str!("inputs/08.example1.txt"), part1 = 21, part2 = 8;
 }
 
 #[cfg(test)]
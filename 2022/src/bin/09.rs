@@ -68,17 +68,32 @@ fn part2(input: &Vec<(char, u8)>) -> usize {
     tail_visited.len()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day09;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Vec<(char, u8)>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day09);
 
-    Ok(())
+aoclib::example_tests! {
+    Day09;
+    example1: include_str!("inputs/09.example1.txt"), part1 = 13, part2 = 1;
+    example2: include_str!("inputs/09.example2.txt"), part2 = 36;
 }
 
 fn parse_input(input: Vec<String>) -> Vec<(char, u8)> {
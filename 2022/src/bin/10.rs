@@ -1,6 +1,6 @@
 use std::io;
 
-fn run(input: Vec<Instruction>) -> (i32, Vec<Vec<bool>>) {
+fn run(input: &[Instruction]) -> (i32, Vec<Vec<bool>>) {
     let mut inp_iter = input.iter();
     let mut current_op = None;
     let mut x = 1i32;
@@ -31,23 +31,31 @@ fn run(input: Vec<Instruction>) -> (i32, Vec<Vec<bool>>) {
         }
     }
 
-    println!("Part 1: {res}");
-
-    println!("Part 2:");
-    aoclib::print_bool_matrix(&disp);
-
     (res, disp)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day10;
+
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = aoclib::Answer;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
 
-    run(input);
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        run(input).0
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> aoclib::Answer {
+        aoclib::Answer::bitmap(&run(input).1).read_letters()
+    }
 }
 
+aoclib::aoc_main!(Day10);
+
 #[derive(Clone, Debug)]
 enum Instruction {
     Nop,
@@ -78,7 +86,7 @@ mod test {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(10)).unwrap();
         let input = parse_input(input);
 
-        let (p1, p2) = run(input);
+        let (p1, p2) = run(&input);
         assert_eq!(p1, 13760);
 
         let p2_bin = p2
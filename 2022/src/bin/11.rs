@@ -8,6 +8,7 @@ use nom::{
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
     IResult,
 };
+use std::io;
 
 type Item = usize;
 
@@ -125,18 +126,32 @@ fn run<F: Fn(Item) -> Item>(mut monkeys: Vec<Monkey>, rounds: usize, relax: F) -
     inspections[inspections.len() - 2..].iter().product()
 }
 
-fn main() -> anyhow::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let input = parse_input(&input);
+struct Day11;
 
-    let p1 = run(input.clone(), 20, |i| i / 3);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let modulo: usize = input.iter().map(|m| m.test_divisor).product();
-    let p2 = run(input, 10_000, move |i| i % modulo);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input.clone(), 20, |i| i / 3)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        let modulo: usize = input.iter().map(|m| m.test_divisor).product();
+        run(input.clone(), 10_000, move |i| i % modulo)
+    }
+}
+
+aoclib::aoc_main!(Day11);
 
-    Ok(())
+aoclib::example_tests! {
+    Day11;
+    example1: include_str!("inputs/11.example1.txt"), part1 = 10605, part2 = 2713310158u64;
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoclib::coord2::Coord2;
 use ndarray::Array2;
 use std::{
     collections::{HashSet, VecDeque},
@@ -6,17 +7,6 @@ use std::{
 
 type Coord = (usize, usize);
 
-fn adjacents(x: usize, y: usize, w: usize, h: usize) -> impl Iterator<Item = Coord> {
-    [
-        if x > 0 { Some((x - 1, y)) } else { None },
-        if x < w - 1 { Some((x + 1, y)) } else { None },
-        if y > 0 { Some((x, y - 1)) } else { None },
-        if y < h - 1 { Some((x, y + 1)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
-}
-
 fn run(input: &Array2<u8>, start: Coord, target: Coord) -> Option<usize> {
     let mut q = VecDeque::new();
     let mut visited = HashSet::new();
@@ -30,7 +20,10 @@ fn run(input: &Array2<u8>, start: Coord, target: Coord) -> Option<usize> {
                 return Some(d);
             }
 
-            for (ax, ay) in adjacents(x, y, w, h) {
+            for (ay, ax) in Coord2::from((y, x))
+                .neighbors4((h, w))
+                .map(Coord2::<usize>::usizes)
+            {
                 if !visited.contains(&(ay, ax)) && input[(y, x)] + 1 >= input[(ay, ax)] {
                     q.push_back(((ay, ax), d + 1));
                 }
@@ -54,26 +47,36 @@ fn part2(input: &Array2<u8>, target: Coord) -> usize {
         .expect("No routes found?")
 }
 
-fn main() -> io::Result<()> {
-    let mut input = aoclib::read_input_byte_matrix()?;
-    let (start, sh) = input
-        .indexed_iter_mut()
-        .find(|(_c, el)| el == &&b'S')
-        .unwrap();
-    *sh = b'a';
-    let (target, th) = input
-        .indexed_iter_mut()
-        .find(|(_c, el)| el == &&b'E')
-        .unwrap();
-    *th = b'z';
-
-    let p1 = part1(&input, start, target);
-    println!("Part 1: {}", p1);
-
-    let p2 = part2(&input, target);
-    println!("Part 2: {}", p2);
-
-    Ok(())
+fn parse_input(input: &str) -> io::Result<(Array2<u8>, Coord, Coord)> {
+    let (grid, [start, target]) = aoclib::parse_grid_markers(input, [('S', 'a'), ('E', 'z')])?;
+    Ok((grid, start.into(), target.into()))
+}
+
+struct Day12;
+
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = (Array2<u8>, Coord, Coord);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1((input, start, target): &Self::Input<'_>) -> usize {
+        part1(input, *start, *target)
+    }
+
+    fn part2((input, _, target): &Self::Input<'_>) -> usize {
+        part2(input, *target)
+    }
+}
+
+aoclib::aoc_main!(Day12);
+
+aoclib::example_tests! {
+    Day12;
+    example1: include_str!("inputs/12.example1.txt"), part1 = 31, part2 = 29;
 }
 
 #[cfg(test)]
@@ -81,18 +84,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let mut input = aoclib::read_file_byte_matrix(aoclib::get_test_input_file!(12)).unwrap();
-
-        let (start, sh) = input
-            .indexed_iter_mut()
-            .find(|(_c, el)| el == &&b'S')
-            .unwrap();
-        *sh = b'a';
-        let (target, th) = input
-            .indexed_iter_mut()
-            .find(|(_c, el)| el == &&b'E')
-            .unwrap();
-        *th = b'z';
+        let input = aoclib::read_file_string(aoclib::get_test_input_file!(12)).unwrap();
+        let (input, start, target) = parse_input(&input).unwrap();
 
         let p1 = part1(&input, start, target);
         assert_eq!(p1, 391);
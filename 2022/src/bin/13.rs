@@ -1,12 +1,6 @@
+use aoclib::parse::{int, nested, parse_all, Nested};
 use itertools::Itertools;
-use nom::{
-    branch::alt,
-    character::complete::{char, i32, newline},
-    combinator::{all_consuming, map},
-    multi::{count, separated_list0, separated_list1},
-    sequence::{delimited, separated_pair},
-    IResult,
-};
+use nom::{character::complete::newline, sequence::separated_pair};
 use std::io;
 
 #[derive(PartialEq, Eq, Clone)]
@@ -41,29 +35,23 @@ impl PartialOrd for Item {
     }
 }
 
-fn item(input: &str) -> IResult<&str, Item> {
-    alt((item_number, item_list))(input)
-}
-
-fn item_list(input: &str) -> IResult<&str, Item> {
-    map(
-        delimited(char('['), separated_list0(char(','), item), char(']')),
-        Item::List,
-    )(input)
-}
-
-fn item_number(input: &str) -> IResult<&str, Item> {
-    map(i32, Item::Number)(input)
-}
-
-fn item_pair(input: &str) -> IResult<&str, (Item, Item)> {
-    separated_pair(item_list, newline, item_list)(input)
+impl From<Nested<i32>> for Item {
+    fn from(value: Nested<i32>) -> Self {
+        match value {
+            Nested::Item(n) => Item::Number(n),
+            Nested::List(l) => Item::List(l.into_iter().map(Item::from).collect()),
+        }
+    }
 }
 
-fn parse_input(input: &str) -> Vec<(Item, Item)> {
-    all_consuming(separated_list1(count(newline, 2), item_pair))(input.trim_end())
-        .unwrap()
-        .1
+fn parse_input(input: &str) -> io::Result<Vec<(Item, Item)>> {
+    aoclib::split_sections(input)
+        .into_iter()
+        .map(|pair| {
+            let (l, r) = parse_all(separated_pair(nested(int), newline, nested(int)), pair)?;
+            Ok((l.into(), r.into()))
+        })
+        .collect()
 }
 
 fn part1(input: &[(Item, Item)]) -> usize {
@@ -95,17 +83,31 @@ fn part2(input: Vec<(Item, Item)>) -> usize {
     div1_idx * div2_idx
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let input = parse_input(&input);
+struct Day13;
+
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Vec<(Item, Item)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
+}
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day13);
 
-    Ok(())
+aoclib::example_tests! {
+    Day13;
+    example1: include_str!("inputs/13.example1.txt"), part1 = 13, part2 = 140;
 }
 
 #[cfg(test)]
@@ -114,7 +116,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = std::fs::read_to_string(aoclib::get_test_input_file!(13)).unwrap();
-        let input = parse_input(&input);
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 5905);
@@ -113,19 +113,31 @@ fn parse_input(lines: Vec<String>) -> Array2<u8> {
     arr
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day14;
 
-    //print_map(&input);
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = Array2<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(input.clone());
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
+}
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day14);
 
-    Ok(())
+aoclib::example_tests! {
+    Day14;
+    example1: include_str!("inputs/14.example1.txt"), part1 = 24, part2 = 93;
 }
 
 pub fn print_map(mtx: &Array2<u8>) {
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use arrayvec::ArrayVec;
 use itertools::Itertools;
 use std::{io, ops::RangeInclusive};
@@ -111,46 +112,52 @@ fn part2(input: &[Sensor], test_mode: bool) -> u64 {
     unreachable!()
 }
 
-fn parse_input(input: Vec<String>) -> ArrayVec<Sensor, MAX_SENSORS> {
+fn parse_input(input: Vec<String>) -> Result<ArrayVec<Sensor, MAX_SENSORS>, InputError> {
     input
-        .into_iter()
-        .filter_map(|l| {
-            aoclib::read_ints_from_string(&l, true)
-                .into_iter()
-                .collect_tuple()
-        })
-        .map(|(x, y, bx, by)| Sensor {
-            x,
-            y,
-            bx,
-            by,
-            r: dist(x, y, bx, by),
+        .iter()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let [x, y, bx, by] = aoclib::parse_int_array(l, true)?;
+            Ok(Sensor {
+                x,
+                y,
+                bx,
+                by,
+                r: dist(x, y, bx, by),
+            })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let test_mode = std::env::var("TEST_MODE").is_ok();
+struct Day15;
 
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = ArrayVec<Sensor, MAX_SENSORS>;
+    type Part1 = usize;
+    type Part2 = u64;
 
-    let p1 = part1(&input, test_mode);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
 
-    let p2 = part2(&input, test_mode);
-    println!("Part 2: {}", p2);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input, std::env::var("TEST_MODE").is_ok())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        part2(input, std::env::var("TEST_MODE").is_ok())
+    }
 }
 
+aoclib::aoc_main!(Day15);
+
 #[cfg(test)]
 mod test {
     use super::*;
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(15)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input, false);
         assert_eq!(p1, 5688618);
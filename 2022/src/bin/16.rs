@@ -5,7 +5,13 @@ use std::fmt::Debug;
 use std::iter::repeat;
 use std::{collections::HashMap, io};
 
-const INPUT_RE: &str = r"Valve (\w{2}) has flow rate=(\d+); tunnels? leads? to valves? ([\w, ]+)";
+aoclib::line_pattern! {
+    struct Valve {
+        name: String,
+        flow_rate: usize,
+        tunnels: Vec<String>,
+    } = "Valve {name} has flow rate={flow_rate}; {_} valve{_} {tunnels:list(\", \")}";
+}
 
 type DistanceMatrix = Array2<usize>;
 
@@ -133,36 +139,46 @@ fn part2(nodes: &HashMap<u8, Node>, dmtx: &DistanceMatrix, start_node: u8) -> us
     )
 }
 
-fn main() -> io::Result<()> {
-    let (nodes, dmtx, start) = parse_input(aoclib::read_input_lines()?);
+struct Day16;
+
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = (HashMap<u8, Node>, DistanceMatrix, u8);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let part1 = part1(&nodes, &dmtx, start);
-    println!("Part 1: {}", part1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::pattern::parse_lines(input)?))
+    }
 
-    let part2 = part2(&nodes, &dmtx, start);
-    println!("Part 2: {}", part2);
+    fn part1((nodes, dmtx, start): &Self::Input<'_>) -> usize {
+        part1(nodes, dmtx, *start)
+    }
 
-    Ok(())
+    fn part2((nodes, dmtx, start): &Self::Input<'_>) -> usize {
+        part2(nodes, dmtx, *start)
+    }
 }
 
-fn parse_input(input: Vec<String>) -> (HashMap<u8, Node>, DistanceMatrix, u8) {
-    let re = regex::Regex::new(INPUT_RE).unwrap();
+aoclib::aoc_main!(Day16);
+
+aoclib::example_tests! {
+    Day16;
+    example1: include_str!("inputs/16.example1.txt"), part1 = 1651, part2 = 1707;
+}
 
+fn parse_input(input: Vec<Valve>) -> (HashMap<u8, Node>, DistanceMatrix, u8) {
     let nodes = input
         .into_iter()
         .enumerate()
-        .map(|(i, l)| {
-            let matches = re.captures(&l).expect("No regex matches");
-            let str_id = matches[1].to_string();
-            let flow_rate = matches[2].parse().expect("Failed to parse flow rate");
-            let paths: Vec<_> = matches[3].split(", ").map(|s| (s.to_string(), 1)).collect();
+        .map(|(i, v)| {
+            let paths: Vec<_> = v.tunnels.into_iter().map(|s| (s, 1)).collect();
 
             (
-                str_id,
+                v.name,
                 paths,
                 Node {
                     id: i as u8,
-                    flow_rate,
+                    flow_rate: v.flow_rate,
                     connected_nodes: HashMap::new(),
                 },
             )
@@ -248,9 +264,9 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(16)).unwrap();
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(16)).unwrap();
 
-        let (nodes, dmtx, start) = parse_input(input);
+        let (nodes, dmtx, start) = parse_input(aoclib::pattern::parse_lines(&input).unwrap());
 
         let p1 = part1(&nodes, &dmtx, start);
         assert_eq!(p1, 1595);
@@ -232,17 +232,31 @@ fn part2(input: &[char]) -> usize {
     (MAX_HEIGHT - loop_start_y) + dy_skip + dy_rem
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let input: Vec<_> = input.trim().chars().collect();
+struct Day17;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day17 {
+    type Input<'a> = Vec<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.to_owned().trim().chars().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day17);
 
-    Ok(())
+aoclib::example_tests! {
+    Day17;
+    example1: ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>", part1 = 3068, part2 = 1514285714288u64;
 }
 
 #[cfg(test)]
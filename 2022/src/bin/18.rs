@@ -1,3 +1,5 @@
+use aoclib::coord3::Coord3;
+use aoclib::InputError;
 use itertools::Itertools;
 use ndarray::Array3;
 use std::{
@@ -5,78 +7,61 @@ use std::{
     io,
 };
 
-type Coord = (usize, usize, usize);
+type Coord = Coord3<usize>;
 
-fn neighbours_3d(c: Coord, dims: Coord) -> Vec<Coord> {
-    let (z, y, x) = c;
-    let mut res = vec![];
-    if z > 0 {
-        res.push((z - 1, y, x));
-    }
-    if z < dims.0 - 1 {
-        res.push((z + 1, y, x));
-    }
-
-    if y > 0 {
-        res.push((z, y - 1, x));
-    }
-    if y < dims.1 - 1 {
-        res.push((z, y + 1, x));
-    }
+fn neighbours_3d(c: Coord, grid: &Array3<u8>) -> impl Iterator<Item = Coord> {
+    c.neighbors6_within(grid.dim())
+}
 
-    if x > 0 {
-        res.push((z, y, x - 1));
-    }
-    if x < dims.2 - 1 {
-        res.push((z, y, x + 1));
-    }
+fn cells(grid: &Array3<u8>, value: u8) -> impl Iterator<Item = Coord> + '_ {
+    grid.indexed_iter()
+        .filter(move |(_, v)| **v == value)
+        .map(|((z, y, x), _)| Coord3 { x, y, z })
+}
 
-    res
+fn max_coord(input: &[Coord]) -> Coord {
+    input.iter().fold(Coord::ZERO, |m, c| Coord3 {
+        x: m.x.max(c.x),
+        y: m.y.max(c.y),
+        z: m.z.max(c.z),
+    })
 }
 
-fn part1(input: &Vec<Coord>) -> usize {
-    let max_x = input.iter().map(|(x, _, _)| x).max().unwrap();
-    let max_y = input.iter().map(|(_, y, _)| y).max().unwrap();
-    let max_z = input.iter().map(|(_, _, z)| z).max().unwrap();
+fn part1(input: &[Coord]) -> usize {
+    let max = max_coord(input);
 
-    let mut grid: Array3<u8> = Array3::zeros((max_z + 1, max_y + 1, max_x + 1));
+    let mut grid: Array3<u8> = Array3::zeros((max.z + 1, max.y + 1, max.x + 1));
 
-    for (x, y, z) in input {
-        grid[(*z, *y, *x)] = 1;
+    for c in input {
+        grid[*c] = 1;
     }
 
-    grid.indexed_iter()
-        .filter(|(_, v)| **v > 0)
-        .map(|(c, _)| {
-            let pop_neighs = neighbours_3d(c, grid.dim())
-                .into_iter()
-                .filter(|n| grid[*n] > 0)
-                .count();
+    cells(&grid, 1)
+        .map(|c| {
+            let pop_neighs = neighbours_3d(c, &grid).filter(|n| grid[*n] > 0).count();
             6 - pop_neighs
         })
         .sum()
 }
 
-fn part2(input: &Vec<Coord>) -> usize {
-    let max_x = input.iter().map(|(x, _, _)| x).max().unwrap();
-    let max_y = input.iter().map(|(_, y, _)| y).max().unwrap();
-    let max_z = input.iter().map(|(_, _, z)| z).max().unwrap();
+fn part2(input: &[Coord]) -> usize {
+    let max = max_coord(input);
 
-    let mut grid: Array3<u8> = Array3::zeros((max_z + 3, max_y + 3, max_x + 3));
+    let mut grid: Array3<u8> = Array3::zeros((max.z + 3, max.y + 3, max.x + 3));
 
-    for (x, y, z) in input {
-        grid[(*z + 1, *y + 1, *x + 1)] = 1;
+    let one = Coord3 { x: 1, y: 1, z: 1 };
+    for c in input {
+        grid[*c + one] = 1;
     }
 
-    let dims = grid.dim();
     let mut q = VecDeque::new();
     let mut visited = HashSet::new();
-    q.push_back((0, 0, 0));
+    q.push_back(Coord3::ZERO);
 
     while let Some(c) = q.pop_front() {
         visited.insert(c);
         if grid[c] == 0 {
-            let neihgs = neighbours_3d(c, dims);
+            let neihgs = neighbours_3d(c, &grid).collect_vec();
             for n in neihgs {
                 if grid[n] == 1 {
                     grid[c] = 2;
@@ -87,36 +72,46 @@ fn part2(input: &Vec<Coord>) -> usize {
         }
     }
 
-    grid.indexed_iter()
-        .filter(|(_, v)| **v == 2)
-        .map(|(c, _)| {
-            neighbours_3d(c, grid.dim())
-                .into_iter()
-                .filter(|n| grid[*n] == 1)
-                .count()
-        })
+    cells(&grid, 2)
+        .map(|c| neighbours_3d(c, &grid).filter(|n| grid[*n] == 1).count())
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input: Vec<Coord> = input
-        .into_iter()
+fn parse_input(input: Vec<String>) -> Result<Vec<Coord>, InputError> {
+    input
+        .iter()
         .map(|l| {
-            l.split(',')
-                .filter_map(|n| n.parse::<usize>().ok())
-                .collect_tuple()
-                .unwrap()
+            let [x, y, z] = aoclib::parse_int_array(l, false)?;
+            Ok(Coord3 { x, y, z })
         })
-        .collect();
+        .collect()
+}
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+struct Day18;
+
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = Vec<Coord>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day18);
 
-    Ok(())
+aoclib::example_tests! {
+    Day18;
+    example1: include_str!("inputs/18.example1.txt"), part1 = 64, part2 = 58;
 }
 
 #[cfg(test)]
@@ -125,15 +120,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(18)).unwrap();
-        let input: Vec<Coord> = input
-            .into_iter()
-            .map(|l| {
-                l.split(',')
-                    .filter_map(|n| n.parse::<usize>().ok())
-                    .collect_tuple()
-                    .unwrap()
-            })
-            .collect();
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 3526);
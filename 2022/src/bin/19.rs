@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use std::io;
 
 #[derive(Debug)]
@@ -176,33 +177,48 @@ fn part2(input: &[Blueprint]) -> usize {
         .product()
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Blueprint> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Blueprint>, InputError> {
     input
-        .into_iter()
-        .map(|l| aoclib::read_ints_from_string(&l, false))
-        .map(|nums| {
-            assert_eq!(7, nums.len());
-            Blueprint {
-                id: nums[0],
-                ore_cost: nums[1],
-                clay_cost: nums[2],
-                obsidian_cost: (nums[3], nums[4]),
-                geode_cost: (nums[5], nums[6]),
-            }
+        .iter()
+        .map(|l| {
+            let [id, ore, clay, obs_ore, obs_clay, geode_ore, geode_obs] =
+                aoclib::parse_int_array(l, false)?;
+            Ok(Blueprint {
+                id,
+                ore_cost: ore,
+                clay_cost: clay,
+                obsidian_cost: (obs_ore, obs_clay),
+                geode_cost: (geode_ore, geode_obs),
+            })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(aoclib::read_input_lines()?);
+struct Day19;
+
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day19);
 
-    Ok(())
+aoclib::example_tests! {
+    Day19;
+    example1: include_str!("inputs/19.example1.txt"), part1 = 33;
 }
 
 #[cfg(test)]
@@ -211,7 +227,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(19)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 1266);
@@ -22,16 +22,31 @@ fn run(input: &[i64], rounds: usize, key: i64) -> i64 {
         + ll[(zero_idx + 3000) % input.len()].1
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_ints(true)?;
+struct Day20;
 
-    let p1 = run(&input, 1, 1);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
 
-    let p2 = run(&input, 10, 811589153);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, true)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        run(input, 1, 1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        run(input, 10, 811589153)
+    }
+}
+
+aoclib::aoc_main!(Day20);
 
-    Ok(())
+aoclib::example_tests! {
+    Day20;
+    example1: include_str!("inputs/20.example1.txt"), part1 = 3, part2 = 1623178306;
 }
 
 #[cfg(test)]
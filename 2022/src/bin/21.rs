@@ -152,16 +152,31 @@ fn parse_input(lines: Vec<String>) -> HashMap<String, Monkey> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(aoclib::read_input_lines()?);
+struct Day21;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = HashMap<String, Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day21);
 
-    Ok(())
+aoclib::example_tests! {
+    Day21;
+    example1: include_str!("inputs/21.example1.txt"), part1 = 152, part2 = 301;
 }
 
 #[cfg(test)]
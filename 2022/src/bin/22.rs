@@ -1,68 +1,53 @@
+use aoclib::parse::{int, parse_all};
+use aoclib::Dir4;
 use ndarray::{s, Array2, ArrayView2};
-use nom::{
-    branch::alt,
-    character::complete::char,
-    character::complete::u32,
-    combinator::{all_consuming, map},
-    multi::many1,
-    IResult,
-};
+use nom::{branch::alt, character::complete::char, combinator::map, multi::many1, IResult};
 use std::io;
 
-#[derive(Copy, Clone, Debug)]
-enum Dir {
-    Right = 0,
-    Down = 1,
-    Left = 2,
-    Up = 3,
+/// Turning on the map after it has been folded into a cube
+trait CubeDir {
+    fn ccw_on_cube(self, side: CubeSide) -> Self;
+    fn cw_on_cube(self, side: CubeSide) -> Self;
+    fn flip_horizontal(self) -> Self;
+    fn flip_vertical(self) -> Self;
+    fn facing(self) -> usize;
 }
-impl Dir {
-    fn ccw(&self) -> Dir {
-        match self {
-            Dir::Up => Dir::Left,
-            Dir::Left => Dir::Down,
-            Dir::Down => Dir::Right,
-            Dir::Right => Dir::Up,
-        }
-    }
-
-    fn cw(&self) -> Dir {
-        match self {
-            Dir::Up => Dir::Right,
-            Dir::Left => Dir::Up,
-            Dir::Down => Dir::Left,
-            Dir::Right => Dir::Down,
-        }
-    }
 
-    fn ccw_on_cube(&self, side: CubeSide) -> Self {
+impl CubeDir for Dir4 {
+    fn ccw_on_cube(self, side: CubeSide) -> Self {
         match side {
             CubeSide::Right | CubeSide::Back | CubeSide::Bottom => self.cw(),
             _ => self.ccw(),
         }
     }
 
-    fn cw_on_cube(&self, side: CubeSide) -> Self {
+    fn cw_on_cube(self, side: CubeSide) -> Self {
         match side {
             CubeSide::Right | CubeSide::Back | CubeSide::Bottom => self.ccw(),
             _ => self.cw(),
         }
     }
 
-    fn flip_horizontal(&self) -> Self {
-        match self {
-            Dir::Right => Dir::Left,
-            Dir::Left => Dir::Right,
-            d => *d,
+    fn flip_horizontal(self) -> Self {
+        if self.is_horizontal() {
+            self.reverse()
+        } else {
+            self
         }
     }
-    fn flip_vertical(&self) -> Self {
-        match self {
-            Dir::Down => Dir::Up,
-            Dir::Up => Dir::Down,
-            d => *d,
+
+    fn flip_vertical(self) -> Self {
+        if self.is_vertical() {
+            self.reverse()
+        } else {
+            self
         }
     }
+
+    /// The facing value of the password, counted clockwise from right
+    fn facing(self) -> usize {
+        (self as usize + 3) % 4
+    }
 }
 
 fn turn(input: &str) -> IResult<&str, Instruction> {
@@ -73,50 +58,50 @@ fn turn(input: &str) -> IResult<&str, Instruction> {
 }
 
 fn moven(input: &str) -> IResult<&str, Instruction> {
-    map(u32, |n| Instruction::Move(n as usize))(input)
+    map(int, Instruction::Move)(input)
 }
 
-fn parse_instructions(input: &str) -> Vec<Instruction> {
-    all_consuming(many1(alt((turn, moven))))(input).unwrap().1
+fn parse_instructions(input: &str) -> io::Result<Vec<Instruction>> {
+    Ok(parse_all(many1(alt((turn, moven))), input)?)
 }
 
-fn wrapping_adjacent(dir: Dir, y: usize, x: usize, h: usize, w: usize) -> (usize, usize) {
+fn wrapping_adjacent(dir: Dir4, y: usize, x: usize, h: usize, w: usize) -> (usize, usize) {
     match dir {
-        Dir::Up if y > 0 => (y - 1, x),
-        Dir::Up => (h - 1, x),
-        Dir::Down if y < h - 1 => (y + 1, x),
-        Dir::Down => (0, x),
-        Dir::Left if x > 0 => (y, x - 1),
-        Dir::Left => (y, w - 1),
-        Dir::Right if x < w - 1 => (y, x + 1),
-        Dir::Right => (y, 0),
+        Dir4::Up if y > 0 => (y - 1, x),
+        Dir4::Up => (h - 1, x),
+        Dir4::Down if y < h - 1 => (y + 1, x),
+        Dir4::Down => (0, x),
+        Dir4::Left if x > 0 => (y, x - 1),
+        Dir4::Left => (y, w - 1),
+        Dir4::Right if x < w - 1 => (y, x + 1),
+        Dir4::Right => (y, 0),
     }
 }
 
-fn find_first_grid_pos(dir: Dir, y: usize, x: usize, map: &Array2<char>) -> (usize, usize) {
+fn find_first_grid_pos(dir: Dir4, y: usize, x: usize, map: &Array2<char>) -> (usize, usize) {
     let (h, w) = map.dim();
     match dir {
-        Dir::Up => (
+        Dir4::Up => (
             (0..h)
                 .rev()
                 .find(|yy| map[(*yy, x)] != ' ' && map[(*yy, x)] != char::default())
                 .unwrap(),
             x,
         ),
-        Dir::Down => (
+        Dir4::Down => (
             (0..h)
                 .find(|yy| map[(*yy, x)] != ' ' && map[(*yy, x)] != char::default())
                 .unwrap(),
             x,
         ),
-        Dir::Left => (
+        Dir4::Left => (
             y,
             (0..w)
                 .rev()
                 .find(|xx| map[(y, *xx)] != ' ' && map[(y, *xx)] != char::default())
                 .unwrap(),
         ),
-        Dir::Right => (
+        Dir4::Right => (
             y,
             (0..w)
                 .find(|xx| map[(y, *xx)] != ' ' && map[(y, *xx)] != char::default())
@@ -125,7 +110,7 @@ fn find_first_grid_pos(dir: Dir, y: usize, x: usize, map: &Array2<char>) -> (usi
     }
 }
 
-fn mov(dir: Dir, d: usize, pos: (usize, usize), map: &Array2<char>) -> Option<(usize, usize)> {
+fn mov(dir: Dir4, d: usize, pos: (usize, usize), map: &Array2<char>) -> Option<(usize, usize)> {
     let (y, x) = pos;
     let (h, w) = map.dim();
 
@@ -142,7 +127,7 @@ fn mov(dir: Dir, d: usize, pos: (usize, usize), map: &Array2<char>) -> Option<(u
 }
 
 fn part1(map: &Array2<char>, instructions: &Vec<Instruction>) -> usize {
-    let mut dir = Dir::Right;
+    let mut dir = Dir4::Right;
     let mut pos = (
         0,
         map.row(0)
@@ -160,7 +145,7 @@ fn part1(map: &Array2<char>, instructions: &Vec<Instruction>) -> usize {
         };
     }
 
-    (pos.0 + 1) * 1000 + (pos.1 + 1) * 4 + dir as usize
+    (pos.0 + 1) * 1000 + (pos.1 + 1) * 4 + dir.facing()
 }
 
 struct Cube {
@@ -224,12 +209,12 @@ fn rotate_cw(arr: ArrayView2<char>) -> Array2<char> {
 }
 
 fn cube_adjacent(
-    dir: Dir,
+    dir: Dir4,
     cube_side: CubeSide,
     y: usize,
     x: usize,
     cube: &Cube,
-) -> (Dir, CubeSide, usize, usize) {
+) -> (Dir4, CubeSide, usize, usize) {
     let side_map = match cube_side {
         CubeSide::Top => &cube.top,
         CubeSide::Bottom => &cube.bottom,
@@ -241,56 +226,56 @@ fn cube_adjacent(
     let w = side_map.dim().0;
 
     match dir {
-        Dir::Right if x < w - 1 => (dir, cube_side, y, x + 1),
-        Dir::Left if x > 0 => (dir, cube_side, y, x - 1),
-        Dir::Down if y < w - 1 => (dir, cube_side, y + 1, x),
-        Dir::Up if y > 0 => (dir, cube_side, y - 1, x),
-
-        Dir::Right => match cube_side {
-            CubeSide::Top => (Dir::Down, CubeSide::Right, 0, y),
-            CubeSide::Bottom => (Dir::Up, CubeSide::Right, w - 1, y),
-            CubeSide::Left => (Dir::Right, CubeSide::Front, y, 0),
-            CubeSide::Right => (Dir::Left, CubeSide::Front, y, w - 1),
-            CubeSide::Front => (Dir::Left, CubeSide::Right, y, w - 1),
-            CubeSide::Back => (Dir::Right, CubeSide::Right, y, 0),
+        Dir4::Right if x < w - 1 => (dir, cube_side, y, x + 1),
+        Dir4::Left if x > 0 => (dir, cube_side, y, x - 1),
+        Dir4::Down if y < w - 1 => (dir, cube_side, y + 1, x),
+        Dir4::Up if y > 0 => (dir, cube_side, y - 1, x),
+
+        Dir4::Right => match cube_side {
+            CubeSide::Top => (Dir4::Down, CubeSide::Right, 0, y),
+            CubeSide::Bottom => (Dir4::Up, CubeSide::Right, w - 1, y),
+            CubeSide::Left => (Dir4::Right, CubeSide::Front, y, 0),
+            CubeSide::Right => (Dir4::Left, CubeSide::Front, y, w - 1),
+            CubeSide::Front => (Dir4::Left, CubeSide::Right, y, w - 1),
+            CubeSide::Back => (Dir4::Right, CubeSide::Right, y, 0),
         },
 
-        Dir::Left => match cube_side {
-            CubeSide::Top => (Dir::Down, CubeSide::Left, 0, y),
-            CubeSide::Bottom => (Dir::Up, CubeSide::Left, w - 1, y),
-            CubeSide::Left => (Dir::Right, CubeSide::Back, y, 0),
-            CubeSide::Right => (Dir::Left, CubeSide::Back, y, w - 1),
-            CubeSide::Front => (Dir::Left, CubeSide::Left, y, w - 1),
-            CubeSide::Back => (Dir::Right, CubeSide::Left, y, 0),
+        Dir4::Left => match cube_side {
+            CubeSide::Top => (Dir4::Down, CubeSide::Left, 0, y),
+            CubeSide::Bottom => (Dir4::Up, CubeSide::Left, w - 1, y),
+            CubeSide::Left => (Dir4::Right, CubeSide::Back, y, 0),
+            CubeSide::Right => (Dir4::Left, CubeSide::Back, y, w - 1),
+            CubeSide::Front => (Dir4::Left, CubeSide::Left, y, w - 1),
+            CubeSide::Back => (Dir4::Right, CubeSide::Left, y, 0),
         },
 
-        Dir::Up => match cube_side {
-            CubeSide::Top => (Dir::Down, CubeSide::Back, 0, x),
-            CubeSide::Bottom => (Dir::Up, CubeSide::Back, w - 1, x),
-            CubeSide::Left => (Dir::Right, CubeSide::Top, x, 0),
-            CubeSide::Right => (Dir::Left, CubeSide::Top, x, w - 1),
-            CubeSide::Front => (Dir::Up, CubeSide::Top, w - 1, x),
-            CubeSide::Back => (Dir::Down, CubeSide::Top, 0, x),
+        Dir4::Up => match cube_side {
+            CubeSide::Top => (Dir4::Down, CubeSide::Back, 0, x),
+            CubeSide::Bottom => (Dir4::Up, CubeSide::Back, w - 1, x),
+            CubeSide::Left => (Dir4::Right, CubeSide::Top, x, 0),
+            CubeSide::Right => (Dir4::Left, CubeSide::Top, x, w - 1),
+            CubeSide::Front => (Dir4::Up, CubeSide::Top, w - 1, x),
+            CubeSide::Back => (Dir4::Down, CubeSide::Top, 0, x),
         },
 
-        Dir::Down => match cube_side {
-            CubeSide::Top => (Dir::Down, CubeSide::Front, 0, x),
-            CubeSide::Bottom => (Dir::Up, CubeSide::Front, w - 1, x),
-            CubeSide::Left => (Dir::Right, CubeSide::Bottom, x, 0),
-            CubeSide::Right => (Dir::Left, CubeSide::Bottom, x, w - 1),
-            CubeSide::Front => (Dir::Up, CubeSide::Bottom, w - 1, x),
-            CubeSide::Back => (Dir::Down, CubeSide::Bottom, 0, x),
+        Dir4::Down => match cube_side {
+            CubeSide::Top => (Dir4::Down, CubeSide::Front, 0, x),
+            CubeSide::Bottom => (Dir4::Up, CubeSide::Front, w - 1, x),
+            CubeSide::Left => (Dir4::Right, CubeSide::Bottom, x, 0),
+            CubeSide::Right => (Dir4::Left, CubeSide::Bottom, x, w - 1),
+            CubeSide::Front => (Dir4::Up, CubeSide::Bottom, w - 1, x),
+            CubeSide::Back => (Dir4::Down, CubeSide::Bottom, 0, x),
         },
     }
 }
 
 fn mov_cube(
-    dir: Dir,
+    dir: Dir4,
     d: usize,
     cube_side: CubeSide,
     pos: (usize, usize),
     cube: &Cube,
-) -> Option<(Dir, CubeSide, (usize, usize))> {
+) -> Option<(Dir4, CubeSide, (usize, usize))> {
     let side_map = match cube_side {
         CubeSide::Top => &cube.top,
         CubeSide::Bottom => &cube.bottom,
@@ -369,7 +354,7 @@ fn part2(map: &Array2<char>, instructions: &Vec<Instruction>) -> usize {
         panic!("Unsupported map");
     };
 
-    let mut dir = Dir::Right;
+    let mut dir = Dir4::Right;
     let mut cube_side = CubeSide::Top;
     let mut pos = (0, 0);
 
@@ -407,7 +392,7 @@ fn part2(map: &Array2<char>, instructions: &Vec<Instruction>) -> usize {
         unreachable!()
     };
 
-    (y + 1) * 1000 + (x + 1) * 4 + dir as usize
+    (y + 1) * 1000 + (x + 1) * 4 + dir.facing()
 }
 
 #[derive(Debug)]
@@ -417,19 +402,36 @@ enum Instruction {
     Move(usize),
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let (map, instr_str) = input.split_once("\n\n").unwrap();
-    let map = aoclib::read_string_char_matrix(map.trim_end())?;
-    let instructions = parse_instructions(instr_str.trim());
+struct Day22;
 
-    let p1 = part1(&map, &instructions);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = (Array2<char>, Vec<Instruction>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let (map, instr_str) = input
+            .split_once("\n\n")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No instructions"))?;
+        let map = aoclib::read_string_char_matrix(map.trim_end())?;
+        let instructions = parse_instructions(instr_str.trim())?;
+        Ok((map, instructions))
+    }
+
+    fn part1((map, instructions): &Self::Input<'_>) -> usize {
+        part1(map, instructions)
+    }
+
+    fn part2((map, instructions): &Self::Input<'_>) -> usize {
+        part2(map, instructions)
+    }
+}
 
-    let p2 = part2(&map, &instructions);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day22);
 
-    Ok(())
+aoclib::example_tests! {
+    Day22;
+    example1: include_str!("inputs/22.example1.txt"), part1 = 6032;
 }
 
 #[cfg(test)]
@@ -440,7 +442,7 @@ mod test {
         let input = std::fs::read_to_string(aoclib::get_test_input_file!(22)).unwrap();
         let (map, instr_str) = input.split_once("\n\n").unwrap();
         let map = aoclib::read_string_char_matrix(map.trim_end()).unwrap();
-        let instructions = parse_instructions(instr_str.trim());
+        let instructions = parse_instructions(instr_str.trim()).unwrap();
 
         let p1 = part1(&map, &instructions);
         assert_eq!(p1, 164014);
@@ -145,16 +145,31 @@ fn parse_input(input: Array2<char>) -> Vec<Coord> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(aoclib::read_input_char_matrix()?);
+struct Day23;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = Vec<Coord>;
+    type Part1 = i32;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day23);
 
-    Ok(())
+aoclib::example_tests! {
+    Day23;
+    example1: include_str!("inputs/23.example1.txt"), part1 = 110, part2 = 20;
 }
 
 #[cfg(test)]
@@ -127,16 +127,31 @@ fn parse_input(arr: Array2<char>) -> (Vec<Blizzard>, usize, usize) {
     )
 }
 
-fn main() -> io::Result<()> {
-    let (input, h, w) = parse_input(aoclib::read_input_char_matrix()?);
+struct Day24;
 
-    let p1 = run(&input, h, w, 1);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = (Vec<Blizzard>, usize, usize);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&input, h, w, 3);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1((input, h, w): &Self::Input<'_>) -> usize {
+        run(input, *h, *w, 1)
+    }
+
+    fn part2((input, h, w): &Self::Input<'_>) -> usize {
+        run(input, *h, *w, 3)
+    }
+}
+
+aoclib::aoc_main!(Day24);
 
-    Ok(())
+aoclib::example_tests! {
+    Day24;
+    example1: include_str!("inputs/24.example1.txt"), part1 = 18, part2 = 54;
 }
 
 #[cfg(test)]
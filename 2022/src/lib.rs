@@ -0,0 +1,106 @@
+//! Advent of Code 2022 solutions, registered for the `aoc` runner.
+
+use aoclib::solution::Entry;
+
+#[path = "bin/01.rs"]
+pub mod day01;
+
+#[path = "bin/02.rs"]
+pub mod day02;
+
+#[path = "bin/03.rs"]
+pub mod day03;
+
+#[path = "bin/04.rs"]
+pub mod day04;
+
+#[path = "bin/05.rs"]
+pub mod day05;
+
+#[path = "bin/06.rs"]
+pub mod day06;
+
+#[path = "bin/07.rs"]
+pub mod day07;
+
+#[path = "bin/08.rs"]
+pub mod day08;
+
+#[path = "bin/09.rs"]
+pub mod day09;
+
+#[path = "bin/10.rs"]
+pub mod day10;
+
+#[path = "bin/11.rs"]
+pub mod day11;
+
+#[path = "bin/12.rs"]
+pub mod day12;
+
+#[path = "bin/13.rs"]
+pub mod day13;
+
+#[path = "bin/14.rs"]
+pub mod day14;
+
+#[path = "bin/15.rs"]
+pub mod day15;
+
+#[path = "bin/16.rs"]
+pub mod day16;
+
+#[path = "bin/17.rs"]
+pub mod day17;
+
+#[path = "bin/18.rs"]
+pub mod day18;
+
+#[path = "bin/19.rs"]
+pub mod day19;
+
+#[path = "bin/20.rs"]
+pub mod day20;
+
+#[path = "bin/21.rs"]
+pub mod day21;
+
+#[path = "bin/22.rs"]
+pub mod day22;
+
+#[path = "bin/23.rs"]
+pub mod day23;
+
+#[path = "bin/24.rs"]
+pub mod day24;
+
+#[path = "bin/25.rs"]
+pub mod day25;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2022, 1, day01::SOLUTION),
+    Entry::new(2022, 2, day02::SOLUTION),
+    Entry::new(2022, 3, day03::SOLUTION),
+    Entry::new(2022, 4, day04::SOLUTION),
+    Entry::new(2022, 5, day05::SOLUTION),
+    Entry::new(2022, 6, day06::SOLUTION),
+    Entry::new(2022, 7, day07::SOLUTION),
+    Entry::new(2022, 8, day08::SOLUTION),
+    Entry::new(2022, 9, day09::SOLUTION),
+    Entry::new(2022, 10, day10::SOLUTION),
+    Entry::new(2022, 11, day11::SOLUTION),
+    Entry::new(2022, 12, day12::SOLUTION),
+    Entry::new(2022, 13, day13::SOLUTION),
+    Entry::new(2022, 14, day14::SOLUTION),
+    Entry::new(2022, 15, day15::SOLUTION),
+    Entry::new(2022, 16, day16::SOLUTION),
+    Entry::new(2022, 17, day17::SOLUTION),
+    Entry::new(2022, 18, day18::SOLUTION),
+    Entry::new(2022, 19, day19::SOLUTION),
+    Entry::new(2022, 20, day20::SOLUTION),
+    Entry::new(2022, 21, day21::SOLUTION),
+    Entry::new(2022, 22, day22::SOLUTION),
+    Entry::new(2022, 23, day23::SOLUTION),
+    Entry::new(2022, 24, day24::SOLUTION),
+    Entry::new(2022, 25, day25::SOLUTION),
+];
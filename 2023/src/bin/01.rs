@@ -1,11 +1,6 @@
 use lazy_static::lazy_static;
-use regex::Regex;
 use std::{collections::HashMap, io};
 
-lazy_static! {
-    static ref DIGIT_REGEX: Regex =
-        Regex::new(r"(\d|one|two|three|four|five|six|seven|eight|nine)").unwrap();
-}
 lazy_static! {
     static ref DIGIT_WORDS: HashMap<&'static str, u32> = [
         ("one", 1),
@@ -31,10 +26,11 @@ fn part1(input: &[String]) -> u32 {
 }
 
 fn find_first_last_digit(s: &str) -> (u32, u32) {
-    let first = DIGIT_REGEX.find(s).unwrap().as_str();
+    let digit_regex = aoclib::regex!(r"(\d|one|two|three|four|five|six|seven|eight|nine)");
+    let first = digit_regex.find(s).unwrap().as_str();
     let last = (0..s.len())
         .rev()
-        .find_map(|i| DIGIT_REGEX.find_at(s, i))
+        .find_map(|i| digit_regex.find_at(s, i))
         .unwrap()
         .as_str();
 
@@ -58,16 +54,32 @@ fn part2(input: &[String]) -> u32 {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day01;
+
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_string_lines(input))
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u32 {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day01);
 
-    Ok(())
+aoclib::example_tests! {
+    Day01;
+    example1: include_str!("inputs/01.example1.txt"), part1 = 142;
+    example2: include_str!("inputs/01.example2.txt"), part2 = 281;
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use std::io;
 
 struct Game {
@@ -26,45 +27,59 @@ fn part2(input: &[Game]) -> usize {
         .sum()
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Game> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Game>, InputError> {
     input
         .into_iter()
         .map(|l| {
             let (left, right) = aoclib::split_to_tuple2(&l, ": ").unwrap();
-            let id = *aoclib::read_ints_from_string(left, false).first().unwrap();
+            let [id] = aoclib::parse_int_array(left, false)?;
             let reveals = right
                 .split("; ")
                 .map(|r| {
-                    r.split(", ").fold((0, 0, 0), |(ar, ag, ab), c| {
-                        let num = *aoclib::read_ints_from_string(c, false).first().unwrap();
+                    r.split(", ").try_fold((0, 0, 0), |(ar, ag, ab), c| {
+                        let [num] = aoclib::parse_int_array(c, false)?;
                         if c.ends_with("red") {
-                            (num, ag, ab)
+                            Ok((num, ag, ab))
                         } else if c.ends_with("green") {
-                            (ar, num, ab)
+                            Ok((ar, num, ab))
                         } else if c.ends_with("blue") {
-                            (ar, ag, num)
+                            Ok((ar, ag, num))
                         } else {
-                            panic!("Unknown color in '{c}'")
+                            Err(InputError::unexpected("a color", format!("{:?}", c)))
                         }
                     })
                 })
-                .collect();
-            Game { id, reveals }
+                .collect::<Result<_, _>>()?;
+            Ok(Game { id, reveals })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day02;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day02);
 
-    Ok(())
+aoclib::example_tests! {
+    Day02;
+    example1: include_str!("inputs/02.example1.txt"), part1 = 8, part2 = 2286;
 }
 
 #[cfg(test)]
@@ -73,7 +88,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(2)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 2716);
@@ -1,19 +1,13 @@
 use itertools::Itertools;
-use lazy_static::lazy_static;
 use ndarray::{s, Array2};
-use regex::Regex;
 use std::{collections::HashMap, io};
 
-lazy_static! {
-    static ref NUM_REGEX: Regex = Regex::new(r"(\d+)").unwrap();
-}
-
 fn part1(input: &Array2<char>) -> usize {
     let (rows, cols) = input.dim();
     let mut resnums: Vec<usize> = vec![];
     for r in 0..rows {
         let row_string = input.row(r).iter().collect::<String>();
-        let nums = NUM_REGEX.find_iter(&row_string);
+        let nums = aoclib::regex!(r"\d+").find_iter(&row_string);
         for n in nums {
             let start = n.start();
             let end = n.end() - 1;
@@ -41,7 +35,7 @@ fn part2(input: &Array2<char>) -> usize {
     let mut geared: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
     for r in 0..rows {
         let row_string = input.row(r).iter().collect::<String>();
-        let nums = NUM_REGEX.find_iter(&row_string);
+        let nums = aoclib::regex!(r"\d+").find_iter(&row_string);
         for n in nums {
             let start = n.start();
             let end = n.end() - 1;
@@ -71,16 +65,31 @@ fn part2(input: &Array2<char>) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day03;
+
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day03);
 
-    Ok(())
+aoclib::example_tests! {
+    Day03;
+    example1: include_str!("inputs/03.example1.txt"), part1 = 4361, part2 = 467835;
 }
 
 #[cfg(test)]
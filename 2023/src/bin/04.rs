@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use std::{collections::HashMap, collections::HashSet, io};
 
 struct Card {
@@ -32,41 +33,51 @@ fn part2(input: &[Card]) -> usize {
     card_counts.values().sum()
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Card> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Card>, InputError> {
     input
         .into_iter()
         .map(|l| {
             let (head, tail) = aoclib::split_to_tuple2(&l, ": ").unwrap();
             let (win_str, num_str) = aoclib::split_to_tuple2(tail, "|").unwrap();
 
-            let id = *aoclib::read_ints_from_string(head, false).first().unwrap();
-            let winning = aoclib::read_ints_from_string(win_str, false)
-                .into_iter()
-                .collect();
-            let card_nums = aoclib::read_ints_from_string(num_str, false)
-                .into_iter()
-                .collect();
+            let [id] = aoclib::parse_int_array(head, false)?;
+            let winning = aoclib::parse_ints(win_str, false)?.into_iter().collect();
+            let card_nums = aoclib::parse_ints(num_str, false)?.into_iter().collect();
 
-            Card {
+            Ok(Card {
                 id,
                 winning,
                 card_nums,
-            }
+            })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day04;
+
+impl aoclib::solution::Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day04);
 
-    Ok(())
+aoclib::example_tests! {
+    Day04;
+    example1: include_str!("inputs/04.example1.txt"), part1 = 13, part2 = 30;
 }
 
 
@@ -76,7 +87,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(4)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 23750);
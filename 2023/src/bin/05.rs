@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::{io, ops::Range};
 
@@ -96,40 +97,56 @@ fn part2(seeds: &[usize], maps: &[Vec<Map>]) -> usize {
         .unwrap()
 }
 
-fn read_maps(input: &[String]) -> Vec<Map> {
-    input
+fn read_maps(input: &[String]) -> Result<Vec<Map>, InputError> {
+    let maps: Vec<Map> = input
         .iter()
         .skip(1)
-        .filter_map(|l| {
-            aoclib::read_ints_from_string::<usize>(l, false)
-                .into_iter()
-                .collect_tuple()
-        })
-        .sorted_by_key(|m: &Map| m.1)
-        .collect()
+        .map(|l| aoclib::parse_int_array(l, false).map(|[dst, src, len]| (dst, src, len)))
+        .collect::<Result<_, _>>()?;
+    Ok(maps.into_iter().sorted_by_key(|m| m.1).collect())
 }
 
-fn parse_input(input: Vec<String>) -> Option<(Vec<usize>, Vec<Vec<Map>>)> {
+fn parse_input(input: Vec<String>) -> Result<(Vec<usize>, Vec<Vec<Map>>), InputError> {
     let mut parts = input.split(|l| l.is_empty());
 
-    let seeds = aoclib::read_ints_from_string(parts.next()?.first()?, false);
+    let seeds = parts
+        .next()
+        .and_then(|p| p.first())
+        .map_or("", |l| l.as_str());
+    let seeds = aoclib::parse_ints(seeds, false)?;
 
-    let maps: Vec<Vec<Map>> = parts.map(read_maps).collect();
-    assert_eq!(7, maps.len());
-    Some((seeds, maps))
+    let maps: Vec<Vec<Map>> = parts.map(read_maps).collect::<Result<_, _>>()?;
+    if maps.len() != 7 {
+        return Err(InputError::unexpected("7 maps", maps.len().to_string()));
+    }
+    Ok((seeds, maps))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let (seeds, maps) = parse_input(input).unwrap();
+struct Day05;
+
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = (Vec<usize>, Vec<Vec<Map>>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&seeds, &maps);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1((seeds, maps): &Self::Input<'_>) -> usize {
+        part1(seeds, maps)
+    }
+
+    fn part2((seeds, maps): &Self::Input<'_>) -> usize {
+        part2(seeds, maps)
+    }
+}
 
-    let p2 = part2(&seeds, &maps);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day05);
 
-    Ok(())
+aoclib::example_tests! {
+    Day05;
+    example1: include_str!("inputs/05.example1.txt"), part1 = 35, part2 = 46;
 }
 
 #[cfg(test)]
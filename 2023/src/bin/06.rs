@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
@@ -13,26 +14,35 @@ impl Race {
     }
 }
 
-fn part1(input: &[String]) -> usize {
-    let times = aoclib::read_ints_from_string(&input[0], false);
-    let dists = aoclib::read_ints_from_string(&input[1], false);
-    times
+/// The races as listed, and the single race read with the spaces removed.
+type Sheet = (Vec<Race>, Race);
+
+fn parse_input(input: &[String]) -> Result<Sheet, InputError> {
+    let [times, dists] = input else {
+        return Err(InputError::unexpected("2 lines", input.len().to_string()));
+    };
+    let races = aoclib::parse_ints(times, false)?
         .into_iter()
-        .zip(dists)
+        .zip(aoclib::parse_ints(dists, false)?)
         .map(|(t, d)| Race {
             time: t,
             distance: d,
         })
+        .collect();
+    let [time] = aoclib::parse_int_array(&times.replace(' ', ""), false)?;
+    let [distance] = aoclib::parse_int_array(&dists.replace(' ', ""), false)?;
+    Ok((races, Race { time, distance }))
+}
+
+fn part1(races: &[Race]) -> usize {
+    races
+        .iter()
         .map(|r| (1..r.time).filter(|ht| r.will_win(*ht)).count())
         .product1()
         .unwrap()
 }
 
-fn part2(input: &[String]) -> usize {
-    let time = *aoclib::read_ints_from_string(&input[0].replace(' ', ""), false).first().unwrap();
-    let distance = *aoclib::read_ints_from_string(&input[1].replace(' ', ""), false).first().unwrap();
-
-    let race = Race { time, distance };
+fn part2(race: &Race) -> usize {
     // Instead of going through all the options and counting wins, find the first and last
     // winning game. All between will win, too (this is a parabola or something). It's a bit
     // faster to do this. Even faster would be to find these points with binary search?
@@ -41,16 +51,31 @@ fn part2(input: &[String]) -> usize {
     (1..race.time).len() - a - b
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day06;
+
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = Sheet;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(&input.1)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day06);
 
-    Ok(())
+aoclib::example_tests! {
+    Day06;
+    example1: include_str!("inputs/06.example1.txt"), part1 = 288, part2 = 71503;
 }
 
 #[cfg(test)]
@@ -59,11 +84,12 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(6)).unwrap();
+        let (races, race) = parse_input(&input).unwrap();
 
-        let p1 = part1(&input);
+        let p1 = part1(&races);
         assert_eq!(p1, 800280);
 
-        let p2 = part2(&input);
+        let p2 = part2(&race);
         assert_eq!(p2, 45128024);
     }
 }
@@ -73,8 +73,11 @@ fn part2(dirs: &[Dir], nodes: &HashMap<&str, Node>) -> usize {
         .unwrap()
 }
 
-fn parse_input(input: &[String]) -> (Vec<Dir>, HashMap<&str, Node<'_>>) {
-    let dirs = input[0]
+fn parse_input(input: &str) -> (Vec<Dir>, HashMap<&str, Node<'_>>) {
+    let mut lines = input.lines();
+    let dirs = lines
+        .next()
+        .unwrap()
         .chars()
         .map(|c| match c {
             'L' => Dir::L,
@@ -83,9 +86,8 @@ fn parse_input(input: &[String]) -> (Vec<Dir>, HashMap<&str, Node<'_>>) {
         })
         .collect();
 
-    let nodes = input
-        .iter()
-        .skip(2)
+    let nodes = lines
+        .skip(1)
         .map(|l| {
             let ms = aoclib::read_regex_matches_from_string(l, "[A-Z0-9]{3}");
             (
@@ -101,17 +103,33 @@ fn parse_input(input: &[String]) -> (Vec<Dir>, HashMap<&str, Node<'_>>) {
     (dirs, nodes)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let (dirs, nodes) = parse_input(&input);
+struct Day08;
+
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = (Vec<Dir>, HashMap<&'a str, Node<'a>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
 
-    let p1 = part1(&dirs, &nodes);
-    println!("Part 1: {}", p1);
+    fn part1((dirs, nodes): &Self::Input<'_>) -> usize {
+        part1(dirs, nodes)
+    }
+
+    fn part2((dirs, nodes): &Self::Input<'_>) -> usize {
+        part2(dirs, nodes)
+    }
+}
 
-    let p2 = part2(&dirs, &nodes);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day08);
 
-    Ok(())
+aoclib::example_tests! {
+    Day08;
+    example1: include_str!("inputs/08.example1.txt"), part1 = 2;
+    example2: include_str!("inputs/08.example2.txt"), part1 = 6;
+    example3: include_str!("inputs/08.example3.txt"), part2 = 6;
 }
 
 #[cfg(test)]
@@ -119,7 +137,7 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(8)).unwrap();
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(8)).unwrap();
 
         let (dirs, nodes) = parse_input(&input);
         let p1 = part1(&dirs, &nodes);
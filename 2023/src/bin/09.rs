@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
@@ -29,19 +30,38 @@ fn part2(input: &[Vec<i64>]) -> i64 {
     input.iter().map(|seq| extrapolate_seq_back(seq)).sum()
 }
 
-fn main() -> io::Result<()> {
-    let input: Vec<Vec<i64>> = aoclib::read_input_lines()?
+fn parse_input(input: Vec<String>) -> Result<Vec<Vec<i64>>, InputError> {
+    input
         .into_iter()
-        .map(|l| aoclib::read_ints_from_string(&l, true))
-        .collect();
+        .map(|l| aoclib::parse_ints(&l, true))
+        .collect()
+}
+
+struct Day09;
+
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> i64 {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day09);
 
-    Ok(())
+aoclib::example_tests! {
+    Day09;
+    example1: include_str!("inputs/09.example1.txt"), part1 = 114, part2 = 2;
 }
 
 #[cfg(test)]
@@ -49,11 +69,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input: Vec<Vec<i64>> = aoclib::read_file_lines(aoclib::get_test_input_file!(9))
-            .unwrap()
-            .into_iter()
-            .map(|l| aoclib::read_ints_from_string(&l, true))
-            .collect();
+        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(9)).unwrap();
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 1725987467);
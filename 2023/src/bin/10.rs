@@ -283,16 +283,32 @@ fn part2(mut input: Array2<char>) -> usize {
     fills.iter().filter(|f| !f.any_filled()).count()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day10;
 
-    let (p1, _) = part1(&mut input.clone());
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&mut input.clone()).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
+}
+
+aoclib::aoc_main!(Day10);
 
-    Ok(())
+aoclib::example_tests! {
+    Day10;
+    example1: include_str!("inputs/10.example1.txt"), part1 = 8;
+    example2: include_str!("inputs/10.example2.txt"), part2 = 4;
 }
 
 #[cfg(test)]
@@ -40,16 +40,31 @@ fn part2(input: &Array2<char>) -> usize {
     run(input, 1_000_000)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day11;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day11);
 
-    Ok(())
+aoclib::example_tests! {
+    Day11;
+    example1: include_str!("inputs/11.example1.txt"), part1 = 374, part2 = 82000210;
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use lru::LruCache;
 use std::{io, num::NonZeroUsize};
@@ -10,26 +11,26 @@ struct Row {
     damaged_group_sizes: Vec<usize>,
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Row> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Row>, InputError> {
     input
         .into_iter()
         .map(|l| {
             let (spring_chars, groups) = aoclib::split_to_tuple2(&l, " ").unwrap();
-            let damaged_group_sizes = aoclib::read_ints_from_string(groups, false);
+            let damaged_group_sizes = aoclib::parse_ints(groups, false)?;
             let springs = spring_chars
                 .chars()
                 .map(|c| match c {
-                    '#' => Some(true),
-                    '.' => Some(false),
-                    '?' => None,
-                    _ => panic!("Invalid spring: '{c}'"),
+                    '#' => Ok(Some(true)),
+                    '.' => Ok(Some(false)),
+                    '?' => Ok(None),
+                    _ => Err(InputError::unexpected("a spring", format!("{:?}", c))),
                 })
-                .collect();
+                .collect::<Result<_, _>>()?;
 
-            Row {
+            Ok(Row {
                 springs,
                 damaged_group_sizes,
-            }
+            })
         })
         .collect()
 }
@@ -88,8 +89,7 @@ fn arrangements<'a>(
         if let Some((last_undamaged_pos, _)) = springs[..group_lengths[0]]
             .iter()
             .enumerate()
-            .filter(|(_, s)| **s == Some(false))
-            .next_back()
+            .rfind(|(_, s)| **s == Some(false))
         {
             if last_undamaged_pos == springs.len() - 1 {
                 // ...bail (this is not valid)
@@ -153,17 +153,31 @@ fn part2(input: &[Row]) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day12;
+
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = Vec<Row>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day12);
 
-    Ok(())
+aoclib::example_tests! {
+    Day12;
+    example1: include_str!("inputs/12.example1.txt"), part1 = 21, part2 = 525152;
 }
 
 #[cfg(test)]
@@ -172,7 +186,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(12)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 7771);
@@ -80,17 +80,31 @@ fn parse_input(input: String) -> Vec<Array2<i8>> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let input = parse_input(input);
+struct Day13;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Vec<Array2<i8>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input.to_owned()))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day13);
 
-    Ok(())
+aoclib::example_tests! {
+    Day13;
+    example1: include_str!("inputs/13.example1.txt"), part1 = 405, part2 = 400;
 }
 
 #[cfg(test)]
@@ -78,16 +78,31 @@ fn part2(input: Array2<char>) -> usize {
     score(&tortoise)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day14;
 
-    let p1 = part1(input.clone());
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
+}
+
+aoclib::aoc_main!(Day14);
 
-    Ok(())
+aoclib::example_tests! {
+    Day14;
+    example1: include_str!("inputs/14.example1.txt"), part1 = 136, part2 = 64;
 }
 
 #[cfg(test)]
@@ -51,16 +51,31 @@ fn part2(input: &str) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
+struct Day15;
 
-    let p1 = part1(input.trim());
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(input.trim());
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day15);
 
-    Ok(())
+aoclib::example_tests! {
+    Day15;
+    example1: "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7", part1 = 1320, part2 = 145;
 }
 
 #[cfg(test)]
@@ -99,16 +99,31 @@ fn part2(input: &Array2<char>) -> usize {
         .unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day16;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day16);
 
-    Ok(())
+aoclib::example_tests! {
+    Day16;
+    example1: include_str!("inputs/16.example1.txt"), part1 = 46, part2 = 51;
 }
 
 #[cfg(test)]
@@ -101,16 +101,31 @@ fn run(input: &Array2<usize>, part2: bool) -> usize {
     unreachable!()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_int_matrix()?;
+struct Day17;
 
-    let p1 = run(&input, false);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day17 {
+    type Input<'a> = Array2<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run(&input, true);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_int_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input, false)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input, true)
+    }
+}
+
+aoclib::aoc_main!(Day17);
 
-    Ok(())
+aoclib::example_tests! {
+    Day17;
+    example1: include_str!("inputs/17.example1.txt"), part1 = 102, part2 = 94;
 }
 
 #[cfg(test)]
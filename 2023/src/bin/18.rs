@@ -1,50 +1,14 @@
+use aoclib::{coord2::Coord2, Dir4, Direction};
 use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    io,
-    str::FromStr,
-};
-
-#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
-enum Dir {
-    Left,
-    Right,
-    Up,
-    Down,
-}
-
-impl Dir {
-    fn delta(&self) -> (isize, isize) {
-        match self {
-            Dir::Left => (0, -1),
-            Dir::Right => (0, 1),
-            Dir::Up => (-1, 0),
-            Dir::Down => (1, 0),
-        }
-    }
-}
-
-impl FromStr for Dir {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "U" | "3" => Ok(Self::Up),
-            "D" | "1" => Ok(Self::Down),
-            "L" | "2" => Ok(Self::Left),
-            "R" | "0" => Ok(Self::Right),
-            _ => Err(anyhow::anyhow!("Invalid dir")),
-        }
-    }
-}
+use std::{cmp::Ordering, io};
 
 fn run(input: &PartInput) -> usize {
     let mut hor_lines: Vec<((isize, isize), (isize, isize))> = Vec::with_capacity(input.len());
     let mut ver_lines: Vec<((isize, isize), (isize, isize))> = Vec::with_capacity(input.len());
     let mut pos = (0, 0);
     for (dir, len) in input {
-        let d = dir.delta();
-        let d = (d.0 * len, d.1 * len);
+        let d: Coord2<isize> = dir.delta() * *len;
+        let d = (d.y, d.x);
         let end = (pos.0 + d.0, pos.1 + d.1);
         let line = match d.0.cmp(&0) {
             Ordering::Equal => match d.1 >= 0 {
@@ -164,7 +128,7 @@ fn run(input: &PartInput) -> usize {
     count.try_into().unwrap()
 }
 
-type PartInput = Vec<(Dir, isize)>;
+type PartInput = Vec<(Dir4, isize)>;
 
 fn parse_input(input: Vec<String>) -> (PartInput, PartInput) {
     let mut part1 = Vec::with_capacity(input.len());
@@ -174,7 +138,13 @@ fn parse_input(input: Vec<String>) -> (PartInput, PartInput) {
         let (dir, dist, color) = aoclib::split_to_tuple3(&s, " ").unwrap();
         let dir1 = dir.parse().unwrap();
         let dist1 = dist.parse().unwrap();
-        let dir2 = color[7..8].parse().unwrap();
+        let dir2 = match &color[7..8] {
+            "0" => Dir4::Right,
+            "1" => Dir4::Down,
+            "2" => Dir4::Left,
+            "3" => Dir4::Up,
+            d => panic!("Invalid dir {}", d),
+        };
         let dist2 = isize::from_str_radix(&color[2..7], 16).unwrap();
         part1.push((dir1, dist1));
         part2.push((dir2, dist2));
@@ -183,17 +153,31 @@ fn parse_input(input: Vec<String>) -> (PartInput, PartInput) {
     (part1, part2)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let (p1_input, p2_input) = parse_input(input);
+struct Day18;
+
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = (PartInput, PartInput);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
 
-    let p1 = run(&p1_input);
-    println!("Part 1: {}", p1);
+    fn part1((p1_input, _): &Self::Input<'_>) -> usize {
+        run(p1_input)
+    }
+
+    fn part2((_, p2_input): &Self::Input<'_>) -> usize {
+        run(p2_input)
+    }
+}
 
-    let p2 = run(&p2_input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day18);
 
-    Ok(())
+aoclib::example_tests! {
+    Day18;
+    example1: include_str!("inputs/18.example1.txt"), part1 = 62;
 }
 
 #[cfg(test)]
@@ -1,5 +1,4 @@
-use aoclib::UnwrapOptionIterator;
-use itertools::Itertools;
+use aoclib::{InputError, UnwrapOptionIterator};
 use std::{collections::HashMap, io};
 
 struct Part(i32, i32, i32, i32);
@@ -176,11 +175,11 @@ fn parse_rule(s: &str) -> Option<Rule<'_>> {
     }
 }
 
-fn parse_input(input: &[String]) -> (HashMap<&str, Workflow<'_>>, Vec<Part>) {
-    let (rule_lines, part_lines) = input.split(|l| l.is_empty()).collect_tuple().unwrap();
+fn parse_input(input: &str) -> Result<(HashMap<&str, Workflow<'_>>, Vec<Part>), InputError> {
+    let (rule_lines, part_lines) = input.split_once("\n\n").unwrap();
 
     let workflows = rule_lines
-        .iter()
+        .lines()
         .map(|l| {
             let (id, rules) = aoclib::split_to_tuple2(l, "{").unwrap();
             let rules = rules[..rules.len() - 1]
@@ -193,30 +192,41 @@ fn parse_input(input: &[String]) -> (HashMap<&str, Workflow<'_>>, Vec<Part>) {
         .collect();
 
     let parts = part_lines
-        .iter()
+        .lines()
         .map(|l| {
-            let (x, m, a, s) = aoclib::read_ints_from_string(l, false)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
-            Part(x, m, a, s)
+            let [x, m, a, s] = aoclib::parse_int_array(l, false)?;
+            Ok(Part(x, m, a, s))
         })
-        .collect();
+        .collect::<Result<_, InputError>>()?;
 
-    (workflows, parts)
+    Ok((workflows, parts))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let (wfs, parts) = parse_input(&input);
+struct Day19;
+
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = (HashMap<&'a str, Workflow<'a>>, Vec<Part>);
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((wfs, parts): &Self::Input<'_>) -> i32 {
+        part1(wfs, parts)
+    }
 
-    let p1 = part1(&wfs, &parts);
-    println!("Part 1: {}", p1);
+    fn part2((wfs, _): &Self::Input<'_>) -> usize {
+        part2(wfs)
+    }
+}
 
-    let p2 = part2(&wfs);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day19);
 
-    Ok(())
+aoclib::example_tests! {
+    Day19;
+    example1: include_str!("inputs/19.example1.txt"), part1 = 19114, part2 = 167409079868000u64;
 }
 
 #[cfg(test)]
@@ -224,8 +234,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(19)).unwrap();
-        let (wfs, parts) = parse_input(&input);
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(19)).unwrap();
+        let (wfs, parts) = parse_input(&input).unwrap();
 
         let p1 = part1(&wfs, &parts);
         assert_eq!(p1, 333263);
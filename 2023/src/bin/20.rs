@@ -69,7 +69,7 @@ impl<'b> Modules<'b> {
     }
 }
 
-fn parse(input: &Vec<String>) -> Modules<'_> {
+fn parse<'a>(input: &[&'a str]) -> Modules<'a> {
     let mut broadcast = None;
     let mut conjunctions = HashMap::new();
     let mut flipflops = HashMap::new();
@@ -105,7 +105,7 @@ fn parse(input: &Vec<String>) -> Modules<'_> {
     }
 }
 
-fn part1(input: &Vec<String>) -> usize {
+fn part1(input: &[&str]) -> usize {
     let mut modules = parse(input);
     let mut q = VecDeque::new();
     let mut low_count = 0;
@@ -129,7 +129,7 @@ fn part1(input: &Vec<String>) -> usize {
 }
 
 
-fn part2(input: &Vec<String>) -> usize {
+fn part2(input: &[&str]) -> usize {
     let mut modules = parse(input);
     let mut q = VecDeque::new();
 
@@ -184,16 +184,32 @@ fn part2(input: &Vec<String>) -> usize {
     diffs.into_iter().reduce(|a, b| a.lcm(&b)).unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day20;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::str_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day20);
 
-    Ok(())
+aoclib::example_tests! {
+    Day20;
+    example1: include_str!("inputs/20.example1.txt"), part1 = 32000000;
+    example2: include_str!("inputs/20.example2.txt"), part1 = 11687500;
 }
 
 #[cfg(test)]
@@ -201,7 +217,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(20)).unwrap();
+        let input = aoclib::read_file_string(aoclib::get_test_input_file!(20)).unwrap();
+        let input = aoclib::str_lines(&input);
 
         let p1 = part1(&input);
         assert_eq!(p1, 818723272);
@@ -1,3 +1,4 @@
+use aoclib::coord2::Coord2;
 use itertools::Itertools;
 use ndarray::Array2;
 use num_integer::Integer;
@@ -6,19 +7,6 @@ use std::{
     io,
 };
 
-type Coord = (usize, usize);
-
-fn adjacents(y: usize, x: usize, h: usize, w: usize) -> impl Iterator<Item = Coord> {
-    [
-        if x > 0 { Some((y, x - 1)) } else { None },
-        if x < w - 1 { Some((y, x + 1)) } else { None },
-        if y > 0 { Some((y - 1, x)) } else { None },
-        if y < h - 1 { Some((y + 1, x)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
-}
-
 fn wrapping_adjacents(
     y: usize,
     x: usize,
@@ -68,7 +56,9 @@ fn find_targets(
     }
 
     let (h, w) = grid.dim();
-    adjacents(y, x, h, w)
+    Coord2 { y, x }
+        .neighbors4((h, w))
+        .map(Coord2::<usize>::usizes)
         .filter(|(yy, xx)| grid[(*yy, *xx)] == '.')
         .for_each(|(yy, xx)| {
             find_targets(steps_remaining - 1, yy, xx, grid, target_bitmap, visited)
@@ -281,19 +271,28 @@ fn find_start(mut arr: Array2<char>) -> ((usize, usize), Array2<char>) {
     (start, arr)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
-    let (start, input) = find_start(input);
+struct Day21;
 
-    let p1 = part1(&input, start);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = ((usize, usize), Array2<char>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input, start);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(find_start(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1((start, input): &Self::Input<'_>) -> usize {
+        part1(input, *start)
+    }
 
-    Ok(())
+    fn part2((start, input): &Self::Input<'_>) -> usize {
+        part2(input, *start)
+    }
 }
 
+aoclib::aoc_main!(Day21);
+
 #[cfg(test)]
 mod test {
     use super::*;
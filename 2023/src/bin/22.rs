@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use ndarray::{s, Array3, Axis};
 use std::{
@@ -165,31 +166,42 @@ fn run(input: Vec<Brick>) -> (usize, usize) {
 }
 
 
-fn parse_input(input: Vec<String>) -> Vec<Brick> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Brick>, InputError> {
     input
-        .into_iter()
-        .map(|l| aoclib::read_ints_from_string(&l, false))
+        .iter()
         .enumerate()
-        .map(|(i, nums)| {
-            assert_eq!(nums.len(), 6);
-            Brick(
-                (i + 1) as u16,
-                nums[..3].iter().rev().copied().collect_tuple().unwrap(),
-                nums[3..].iter().rev().copied().collect_tuple().unwrap(),
-            )
+        .map(|(i, l)| {
+            let [x1, y1, z1, x2, y2, z2] = aoclib::parse_int_array(l, false)?;
+            Ok(Brick((i + 1) as u16, (z1, y1, x1), (z2, y2, x2)))
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day22;
+
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = Vec<Brick>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input.clone()).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input.clone()).1
+    }
+}
 
-    let (p1, p2) = run(input);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day22);
 
-    Ok(())
+aoclib::example_tests! {
+    Day22;
+    example1: include_str!("inputs/22.example1.txt"), part1 = 5, part2 = 7;
 }
 
 #[cfg(test)]
@@ -198,7 +210,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(22)).unwrap();
-        let input = parse_input(input);
+        let input = parse_input(input).unwrap();
 
         let (p1, p2) = run(input);
         assert_eq!(p1, 432);
@@ -1,3 +1,4 @@
+use aoclib::coord2::Coord2;
 use itertools::Itertools;
 use ndarray::{s, Array2};
 use std::{
@@ -7,17 +8,6 @@ use std::{
 
 type Coord = (usize, usize);
 
-fn adjacents(y: usize, x: usize, h: usize, w: usize) -> impl Iterator<Item = Coord> {
-    [
-        if x > 0 { Some((y, x - 1)) } else { None },
-        if x < w - 1 { Some((y, x + 1)) } else { None },
-        if y > 0 { Some((y - 1, x)) } else { None },
-        if y < h - 1 { Some((y + 1, x)) } else { None },
-    ]
-    .into_iter()
-    .flatten()
-}
-
 const ARROWS: [char; 4] = ['<', '>', '^', 'v'];
 
 fn dfs(
@@ -45,7 +35,9 @@ fn dfs(
         'v' => dfs(input, target, visited, (pos.0 + 1, pos.1)),
         _ => {
             let (h, w) = input.dim();
-            adjacents(pos.0, pos.1, h, w)
+            Coord2::from(pos)
+                .neighbors4((h, w))
+                .map(Coord2::<usize>::usizes)
                 .flat_map(|co| dfs(input, target, visited, co))
                 .max()
         }
@@ -72,7 +64,9 @@ fn dfs2(
     }
 
     let (h, w) = input.dim();
-    adjacents(pos.0, pos.1, h, w)
+    Coord2::from(pos)
+        .neighbors4((h, w))
+        .map(Coord2::<usize>::usizes)
         .filter(|&p| input[p] != '#' && p != from)
         .exactly_one()
         .ok()
@@ -160,7 +154,7 @@ fn part2(input: &Array2<char>) -> usize {
     let mut adj: HashMap<Coord, Vec<(Coord, usize)>> = HashMap::new();
 
     for c in &crossroad_coords {
-        for ac in adjacents(c.0, c.1, h, w) {
+        for ac in Coord2::from(*c).neighbors4((h, w)).map(Coord2::<usize>::usizes) {
             if input[ac] == '#' {
                 continue;
             }
@@ -181,16 +175,31 @@ fn part2(input: &Array2<char>) -> usize {
     dfs3(&adj, (h - 1, end_x), &mut visited, 0, (0, start_x)).unwrap()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
+struct Day23;
+
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = Array2<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_char_matrix(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day23);
 
-    Ok(())
+aoclib::example_tests! {
+    Day23;
+    example1: include_str!("inputs/23.example1.txt"), part1 = 94, part2 = 154;
 }
 
 #[cfg(test)]
@@ -184,10 +184,7 @@ fn parse_input(input: Vec<String>) -> Vec<Hailstone> {
     input
         .into_iter()
         .map(|l| {
-            let (x, y, z, vx, vy, vz) = aoclib::read_ints_from_string::<i64>(&l, true)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
+            let [x, y, z, vx, vy, vz] = aoclib::ints::<6, i64>(&l).unwrap();
             Hailstone {
                 x,
                 y,
@@ -200,19 +197,28 @@ fn parse_input(input: Vec<String>) -> Vec<Hailstone> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day24;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = i128;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> i128 {
+        part2(input)
+    }
 }
 
+aoclib::aoc_main!(Day24);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -85,13 +85,31 @@ fn part1(input: &Vec<String>) -> usize {
     vis.len() * (edges.len() - vis.len())
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day25;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day25 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = aoclib::solution::NoAnswer;
 
-    Ok(())
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_string_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> aoclib::solution::NoAnswer {
+        aoclib::solution::NoAnswer
+    }
+}
+
+aoclib::aoc_main!(Day25);
+
+aoclib::example_tests! {
+    Day25;
+    example1: include_str!("inputs/25.example1.txt"), part1 = 54;
 }
 
 #[cfg(test)]
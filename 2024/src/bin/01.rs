@@ -26,23 +26,42 @@ fn part2(input: &(Vec<usize>, Vec<usize>)) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input: (Vec<usize>, Vec<usize>) = aoclib::read_input_ints(false)?
+fn parse_input(input: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+    input
         .into_iter()
         .tuples()
         .fold((vec![], vec![]), |(mut lc, mut rc), (l, r)| {
             lc.push(l);
             rc.push(r);
             (lc, rc)
-        });
+        })
+}
+
+struct Day01;
+
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::parse_ints(input, false)?))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day01);
 
-    Ok(())
+aoclib::example_tests! {
+    Day01;
+    example1: include_str!("inputs/01.example1.txt"), part1 = 11, part2 = 31;
 }
 
 #[cfg(test)]
@@ -50,16 +69,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input: (Vec<usize>, Vec<usize>) =
-            aoclib::read_ints_from_file(aoclib::get_test_input_file!(1), false)
-                .unwrap()
-                .into_iter()
-                .tuples()
-                .fold((vec![], vec![]), |(mut lc, mut rc), (l, r)| {
-                    lc.push(l);
-                    rc.push(r);
-                    (lc, rc)
-                });
+        let input = aoclib::read_ints_from_file(aoclib::get_test_input_file!(1), false).unwrap();
+        let input = parse_input(input);
 
         let p1 = part1(&input);
         assert_eq!(p1, 1660292);
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::io;
 
@@ -15,24 +16,23 @@ fn is_safe(report: &[u8]) -> bool {
     }
 }
 
-fn part1(input: &[String]) -> usize {
-    input
-        .iter()
-        .map(|l| aoclib::read_ints_from_string(l, false))
-        .filter(|r| is_safe(r))
-        .count()
+fn parse_input(input: &[String]) -> Result<Vec<Vec<u8>>, InputError> {
+    input.iter().map(|l| aoclib::parse_ints(l, false)).collect()
+}
+
+fn part1(reports: &[Vec<u8>]) -> usize {
+    reports.iter().filter(|r| is_safe(r)).count()
 }
 
-fn part2(input: &[String]) -> usize {
-    input
+fn part2(reports: &[Vec<u8>]) -> usize {
+    reports
         .iter()
-        .map(|l| aoclib::read_ints_from_string(l, false))
         .filter(|r| {
             if is_safe(r) {
                 return true;
             }
 
-            let mut rr = r.clone();
+            let mut rr = r.to_vec();
             for i in 0..r.len() {
                 let x = rr.remove(i);
                 if is_safe(&rr) {
@@ -46,16 +46,31 @@ fn part2(input: &[String]) -> usize {
         .count()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day02;
+
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<Vec<u8>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day02);
 
-    Ok(())
+aoclib::example_tests! {
+    Day02;
+    example1: include_str!("inputs/02.example1.txt"), part1 = 2, part2 = 4;
 }
 
 #[cfg(test)]
@@ -64,6 +79,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(2)).unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 230);
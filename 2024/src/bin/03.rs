@@ -1,46 +1,87 @@
+use aoclib::InputError;
 use std::io;
 
-fn part1(input: &[String]) -> usize {
-    input
-        .iter()
-        .map(|l| aoclib::read_ints_from_string::<usize>(l, false))
-        .filter(|nums| nums.len() == 2)
-        .map(|nums| nums[0] * nums[1])
-        .sum()
+enum Instr {
+    Mul(usize, usize),
+    Do,
+    Dont,
 }
 
-fn part2(input: &[String]) -> usize {
+fn parse_input(input: &[String]) -> Result<Vec<Instr>, InputError> {
     input
         .iter()
-        .scan(true, |acc, l| {
-            if l.starts_with("don't") {
-                *acc = false;
-                Some(None)
+        .map(|l| {
+            Ok(if l.starts_with("don't") {
+                Instr::Dont
             } else if l.starts_with("do") {
-                *acc = true;
-                Some(None)
-            } else if *acc {
-                Some(Some(l))
+                Instr::Do
             } else {
-                Some(None)
-            }
+                let [a, b] = aoclib::parse_int_array(l, false)?;
+                Instr::Mul(a, b)
+            })
+        })
+        .collect()
+}
+
+fn part1(instrs: &[Instr]) -> usize {
+    instrs
+        .iter()
+        .map(|i| match i {
+            Instr::Mul(a, b) => a * b,
+            _ => 0,
         })
-        .flatten()
-        .map(|l| aoclib::read_ints_from_string::<usize>(l, false))
-        .map(|nums| nums[0] * nums[1])
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_regex_matches_from_stdin(r"(mul\(\d{1,3},\d{1,3}\)|do(n't)?\(\))")?;
+fn part2(instrs: &[Instr]) -> usize {
+    instrs
+        .iter()
+        .scan(true, |acc, i| {
+            Some(match i {
+                Instr::Do => {
+                    *acc = true;
+                    0
+                }
+                Instr::Dont => {
+                    *acc = false;
+                    0
+                }
+                Instr::Mul(a, b) if *acc => a * b,
+                Instr::Mul(..) => 0,
+            })
+        })
+        .sum()
+}
+
+struct Day03;
+
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = Vec<Instr>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        let matches =
+            aoclib::read_regex_matches_from_string(input, r"(mul\(\d{1,3},\d{1,3}\)|do(n't)?\(\))");
+        let matches: Vec<String> = matches.into_iter().map(|m| m.to_owned()).collect();
+        Ok(parse_input(&matches)?)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day03);
 
-    Ok(())
+aoclib::example_tests! {
+    Day03;
+    example1: include_str!("inputs/03.example1.txt"), part1 = 161;
+    example2: include_str!("inputs/03.example2.txt"), part2 = 48;
 }
 
 #[cfg(test)]
@@ -53,6 +94,7 @@ mod test {
             r"(mul\(\d{1,3},\d{1,3}\)|do(n't)?\(\))",
         )
         .unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 167650499);
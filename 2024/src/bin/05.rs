@@ -1,4 +1,4 @@
-use aoclib::UnwrapOptionIterator;
+use aoclib::InputError;
 use itertools::Itertools;
 use std::{collections::HashMap, io};
 
@@ -60,37 +60,51 @@ fn part2(orders: &HashMap<u8, Vec<u8>>, pages: &[Vec<u8>]) -> usize {
         .sum()
 }
 
-fn parse_input(input: &[String]) -> (HashMap<u8, Vec<u8>>, Vec<Vec<u8>>) {
+/// The pages that must come after each page, and the updates to check.
+type Manual = (HashMap<u8, Vec<u8>>, Vec<Vec<u8>>);
+
+fn parse_input(input: &[String]) -> Result<Manual, InputError> {
     let (orders, pages) = input.split(|l| l.is_empty()).collect_tuple().unwrap();
 
     let orders: HashMap<u8, Vec<u8>> = orders
         .iter()
-        .map(|l| {
-            aoclib::read_ints_from_string(l, false)
-                .into_iter()
-                .collect_tuple()
-        })
-        .unwrap_options()
+        .map(|l| aoclib::parse_int_array(l, false).map(|[a, b]| (a, b)))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
         .into_group_map();
     let pages = pages
         .iter()
-        .map(|l| aoclib::read_ints_from_string(l, false))
-        .collect();
+        .map(|l| aoclib::parse_ints(l, false))
+        .collect::<Result<_, _>>()?;
 
-    (orders, pages)
+    Ok((orders, pages))
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let (orders, pages) = parse_input(&input);
+struct Day05;
+
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Manual;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
 
-    let p1 = part1(&orders, &pages);
-    println!("Part 1: {}", p1);
+    fn part1((orders, pages): &Self::Input<'_>) -> usize {
+        part1(orders, pages)
+    }
+
+    fn part2((orders, pages): &Self::Input<'_>) -> usize {
+        part2(orders, pages)
+    }
+}
 
-    let p2 = part2(&orders, &pages);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day05);
 
-    Ok(())
+aoclib::example_tests! {
+    Day05;
+    example1: include_str!("inputs/05.example1.txt"), part1 = 143, part2 = 123;
 }
 
 #[cfg(test)]
@@ -99,7 +113,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(5)).unwrap();
-        let (orders, pages) = parse_input(&input);
+        let (orders, pages) = parse_input(&input).unwrap();
 
         let p1 = part1(&orders, &pages);
         assert_eq!(p1, 5651);
@@ -1,11 +1,12 @@
+use aoclib::InputError;
 use std::io;
 
-fn run<const PART2: bool>(input: &[String]) -> usize {
-    input
-        .iter()
-        .map(|l| aoclib::read_ints_from_string(l, false))
-        .filter_map(|l| calc::<PART2>(&l))
-        .sum()
+fn parse_input(input: &[String]) -> Result<Vec<Vec<usize>>, InputError> {
+    input.iter().map(|l| aoclib::parse_ints(l, false)).collect()
+}
+
+fn run<const PART2: bool>(equations: &[Vec<usize>]) -> usize {
+    equations.iter().filter_map(|l| calc::<PART2>(l)).sum()
 }
 
 fn calc<const CONCAT: bool>(l: &[usize]) -> Option<usize> {
@@ -40,16 +41,31 @@ fn calc<const CONCAT: bool>(l: &[usize]) -> Option<usize> {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day07;
+
+impl aoclib::solution::Solution for Day07 {
+    type Input<'a> = Vec<Vec<usize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
 
-    let p1 = run::<false>(&input);
-    println!("Part 1: {}", p1);
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run::<false>(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run::<true>(input)
+    }
+}
 
-    let p2 = run::<true>(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day07);
 
-    Ok(())
+aoclib::example_tests! {
+    Day07;
+    example1: include_str!("inputs/07.example1.txt"), part1 = 3749, part2 = 11387;
 }
 
 #[cfg(test)]
@@ -58,6 +74,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(7)).unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = run::<false>(&input);
         assert_eq!(p1, 303876485655);
@@ -59,17 +59,31 @@ fn parse_input(input: Array2<char>) -> (Bounds, HashMap<char, Vec<Coord2<usize>>
     (dim, nodes)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
-    let (dim, nodes) = parse_input(input);
+struct Day08;
 
-    let p1 = part1(dim, &nodes);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = (Bounds, HashMap<char, Vec<Coord2<usize>>>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(dim, &nodes);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1((dim, nodes): &Self::Input<'_>) -> usize {
+        part1(*dim, nodes)
+    }
+
+    fn part2((dim, nodes): &Self::Input<'_>) -> usize {
+        part2(*dim, nodes)
+    }
+}
+
+aoclib::aoc_main!(Day08);
 
-    Ok(())
+aoclib::example_tests! {
+    Day08;
+    example1: include_str!("inputs/08.example1.txt"), part1 = 14, part2 = 34;
 }
 
 #[cfg(test)]
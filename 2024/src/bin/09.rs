@@ -107,16 +107,31 @@ fn part2(input: &str) -> usize {
     checksum(&disk_map)
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
+struct Day09;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = String;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day09);
 
-    Ok(())
+aoclib::example_tests! {
+    Day09;
+    example1: "2333133121414131402", part1 = 1928, part2 = 2858;
 }
 
 #[cfg(test)]
@@ -72,16 +72,31 @@ fn part2(input: &Array2<u8>) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_int_matrix()?;
+struct Day10;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Array2<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        aoclib::read_string_int_matrix(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day10);
 
-    Ok(())
+aoclib::example_tests! {
+    Day10;
+    example1: include_str!("inputs/10.example1.txt"), part1 = 36, part2 = 81;
 }
 
 #[cfg(test)]
@@ -1,4 +1,3 @@
-use itertools::Itertools;
 use nalgebra::{Matrix2, Matrix2x1};
 use num_integer::Integer;
 use std::io;
@@ -69,18 +68,9 @@ fn parse_input(input: Vec<String>) -> Vec<Machine> {
         .split(|l| l.is_empty())
         .map(|ls| {
             assert_eq!(3, ls.len());
-            let (a11, a21) = aoclib::read_ints_from_string(&ls[0], false)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
-            let (a12, a22) = aoclib::read_ints_from_string(&ls[1], false)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
-            let (b1, b2) = aoclib::read_ints_from_string(&ls[2], false)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
+            let [a11, a21] = aoclib::ints(&ls[0]).unwrap();
+            let [a12, a22] = aoclib::ints(&ls[1]).unwrap();
+            let [b1, b2] = aoclib::ints(&ls[2]).unwrap();
             Machine {
                 a11,
                 a12,
@@ -93,17 +83,31 @@ fn parse_input(input: Vec<String>) -> Vec<Machine> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day13;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day13 {
+    type Input<'a> = Vec<Machine>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day13);
 
-    Ok(())
+aoclib::example_tests! {
+    Day13;
+    example1: include_str!("inputs/13.example1.txt"), part1 = 480, part2 = 875318608908u64;
 }
 
 #[cfg(test)]
@@ -8,6 +8,15 @@ struct Robot {
     vel: Coord2<isize>,
 }
 
+aoclib::line_pattern! {
+    struct RobotLine {
+        px: usize,
+        py: usize,
+        vx: isize,
+        vy: isize,
+    } = "p={px},{py} v={vx},{vy}";
+}
+
 fn part1(input: &[Robot]) -> usize {
     let dim = (103usize, 101usize);
 
@@ -62,36 +71,37 @@ fn print_robots(robots: &[Robot], dim: (usize, usize)) {
     aoclib::print_bool_ndarray(arr.view());
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day14;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day14 {
+    type Input<'a> = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        parse_input(input)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input.clone())
+    }
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Robot> {
-    input
+aoclib::aoc_main!(Day14);
+
+fn parse_input(input: &str) -> io::Result<Vec<Robot>> {
+    let robots = aoclib::pattern::parse_lines::<RobotLine>(input)?
         .into_iter()
-        .map(|l| {
-            let nums: Vec<isize> = aoclib::read_ints_from_string(&l, true);
-            Robot {
-                pos: Coord2 {
-                    x: nums[0].try_into().unwrap(),
-                    y: nums[1].try_into().unwrap(),
-                },
-                vel: Coord2 {
-                    x: nums[2],
-                    y: nums[3],
-                },
-            }
+        .map(|r| Robot {
+            pos: Coord2 { x: r.px, y: r.py },
+            vel: Coord2 { x: r.vx, y: r.vy },
         })
-        .collect()
+        .collect();
+    Ok(robots)
 }
 
 #[cfg(test)]
@@ -99,8 +109,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(14)).unwrap();
-        let input = parse_input(input);
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(14)).unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 217328832);
@@ -119,17 +119,32 @@ fn part2(small_map: &Array2<char>, dirs: &Vec<Coord2<isize>>) -> usize {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let (map, dirs) = parse_input(input);
+struct Day15;
 
-    let p1 = part1(&map, &dirs);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day15 {
+    type Input<'a> = (Array2<char>, Vec<Coord2<isize>>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&map, &dirs);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input.to_owned()))
+    }
+
+    fn part1((map, dirs): &Self::Input<'_>) -> usize {
+        part1(map, dirs)
+    }
+
+    fn part2((map, dirs): &Self::Input<'_>) -> usize {
+        part2(map, dirs)
+    }
+}
+
+aoclib::aoc_main!(Day15);
 
-    Ok(())
+aoclib::example_tests! {
+    Day15;
+    example1: include_str!("inputs/15.example1.txt"), part1 = 10092, part2 = 9021;
+    example2: include_str!("inputs/15.example2.txt"), part1 = 2028;
 }
 
 fn parse_input(input: String) -> (Array2<char>, Vec<Coord2<isize>>) {
@@ -108,17 +108,31 @@ fn part2(input: &Array2<char>, start: Coord2, end: Coord2) -> usize {
     unreachable!()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
-    let (map, start, end) = find_start_end(input);
+struct Day16;
 
-    let p1 = part1(&map, start, end);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day16 {
+    type Input<'a> = (Array2<char>, Coord2, Coord2);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&map, start, end);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(find_start_end(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1((map, start, end): &Self::Input<'_>) -> usize {
+        part1(map, *start, *end)
+    }
+
+    fn part2((map, start, end): &Self::Input<'_>) -> usize {
+        part2(map, *start, *end)
+    }
+}
+
+aoclib::aoc_main!(Day16);
 
-    Ok(())
+aoclib::example_tests! {
+    Day16;
+    example1: include_str!("inputs/16.example1.txt"), part1 = 7036, part2 = 45;
 }
 
 fn find_start_end(mut input: Array2<char>) -> (Array2<char>, Coord2, Coord2) {
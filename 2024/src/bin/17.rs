@@ -199,16 +199,31 @@ fn build(a: usize, expected: &[u8]) -> Option<usize> {
     None
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_ints(false)?;
+struct Day17;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day17 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = String;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day17);
 
-    Ok(())
+aoclib::example_tests! {
+    Day17;
+    example1: include_str!("inputs/17.example1.txt"), part1 = "4,6,3,5,6,3,5,2,1,0";
 }
 
 #[cfg(test)]
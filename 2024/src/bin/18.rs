@@ -84,18 +84,28 @@ fn part2(input: &[usize]) -> String {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_ints(false)?;
+struct Day18;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day18 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = String;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input)
+    }
 }
 
+aoclib::aoc_main!(Day18);
+
 #[cfg(test)]
 mod test {
     use super::*;
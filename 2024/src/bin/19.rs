@@ -52,17 +52,31 @@ fn part2(patterns: &[String], rows: &[String]) -> usize {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
-    let (patterns, rows) = parse_input(input);
+struct Day19;
 
-    let p1 = part1(&patterns, &rows);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day19 {
+    type Input<'a> = (Vec<String>, Vec<String>);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&patterns, &rows);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input.to_owned()))
+    }
+
+    fn part1((patterns, rows): &Self::Input<'_>) -> usize {
+        part1(patterns, rows)
+    }
+
+    fn part2((patterns, rows): &Self::Input<'_>) -> usize {
+        part2(patterns, rows)
+    }
+}
+
+aoclib::aoc_main!(Day19);
 
-    Ok(())
+aoclib::example_tests! {
+    Day19;
+    example1: include_str!("inputs/19.example1.txt"), part1 = 6, part2 = 16;
 }
 
 fn parse_input(input: String) -> (Vec<String>, Vec<String>) {
@@ -86,19 +86,28 @@ fn run<const N: usize>(map: &Array2<char>, start: Coord2, end: Coord2) -> usize
     cc
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_char_matrix()?;
-    let (map, start, end) = find_start_end(input);
+struct Day20;
 
-    let p1 = run::<2>(&map, start, end);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day20 {
+    type Input<'a> = (Array2<char>, Coord2, Coord2);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run::<20>(&map, start, end);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(find_start_end(aoclib::read_string_char_matrix(input)?))
+    }
+
+    fn part1((map, start, end): &Self::Input<'_>) -> usize {
+        run::<2>(map, *start, *end)
+    }
 
-    Ok(())
+    fn part2((map, start, end): &Self::Input<'_>) -> usize {
+        run::<20>(map, *start, *end)
+    }
 }
 
+aoclib::aoc_main!(Day20);
+
 fn find_start_end(mut input: Array2<char>) -> (Array2<char>, Coord2, Coord2) {
     let start: Coord2 = input
         .indexed_iter()
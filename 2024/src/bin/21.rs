@@ -1,4 +1,4 @@
-use aoclib::coord2::Coord2;
+use aoclib::{coord2::Coord2, InputError};
 use std::{collections::HashMap, io};
 
 const NUMPAD_FORBIDDEN: Coord2 = Coord2 { y: 3, x: 0 };
@@ -129,43 +129,60 @@ fn keypad_seq_len<const IS_NUMPAD: bool>(
     sum
 }
 
+/// A door code's buttons, and the number it contains.
+type Code = (Vec<char>, usize);
 
-fn part1(input: &[String]) -> usize {
-    let mut cache = Default::default();
+fn parse_input(input: &[String]) -> Result<Vec<Code>, InputError> {
     input
         .iter()
         .map(|l| {
-            let cs = l.chars().collect();
-            let len = keypad_seq_len::<true>(&cs, 2, &mut cache);
-            let num: usize = aoclib::read_ints_from_string(l, false)[0];
-            len * num
+            let [num] = aoclib::parse_int_array(l, false)?;
+            Ok((l.chars().collect(), num))
         })
+        .collect()
+}
+
+fn part1(codes: &[Code]) -> usize {
+    let mut cache = Default::default();
+    codes
+        .iter()
+        .map(|(cs, num)| keypad_seq_len::<true>(cs, 2, &mut cache) * num)
         .sum()
 }
 
-fn part2(input: &[String]) -> usize {
+fn part2(codes: &[Code]) -> usize {
     let mut cache = Default::default();
-    input
+    codes
         .iter()
-        .map(|l| {
-            let cs = l.chars().collect();
-            let len = keypad_seq_len::<true>(&cs, 25, &mut cache);
-            let num: usize = aoclib::read_ints_from_string(l, false)[0];
-            len * num
-        })
+        .map(|(cs, num)| keypad_seq_len::<true>(cs, 25, &mut cache) * num)
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day21;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day21 {
+    type Input<'a> = Vec<Code>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day21);
 
-    Ok(())
+aoclib::example_tests! {
+    Day21;
+    example1: include_str!("inputs/21.example1.txt"), part1 = 126384;
 }
 
 #[cfg(test)]
@@ -174,6 +191,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(21)).unwrap();
+        let input = parse_input(&input).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 169390);
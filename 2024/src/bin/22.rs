@@ -71,16 +71,32 @@ fn part2(input: &[usize]) -> usize {
     v
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_ints(false)?;
+struct Day22;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day22 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::parse_ints(input, false)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day22);
 
-    Ok(())
+aoclib::example_tests! {
+    Day22;
+    example1: include_str!("inputs/22.example1.txt"), part1 = 37327623;
+    example2: include_str!("inputs/22.example2.txt"), part2 = 23;
 }
 
 #[cfg(test)]
@@ -98,9 +98,9 @@ fn part2(input: &Vec<Link>) -> String {
     }
 }
 
-fn parse_input(input: &[String]) -> Vec<Link<'_>> {
+fn parse_input(input: &str) -> Vec<Link<'_>> {
     input
-        .iter()
+        .lines()
         .map(|l| {
             let (a, b) = aoclib::split_to_tuple2(l, "-").unwrap();
             Link(a, b)
@@ -108,17 +108,31 @@ fn parse_input(input: &[String]) -> Vec<Link<'_>> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let links = parse_input(&input);
+struct Day23;
+
+impl aoclib::solution::Solution for Day23 {
+    type Input<'a> = Vec<Link<'a>>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&links);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&links);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day23);
 
-    Ok(())
+aoclib::example_tests! {
+    Day23;
+    example1: include_str!("inputs/23.example1.txt"), part1 = 7, part2 = "co,de,ka,ta";
 }
 
 #[cfg(test)]
@@ -126,7 +140,7 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(23)).unwrap();
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(23)).unwrap();
         let links = parse_input(&input);
 
         let p1 = part1(&links);
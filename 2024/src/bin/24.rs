@@ -110,17 +110,31 @@ fn parse_input(input: Vec<String>) -> HashMap<String, Gate> {
     lits.chain(gates).collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day24;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day24 {
+    type Input<'a> = HashMap<String, Gate>;
+    type Part1 = usize;
+    type Part2 = String;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> String {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day24);
 
-    Ok(())
+aoclib::example_tests! {
+    Day24;
+    example1: include_str!("inputs/24.example1.txt"), part1 = 4;
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::io;
 
-fn part1(input: String) -> usize {
+fn part1(input: &str) -> usize {
     let parts = input.split("\n\n");
     let mut keys = vec![];
     let mut locks = vec![];
@@ -26,13 +26,31 @@ fn part1(input: String) -> usize {
         .count()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_string()?;
+struct Day25;
 
-    let p1 = part1(input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day25 {
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = aoclib::solution::NoAnswer;
 
-    Ok(())
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> aoclib::solution::NoAnswer {
+        aoclib::solution::NoAnswer
+    }
+}
+
+aoclib::aoc_main!(Day25);
+
+aoclib::example_tests! {
+    Day25;
+    example1: include_str!("inputs/25.example1.txt"), part1 = 3;
 }
 
 #[cfg(test)]
@@ -42,7 +60,7 @@ mod test {
     fn test_real_input() {
         let input = std::fs::read_to_string(aoclib::get_test_input_file!(25)).unwrap();
 
-        let p1 = part1(input);
+        let p1 = part1(&input);
         assert_eq!(p1, 3136);
     }
 }
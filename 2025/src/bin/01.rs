@@ -35,17 +35,31 @@ fn parse_input(lines: Vec<String>) -> Vec<i64> {
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_input(input);
+struct Day01;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day01 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input)))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day01);
 
-    Ok(())
+aoclib::example_tests! {
+    Day01;
+    example1: include_str!("inputs/01.example1.txt"), part1 = 3, part2 = 6;
 }
 
 #[cfg(test)]
@@ -44,17 +44,31 @@ fn part2(input: &[RangeInclusive<u64>]) -> u64 {
         .sum()
 }
 
-fn main() -> io::Result<()> {
-    let input: Vec<u64> = aoclib::read_input_ints(false)?;
-    let input = parse_input(input);
+struct Day02;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day02 {
+    type Input<'a> = Vec<RangeInclusive<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::parse_ints(input, false)?))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> u64 {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day02);
 
-    Ok(())
+aoclib::example_tests! {
+    Day02;
+    example1: include_str!("inputs/02.example1.txt"), part1 = 1227775554, part2 = 4174379265u64;
 }
 
 fn parse_input(inp: Vec<u64>) -> Vec<RangeInclusive<u64>> {
@@ -40,16 +40,31 @@ fn run<const N: usize>(input: &[String]) -> usize {
         .sum::<usize>()
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day03;
 
-    let p1 = run::<2>(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day03 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = run::<12>(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_string_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run::<2>(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run::<12>(input)
+    }
+}
+
+aoclib::aoc_main!(Day03);
 
-    Ok(())
+aoclib::example_tests! {
+    Day03;
+    example1: include_str!("inputs/03.example1.txt"), part1 = 357, part2 = 3121910778619u64;
 }
 
 #[cfg(test)]
@@ -41,16 +41,31 @@ fn part2(input: &[String]) -> usize {
     ranges.into_iter().map(|(a, b)| b - a + 1).sum::<u64>() as usize
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day05;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day05 {
+    type Input<'a> = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(aoclib::read_string_lines(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day05);
 
-    Ok(())
+aoclib::example_tests! {
+    Day05;
+    example1: include_str!("inputs/05.example1.txt"), part1 = 3, part2 = 14;
 }
 
 fn parse_input(inp: &[String]) -> (Vec<(u64, u64)>, Vec<u64>) {
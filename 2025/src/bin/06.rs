@@ -1,11 +1,22 @@
+use aoclib::{InputError, InputErrorKind};
 use ndarray::s;
 use std::io;
 
-fn part1(input: &[String]) -> usize {
+/// The operands of each row, and the raw lines for reading them by column.
+type Worksheet = (Vec<Vec<usize>>, Vec<String>);
+
+fn parse_input(input: Vec<String>) -> Result<Worksheet, InputError> {
+    if input.is_empty() {
+        return Err(InputError::new(InputErrorKind::Empty));
+    }
     let operand_lists = input[..(input.len() - 1)]
         .iter()
-        .map(|l| aoclib::read_ints_from_string::<usize>(l, false))
-        .collect::<Vec<_>>();
+        .map(|l| aoclib::parse_ints(l, false))
+        .collect::<Result<_, _>>()?;
+    Ok((operand_lists, input))
+}
+
+fn part1(operand_lists: &[Vec<usize>], input: &[String]) -> usize {
     let operators = input[input.len() - 1]
         .split_whitespace()
         .collect::<Vec<_>>();
@@ -60,16 +71,31 @@ fn part2(input: &[String]) -> usize {
     sum
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
+struct Day06;
+
+impl aoclib::solution::Solution for Day06 {
+    type Input<'a> = Worksheet;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(&input.1)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day06);
 
-    Ok(())
+aoclib::example_tests! {
+    Day06;
+    example1: include_str!("inputs/06.example1.txt"), part1 = 4277556, part2 = 3263827;
 }
 
 #[cfg(test)]
@@ -78,8 +104,9 @@ mod test {
     #[test]
     fn test_real_input() {
         let input = aoclib::read_file_lines(aoclib::get_test_input_file!(6)).unwrap();
+        let (operand_lists, input) = parse_input(input).unwrap();
 
-        let p1 = part1(&input);
+        let p1 = part1(&operand_lists, &input);
         assert_eq!(p1, 6100348226985);
 
         let p2 = part2(&input);
@@ -1,18 +1,15 @@
+use aoclib::InputError;
 use itertools::Itertools;
 use std::{collections::HashMap, io, str::FromStr};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct Point(u32, u32, u32);
 impl FromStr for Point {
-    type Err = ();
+    type Err = InputError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nums = aoclib::read_ints_from_string(s, false);
-        if nums.len() < 3 {
-            Err(())
-        } else {
-            Ok(Point(nums[0], nums[1], nums[2]))
-        }
+        let [x, y, z] = aoclib::parse_int_array(s, false)?;
+        Ok(Point(x, y, z))
     }
 }
 
@@ -93,26 +90,39 @@ fn run(input: &Vec<Point>) -> (usize, usize) {
     (part1, part2)
 }
 
-fn parse_input(lines: &[String]) -> Vec<Point> {
-    lines.iter().map(|l| l.parse().unwrap()).collect()
+fn parse_input(lines: &[String]) -> Result<Vec<Point>, InputError> {
+    lines.iter().map(|l| l.parse()).collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(&aoclib::read_input_lines()?);
+struct Day08;
+
+impl aoclib::solution::Solution for Day08 {
+    type Input<'a> = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let (p1, p2) = run(&input);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(&aoclib::read_string_lines(input))?)
+    }
 
-    Ok(())
+    fn part1(input: &Self::Input<'_>) -> usize {
+        run(input).0
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        run(input).1
+    }
 }
 
+aoclib::aoc_main!(Day08);
+
 #[cfg(test)]
 mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = parse_input(&aoclib::read_file_lines(aoclib::get_test_input_file!(8)).unwrap());
+        let input = parse_input(&aoclib::read_file_lines(aoclib::get_test_input_file!(8)).unwrap())
+            .unwrap();
 
         let (p1, p2) = run(&input);
         assert_eq!(p1, 135169);
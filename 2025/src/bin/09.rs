@@ -1,4 +1,4 @@
-use aoclib::coord2::Coord2;
+use aoclib::{InputError, coord2::Coord2};
 use itertools::Itertools;
 use std::io;
 
@@ -172,28 +172,41 @@ fn part2(input: &[Coord2]) -> usize {
     max_area
 }
 
-fn parse_input(lines: Vec<String>) -> Vec<Coord2> {
+fn parse_input(lines: Vec<String>) -> Result<Vec<Coord2>, InputError> {
     lines
         .into_iter()
         .map(|l| {
-            let &[y, x] = aoclib::read_ints_from_string(&l, false).as_slice() else {
-                panic!("invalid input row");
-            };
-            Coord2 { x, y }
+            let [y, x] = aoclib::parse_int_array(&l, false)?;
+            Ok(Coord2 { x, y })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(aoclib::read_input_lines()?);
+struct Day09;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day09 {
+    type Input<'a> = Vec<Coord2>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day09);
 
-    Ok(())
+aoclib::example_tests! {
+    Day09;
+    example1: include_str!("inputs/09.example1.txt"), part1 = 50, part2 = 24;
 }
 
 #[cfg(test)]
@@ -201,7 +214,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = parse_input(aoclib::read_file_lines(aoclib::get_test_input_file!(9)).unwrap());
+        let input =
+            parse_input(aoclib::read_file_lines(aoclib::get_test_input_file!(9)).unwrap()).unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 4748769124);
@@ -1,3 +1,4 @@
+use aoclib::InputError;
 use arrayvec::ArrayVec;
 use fraction::GenericFraction;
 use fraction::{ConstOne, ConstZero};
@@ -204,7 +205,7 @@ fn part2(input: &Vec<Machine>) -> usize {
     }
 }
 
-fn parse_input(input: Vec<String>) -> Vec<Machine> {
+fn parse_input(input: Vec<String>) -> Result<Vec<Machine>, InputError> {
     input
         .into_iter()
         .map(|l| {
@@ -216,32 +217,47 @@ fn parse_input(input: Vec<String>) -> Vec<Machine> {
                 .map(|c| c == '#')
                 .collect();
 
-            let joltage = aoclib::read_ints_from_string(parts[parts.len() - 1], false);
+            let joltage = aoclib::parse_ints(parts[parts.len() - 1], false)?;
 
             let buttons = parts[1..parts.len() - 1]
                 .iter()
-                .map(|b| Button(aoclib::read_ints_from_string(b, false)))
-                .collect();
+                .map(|b| Ok(Button(aoclib::parse_ints(b, false)?)))
+                .collect::<Result<_, InputError>>()?;
 
-            Machine {
+            Ok(Machine {
                 indicators,
                 buttons,
                 joltage,
-            }
+            })
         })
         .collect()
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input(aoclib::read_input_lines()?);
+struct Day10;
+
+impl aoclib::solution::Solution for Day10 {
+    type Input<'a> = Vec<Machine>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(aoclib::read_string_lines(input))?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+aoclib::aoc_main!(Day10);
 
-    Ok(())
+aoclib::example_tests! {
+    Day10;
+    example1: include_str!("inputs/10.example1.txt"), part1 = 7, part2 = 33;
 }
 
 #[cfg(test)]
@@ -249,7 +265,8 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = parse_input(aoclib::read_file_lines(aoclib::get_test_input_file!(10)).unwrap());
+        let input = parse_input(aoclib::read_file_lines(aoclib::get_test_input_file!(10)).unwrap())
+            .unwrap();
 
         let p1 = part1(&input);
         assert_eq!(p1, 530);
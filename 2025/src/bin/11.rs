@@ -45,22 +45,37 @@ fn part2(input: &HashMap<&str, Dev>) -> usize {
     }
 }
 
-fn main() -> io::Result<()> {
-    let input = aoclib::read_input_lines()?;
-    let input = parse_devs(&input);
+struct Day11;
 
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
+impl aoclib::solution::Solution for Day11 {
+    type Input<'a> = HashMap<&'a str, Dev<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_devs(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        part2(input)
+    }
+}
+
+aoclib::aoc_main!(Day11);
 
-    Ok(())
+aoclib::example_tests! {
+    Day11;
+    example1: include_str!("inputs/11.example1.txt"), part1 = 5;
+    example2: include_str!("inputs/11.example2.txt"), part2 = 2;
 }
 
-fn parse_devs(input: &[String]) -> HashMap<&str, Dev<'_>> {
+fn parse_devs(input: &str) -> HashMap<&str, Dev<'_>> {
     input
-        .iter()
+        .lines()
         .map(|l| {
             let (name, conns) = aoclib::split_to_tuple2(l, ": ")?;
             let conns = conns.split_ascii_whitespace().collect();
@@ -75,7 +90,7 @@ mod test {
     use super::*;
     #[test]
     fn test_real_input() {
-        let input = aoclib::read_file_lines(aoclib::get_test_input_file!(11)).unwrap();
+        let input = std::fs::read_to_string(aoclib::get_test_input_file!(11)).unwrap();
         let input = parse_devs(&input);
 
         let p1 = part1(&input);
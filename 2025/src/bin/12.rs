@@ -1,11 +1,9 @@
-use aoclib::{UnwrapOptionIterator, coord2::Coord2};
+use aoclib::{InputError, InputErrorKind, coord2::Coord2, transform::orientations};
 use arrayvec::ArrayVec;
 use itertools::Itertools;
 use ndarray::{Array2, s};
 use rayon::prelude::*;
-use std::{collections::HashMap, io, sync::OnceLock};
-
-static SHAPE_VARIANT_CACHE: OnceLock<HashMap<Shape, Vec<Shape>>> = OnceLock::new();
+use std::io;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 struct Shape(Array2<bool>);
@@ -15,65 +13,32 @@ impl Shape {
         self.0.iter().filter(|b| **b).count()
     }
     fn variants(&self) -> Vec<Shape> {
-        let (h, w) = self.0.dim();
-        assert_eq!(h, w);
-
-        let mut flipped = self.clone();
-        flipped.flip();
-        [
-            self.clone(),
-            self.rotate_cw(),
-            self.rotate_cw().rotate_cw(),
-            self.rotate_ccw(),
-            flipped.rotate_cw(),
-            flipped.rotate_ccw(),
-            flipped.rotate_cw().rotate_cw(),
-            flipped,
-        ]
-        .into_iter()
-        .unique()
-        .collect()
-    }
-
-    fn flip(&mut self) {
-        let rlen = self.0.dim().1;
-        for mut r in self.0.rows_mut() {
-            for i in 0..(rlen / 2) {
-                let tmp = r[i];
-                r[i] = r[rlen - 1];
-                r[rlen - 1] = tmp;
-            }
-        }
-    }
-
-    fn rotate_cw(&self) -> Self {
-        let mut res = self.clone();
-        res.0.reverse_axes();
-        res.flip();
-        res
-    }
-
-    fn rotate_ccw(&self) -> Self {
-        let mut res = self.clone();
-        res.flip();
-        res.0.reverse_axes();
-        res
+        orientations(self.0.view()).into_iter().map(Shape).collect()
     }
 }
 struct Space((usize, usize), Vec<usize>);
 
 fn part1(shapes: &Vec<Shape>, spaces: &Vec<Space>) -> usize {
-    SHAPE_VARIANT_CACHE
-        .set(shapes.iter().map(|s| (s.clone(), s.variants())).collect())
-        .unwrap();
-    return spaces.par_iter().filter(|s| is_possible(s, shapes)).count();
+    let variants: Vec<Vec<Shape>> = shapes.iter().map(|s| s.variants()).collect();
+    return spaces
+        .par_iter()
+        .filter(|s| is_possible(s, shapes, &variants))
+        .count();
 
-    fn is_possible(space: &Space, shapes: &Vec<Shape>) -> bool {
+    fn is_possible(space: &Space, shapes: &Vec<Shape>, variants: &[Vec<Shape>]) -> bool {
         let mut map = Array2::default(space.0);
         let mut counts_remaining = space.1.clone();
         let small_holes = Array2::default(space.0);
 
-        step(0, 0, &mut map, &mut counts_remaining, shapes, &small_holes)
+        step(
+            0,
+            0,
+            &mut map,
+            &mut counts_remaining,
+            shapes,
+            variants,
+            &small_holes,
+        )
     }
 
     fn step(
@@ -82,6 +47,7 @@ fn part1(shapes: &Vec<Shape>, spaces: &Vec<Space>) -> usize {
         map: &mut Array2<bool>,
         counts_remaining: &mut Vec<usize>,
         shapes: &Vec<Shape>,
+        variants: &[Vec<Shape>],
         small_holes: &Array2<bool>,
     ) -> bool {
         let (h, w) = map.dim();
@@ -105,10 +71,7 @@ fn part1(shapes: &Vec<Shape>, spaces: &Vec<Space>) -> usize {
             if counts_remaining[sh_idx] == 0 {
                 continue;
             }
-            let sh_variants = SHAPE_VARIANT_CACHE
-                .get()
-                .and_then(|cache| cache.get(&shapes[sh_idx]))
-                .unwrap();
+            let sh_variants = &variants[sh_idx];
 
             let ymin = prev_y.saturating_sub(3);
             let ymax = prev_y + 3;
@@ -126,7 +89,15 @@ fn part1(shapes: &Vec<Shape>, spaces: &Vec<Space>) -> usize {
                     win |= &sh.0;
                     counts_remaining[sh_idx] -= 1;
 
-                    if step(y, x, map, counts_remaining, shapes, &new_small_holes) {
+                    if step(
+                        y,
+                        x,
+                        map,
+                        counts_remaining,
+                        shapes,
+                        variants,
+                        &new_small_holes,
+                    ) {
                         return true;
                     }
 
@@ -181,45 +152,54 @@ fn part1(shapes: &Vec<Shape>, spaces: &Vec<Space>) -> usize {
     }
 }
 
-fn main() -> io::Result<()> {
-    let (shapes, spaces) = parse_input(aoclib::read_input_string()?).unwrap();
+struct Day12;
+
+impl aoclib::solution::Solution for Day12 {
+    type Input<'a> = (Vec<Shape>, Vec<Space>);
+    type Part1 = usize;
+    type Part2 = aoclib::solution::NoAnswer;
 
-    let p1 = part1(&shapes, &spaces);
-    println!("Part 1: {}", p1);
+    fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        part1(&input.0, &input.1)
+    }
 
-    Ok(())
+    fn part2(_input: &Self::Input<'_>) -> aoclib::solution::NoAnswer {
+        aoclib::solution::NoAnswer
+    }
 }
 
-fn parse_input(input: String) -> Option<(Vec<Shape>, Vec<Space>)> {
-    let parts: Vec<_> = input.split("\n\n").collect();
+aoclib::aoc_main!(Day12);
+
+fn parse_input(input: &str) -> Result<(Vec<Shape>, Vec<Space>), InputError> {
+    let sections = aoclib::read_string_sections(input);
+    let (spaces, shapes) = sections
+        .split_last()
+        .ok_or_else(|| InputError::new(InputErrorKind::Empty))?;
 
-    let shapes = parts[..parts.len() - 1]
+    let shapes = shapes
         .iter()
-        .map(|p| {
-            let (_, grid) = p.split_once("\n")?;
-            Some(Shape(
-                aoclib::read_string_char_matrix(grid)
-                    .ok()?
-                    .map(|c| *c == '#'),
-            ))
-        })
-        .unwrap_options()
-        .collect();
+        .map(|s| Ok(Shape(aoclib::parse_char_grid(s.body)?.map(|c| *c == '#'))))
+        .collect::<Result<_, InputError>>()?;
 
-    let spaces = parts
-        .last()?
+    let spaces = spaces
         .lines()
         .map(|l| {
-            let mut nums = aoclib::read_ints_from_string(l, false);
+            let mut nums = aoclib::parse_ints(l, false)?;
+            if nums.len() < 2 {
+                return Err(InputError::unexpected("a region size", format!("{:?}", l)));
+            }
             let w = nums[0];
             let h = nums[1];
             let counts = nums.split_off(2);
-            Some(Space((h, w), counts))
+            Ok(Space((h, w), counts))
         })
-        .unwrap_options()
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Some((shapes, spaces))
+    Ok((shapes, spaces))
 }
 
 #[cfg(test)]
@@ -228,7 +208,7 @@ mod test {
     #[test]
     fn test_real_input() {
         let (shapes, spaces) =
-            parse_input(std::fs::read_to_string(aoclib::get_test_input_file!(12)).unwrap())
+            parse_input(&aoclib::read_file_string(aoclib::get_test_input_file!(12)).unwrap())
                 .unwrap();
 
         let p1 = part1(&shapes, &spaces);
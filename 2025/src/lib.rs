@@ -0,0 +1,54 @@
+//! Advent of Code 2025 solutions, registered for the `aoc` runner.
+
+use aoclib::solution::Entry;
+
+#[path = "bin/01.rs"]
+pub mod day01;
+
+#[path = "bin/02.rs"]
+pub mod day02;
+
+#[path = "bin/03.rs"]
+pub mod day03;
+
+#[path = "bin/04.rs"]
+pub mod day04;
+
+#[path = "bin/05.rs"]
+pub mod day05;
+
+#[path = "bin/06.rs"]
+pub mod day06;
+
+#[path = "bin/07.rs"]
+pub mod day07;
+
+#[path = "bin/08.rs"]
+pub mod day08;
+
+#[path = "bin/09.rs"]
+pub mod day09;
+
+#[path = "bin/10.rs"]
+pub mod day10;
+
+#[path = "bin/11.rs"]
+pub mod day11;
+
+#[path = "bin/12.rs"]
+pub mod day12;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2025, 1, day01::SOLUTION),
+    Entry::new(2025, 2, day02::SOLUTION),
+    Entry::new(2025, 3, day03::SOLUTION),
+    Entry::new(2025, 4, day04::SOLUTION),
+    Entry::new(2025, 5, day05::SOLUTION),
+    Entry::new(2025, 6, day06::SOLUTION),
+    Entry::new(2025, 7, day07::SOLUTION),
+    Entry::new(2025, 8, day08::SOLUTION),
+    Entry::new(2025, 9, day09::SOLUTION),
+    Entry::new(2025, 10, day10::SOLUTION),
+    Entry::new(2025, 11, day11::SOLUTION),
+    Entry::new(2025, 12, day12::SOLUTION),
+];
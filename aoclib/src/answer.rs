@@ -0,0 +1,217 @@
+use itertools::Itertools;
+use ndarray::Array2;
+use num_bigint::BigInt;
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+///
+/// Answers are compared by value: integers compare equal regardless of the
+/// variant they are stored in, and bitmaps compare by their lit pixels.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i128),
+    /// Integers that do not fit an `i128`
+    BigInt(BigInt),
+    Str(String),
+    /// Letters drawn with pixels, displayed with `#` for lit pixels and `.`
+    /// for the rest
+    Bitmap(Array2<bool>),
+}
+
+impl Answer {
+    /// Whether the answer matches an expected answer given as text, such as
+    /// one recorded in an answers file. Bitmaps are matched against the
+    /// letters read from them, or against text drawn with `#` or `█` for lit
+    /// pixels.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(i) => expected.trim().parse::<i128>().is_ok_and(|e| e == *i),
+            Answer::BigInt(b) => expected.trim().parse::<BigInt>().is_ok_and(|e| e == *b),
+            Answer::Str(s) => s == expected,
+            Answer::Bitmap(bitmap) => {
+                crate::ocr::recognize(bitmap.view()).is_some_and(|s| s == expected)
+                    || lit_rows(&Answer::parse_bitmap(expected)) == lit_rows(bitmap)
+            }
+        }
+    }
+
+    /// Reads the letters drawn in a bitmap answer with [`crate::ocr`]. Other
+    /// answers, and bitmaps that cannot be read, are returned as they are.
+    pub fn read_letters(self) -> Answer {
+        match self {
+            Answer::Bitmap(bitmap) => match crate::ocr::recognize(bitmap.view()) {
+                Some(letters) => Answer::Str(letters),
+                None => Answer::Bitmap(bitmap),
+            },
+            other => other,
+        }
+    }
+
+    /// A bitmap from rows of pixels, lit where the value is not the default,
+    /// like [`crate::format_bool_matrix`]. Short rows are padded with unlit
+    /// pixels.
+    pub fn bitmap<T: Default + PartialEq>(rows: &[Vec<T>]) -> Answer {
+        let def = T::default();
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        Answer::Bitmap(Array2::from_shape_fn((rows.len(), width), |(y, x)| {
+            rows[y].get(x).is_some_and(|v| *v != def)
+        }))
+    }
+
+    /// Parses a bitmap drawn with `#` or `█` for lit pixels. Short rows are
+    /// padded with unlit pixels.
+    pub fn parse_bitmap(s: &str) -> Array2<bool> {
+        let rows = s.lines().collect_vec();
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        Array2::from_shape_fn((rows.len(), width), |(y, x)| {
+            matches!(rows[y].chars().nth(x), Some('#' | '█'))
+        })
+    }
+
+    pub fn format_bitmap(bitmap: &Array2<bool>) -> String {
+        bitmap
+            .rows()
+            .into_iter()
+            .map(|r| {
+                r.iter()
+                    .map(|&b| if b { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+/// The lit pixels of a bitmap with unlit rows and columns at the edges
+/// trimmed, so that bitmaps only differing in padding compare equal.
+fn lit_rows(bitmap: &Array2<bool>) -> Vec<Vec<bool>> {
+    let lit = bitmap.indexed_iter().filter(|(_, b)| **b).map(|(i, _)| i);
+    let Some((ymin, ymax)) = lit.clone().map(|(y, _)| y).minmax().into_option() else {
+        return Vec::new();
+    };
+    let (xmin, xmax) = lit.map(|(_, x)| x).minmax().into_option().unwrap();
+
+    (ymin..=ymax)
+        .map(|y| (xmin..=xmax).map(|x| bitmap[(y, x)]).collect())
+        .collect()
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::BigInt(a), Answer::BigInt(b)) => a == b,
+            (Answer::Int(i), Answer::BigInt(b)) | (Answer::BigInt(b), Answer::Int(i)) => {
+                BigInt::from(*i) == *b
+            }
+            (Answer::Str(a), Answer::Str(b)) => a == b,
+            (Answer::Bitmap(a), Answer::Bitmap(b)) => lit_rows(a) == lit_rows(b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::BigInt(b) => write!(f, "{}", b),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Bitmap(b) => write!(f, "{}", Answer::format_bitmap(b)),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or_else(|_| Answer::BigInt(value.into()), Answer::Int)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        i128::try_from(&value).map_or(Answer::BigInt(value), Answer::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Array2<bool>> for Answer {
+    fn from(value: Array2<bool>) -> Self {
+        Answer::Bitmap(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_int_answers() {
+        assert_eq!(Answer::Int(42), 42u8.into());
+        assert_eq!(Answer::from(u128::MAX), Answer::BigInt(u128::MAX.into()));
+        assert_eq!(Answer::Int(-3), Answer::BigInt((-3).into()));
+        assert_ne!(Answer::Int(3), Answer::Str("3".to_owned()));
+
+        assert!(Answer::from(-12i64).matches("-12"));
+        assert!(Answer::from(u128::MAX).matches(&u128::MAX.to_string()));
+        assert!(!Answer::from(12usize).matches("12a"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(!Answer::from("abc").matches("abc "));
+    }
+
+    #[test]
+    fn test_bitmap_answers() {
+        let bitmap = Answer::from(array![[true, false, false], [false, true, false]]);
+        assert_eq!("#..\n.#.", bitmap.to_string());
+        assert_eq!(bitmap, Answer::bitmap(&[vec![1, 0, 0], vec![0, 2]]));
+
+        assert!(bitmap.matches("#..\n.#."));
+        assert!(bitmap.matches("█\n █"));
+        assert!(bitmap.matches("\n #\n  #  \n"));
+        assert!(!bitmap.matches("#.\n#."));
+        assert_eq!(bitmap, bitmap.clone().read_letters());
+
+        let letters = Answer::parse_bitmap(
+            "#..#..###\n#..#...#.\n####...#.\n#..#...#.\n#..#...#.\n#..#..###",
+        );
+        assert!(Answer::Bitmap(letters.clone()).matches("HI"));
+        assert_eq!(Answer::from("HI"), Answer::Bitmap(letters).read_letters());
+        assert_eq!(
+            bitmap,
+            Answer::Bitmap(array![[false, false], [true, false], [false, true]])
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Building 2D arrays from rows or from sets of coordinates, with the shape
+//! checked instead of assumed.
+
+use crate::coord2::Coord2;
+use crate::{InputError, InputErrorKind};
+use ndarray::Array2;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+use std::fmt::Display;
+
+/// Builds an array from rows of cells. Fails if there are no rows, the rows
+/// are empty, or a row has a different length than the first one. Errors
+/// have the line number of the offending row.
+pub fn array_from_rows<T, R: IntoIterator<Item = T>>(
+    rows: impl IntoIterator<Item = R>,
+) -> Result<Array2<T>, InputError> {
+    let mut cells = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for row in rows {
+        let start = cells.len();
+        cells.extend(row);
+        let len = cells.len() - start;
+        if height == 0 {
+            width = len;
+        } else if len != width {
+            return Err(InputError::unexpected(
+                format!("a row of {} cells", width),
+                format!("{} cells", len),
+            )
+            .at_line(height + 1));
+        }
+        height += 1;
+    }
+
+    if width == 0 {
+        return Err(InputError::new(InputErrorKind::Empty));
+    }
+    Ok(Array2::from_shape_vec((height, width), cells).unwrap())
+}
+
+/// Builds an array from nested vectors, see [`array_from_rows`].
+pub fn array_from_vecs<T>(v: Vec<Vec<T>>) -> Result<Array2<T>, InputError> {
+    array_from_rows(v)
+}
+
+/// The smallest rectangle containing a set of coordinates. Both corners are
+/// inclusive.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Bounds<T: Integer = usize> {
+    pub min: Coord2<T>,
+    pub max: Coord2<T>,
+}
+
+impl<T: Integer + Copy> Bounds<T> {
+    /// The bounding box of the coordinates, or `None` if there are none.
+    pub fn of(coords: impl IntoIterator<Item = Coord2<T>>) -> Option<Bounds<T>> {
+        let mut coords = coords.into_iter();
+        let first = coords.next()?;
+        Some(coords.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |b, c| Bounds {
+                min: Coord2 {
+                    y: b.min.y.min(c.y),
+                    x: b.min.x.min(c.x),
+                },
+                max: Coord2 {
+                    y: b.max.y.max(c.y),
+                    x: b.max.x.max(c.x),
+                },
+            },
+        ))
+    }
+
+    pub fn contains(&self, c: Coord2<T>) -> bool {
+        (self.min.y..=self.max.y).contains(&c.y) && (self.min.x..=self.max.x).contains(&c.x)
+    }
+}
+
+impl<T: Integer + Copy + ToPrimitive> Bounds<T> {
+    /// The shape of an array covering the bounds, or `None` if it does not
+    /// fit in `usize`.
+    pub fn dim(&self) -> Option<(usize, usize)> {
+        let h = (self.max.y - self.min.y).to_usize()?.checked_add(1)?;
+        let w = (self.max.x - self.min.x).to_usize()?.checked_add(1)?;
+        Some((h, w))
+    }
+
+    /// The array index of a coordinate within the bounds.
+    pub fn index(&self, c: Coord2<T>) -> Option<Coord2<usize>> {
+        if !self.contains(c) {
+            return None;
+        }
+        Some(Coord2 {
+            y: (c.y - self.min.y).to_usize()?,
+            x: (c.x - self.min.x).to_usize()?,
+        })
+    }
+}
+
+impl<T: Integer + Display> Display for Bounds<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.min, self.max)
+    }
+}
+
+/// Builds an array covering the bounding box of the cells, with the cells
+/// set to their values and the rest to `V::default()`. Returns the bounds
+/// too, for mapping array indices back to coordinates. Fails if there are
+/// no cells.
+pub fn array_from_coords<T, V>(
+    cells: impl IntoIterator<Item = (Coord2<T>, V)>,
+) -> Result<(Array2<V>, Bounds<T>), InputError>
+where
+    T: Integer + Copy + ToPrimitive + Display,
+    V: Clone + Default,
+{
+    let cells: Vec<_> = cells.into_iter().collect();
+    let bounds = Bounds::of(cells.iter().map(|(c, _)| *c))
+        .ok_or_else(|| InputError::new(InputErrorKind::Empty))?;
+    Ok((array_from_coords_in(bounds, cells)?, bounds))
+}
+
+/// Builds an array covering `bounds`, with the cells set to their values
+/// and the rest to `V::default()`. Fails if a cell is outside the bounds.
+pub fn array_from_coords_in<T, V>(
+    bounds: Bounds<T>,
+    cells: impl IntoIterator<Item = (Coord2<T>, V)>,
+) -> Result<Array2<V>, InputError>
+where
+    T: Integer + Copy + ToPrimitive + Display,
+    V: Clone + Default,
+{
+    let dim = bounds
+        .dim()
+        .ok_or_else(|| InputError::unexpected("bounds that fit in memory", bounds.to_string()))?;
+    let mut arr = Array2::default(dim);
+    for (c, v) in cells {
+        let i = bounds.index(c).ok_or_else(|| {
+            InputError::unexpected(format!("a coordinate within {}", bounds), c.to_string())
+        })?;
+        arr[i] = v;
+    }
+    Ok(arr)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_array_from_rows() {
+        assert_eq!(
+            Ok(array![[1, 2, 3], [4, 5, 6]]),
+            array_from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+        assert_eq!(
+            Ok(array![['a'], ['b']]),
+            array_from_rows("a\nb".lines().map(str::chars))
+        );
+        assert_eq!(
+            "line 2: expected a row of 3 cells, found 2 cells",
+            array_from_vecs(vec![vec![1, 2, 3], vec![4, 5]])
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(array_from_vecs(Vec::<Vec<u8>>::new()).is_err());
+        assert!(array_from_vecs(vec![Vec::<u8>::new()]).is_err());
+    }
+
+    #[test]
+    fn test_array_from_coords() {
+        let points = [(-1i32, 2), (1, 4), (0, 3)].map(|c| (Coord2::from(c), true));
+        let (arr, bounds) = array_from_coords(points).unwrap();
+        assert_eq!(
+            array![
+                [true, false, false],
+                [false, true, false],
+                [false, false, true]
+            ],
+            arr
+        );
+        assert_eq!(Coord2 { y: -1, x: 2 }, bounds.min);
+        assert_eq!(
+            Some(Coord2 { y: 1, x: 1 }),
+            bounds.index(Coord2 { y: 0, x: 3 })
+        );
+        assert_eq!(None, bounds.index(Coord2 { y: 2, x: 3 }));
+
+        let bounds = Bounds {
+            min: Coord2 { y: 0, x: 0 },
+            max: Coord2 { y: 1, x: 1 },
+        };
+        assert_eq!(
+            Ok(array![[0, 0], [0, 7]]),
+            array_from_coords_in(bounds, [(Coord2 { y: 1usize, x: 1 }, 7)])
+        );
+        assert_eq!(
+            "expected a coordinate within (y=0, x=0)..=(y=1, x=1), found (y=2, x=0)",
+            array_from_coords_in(bounds, [(Coord2 { y: 2usize, x: 0 }, 7)])
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(array_from_coords(Vec::<(Coord2<i32>, bool)>::new()).is_err());
+    }
+}
@@ -1,12 +1,14 @@
 use std::{
     fmt::{Debug, Display},
     num::TryFromIntError,
-    ops::{Add, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
 };
 
 use ndarray::{Dim, NdIndex};
 use num_integer::Integer;
-use num_traits::{ConstZero, Signed, Unsigned, Zero};
+use num_traits::{ConstOne, ConstZero, Signed, Unsigned, Zero};
+
+use crate::dir::{Dir4, Dir8, Direction};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Coord2<T: Integer = usize> {
@@ -46,14 +48,78 @@ impl<T: Integer> From<Coord2<T>> for (T, T) {
     }
 }
 
-impl<T: Integer + Signed> Coord2<T> {
-    fn try_into_unsigned_tuple<U: Integer + Unsigned + TryFrom<T, Error = TryFromIntError>>(
-        self,
-    ) -> Result<(U, U), TryFromIntError> {
-        Ok((self.y.try_into()?, self.x.try_into()?))
+impl<T: Integer + Copy> Coord2<T> {
+    /// Converts to another integer type, or returns `None` if either value
+    /// does not fit in it.
+    pub fn try_cast<U: Integer + TryFrom<T>>(self) -> Option<Coord2<U>> {
+        Some(Coord2 {
+            y: self.y.try_into().ok()?,
+            x: self.x.try_into().ok()?,
+        })
+    }
+
+    /// Converts to a wider integer type.
+    pub fn cast<U: Integer + From<T>>(self) -> Coord2<U> {
+        Coord2 {
+            y: self.y.into(),
+            x: self.x.into(),
+        }
+    }
+
+    pub fn dot(self, rhs: Self) -> T {
+        self.y * rhs.y + self.x * rhs.x
     }
 }
 
+impl<T: Integer + Signed + Copy> Coord2<T> {
+    /// The z component of the cross product, positive if `rhs` is
+    /// counter-clockwise from `self` with `y` growing upwards.
+    pub fn cross(self, rhs: Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl<T: Integer + Copy + TryInto<usize>> Coord2<T> {
+    /// The coordinate as an array index, or `None` if it is negative or too
+    /// large.
+    pub fn try_usizes(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    /// The coordinate as an array index. Panics if it is negative or too
+    /// large, see [`Coord2::try_usizes`].
+    pub fn usizes(self) -> (usize, usize) {
+        self.try_usizes().expect("Coordinate out of usize range")
+    }
+}
+
+macro_rules! impl_usize_tuple_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<Coord2<$t>> for (usize, usize) {
+                type Error = TryFromIntError;
+
+                fn try_from(value: Coord2<$t>) -> Result<Self, Self::Error> {
+                    Ok((value.y.try_into()?, value.x.try_into()?))
+                }
+            }
+
+            impl TryFrom<(usize, usize)> for Coord2<$t> {
+                type Error = TryFromIntError;
+
+                fn try_from(value: (usize, usize)) -> Result<Self, Self::Error> {
+                    Ok(Coord2 {
+                        y: value.0.try_into()?,
+                        x: value.1.try_into()?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_usize_tuple_conversions!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+
 impl<T: Integer + AbsDiff + Copy> Coord2<T> {
     pub fn manhattan_dist(&self, other: &Self) -> <T as AbsDiff>::Output {
         let y = self.y.abs_diff(other.y);
@@ -72,7 +138,7 @@ unsafe impl NdIndex<Dim<[usize; 2]>> for Coord2<usize> {
     }
 }
 
-impl<T: Integer + Signed> Sub for Coord2<T> {
+impl<T: Integer> Sub for Coord2<T> {
     type Output = Coord2<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -94,6 +160,29 @@ impl<T: Integer> Add for Coord2<T> {
     }
 }
 
+impl<T: Integer + Copy> AddAssign for Coord2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer + Copy> SubAssign for Coord2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer + Copy> Div<T> for Coord2<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Coord2 {
+            y: self.y / rhs,
+            x: self.x / rhs,
+        }
+    }
+}
+
 impl<T: Integer + Copy> Mul<T> for Coord2<T> {
     type Output = Self;
 
@@ -157,14 +246,25 @@ where
 
     pub fn wrapping_add<B>(self, rhs: Coord2<<T as CheckedAddSigned>::S>, bounds: (B, B)) -> Self
     where
-        B: Integer + Unsigned + Copy + Debug + Into<T> + TryInto<<T as CheckedAddSigned>::S, Error: Debug>,
+        B: Integer
+            + Unsigned
+            + Copy
+            + Debug
+            + Into<T>
+            + TryInto<<T as CheckedAddSigned>::S, Error: Debug>,
     {
-        let by = bounds.0.try_into().expect("Can't convert unsigned bound to signed");
-        let bx = bounds.1.try_into().expect("Can't convert unsigned bound to signed");
+        let by = bounds
+            .0
+            .try_into()
+            .expect("Can't convert unsigned bound to signed");
+        let bx = bounds
+            .1
+            .try_into()
+            .expect("Can't convert unsigned bound to signed");
 
         let dy = rhs.y.mod_floor(&by);
         let dx = rhs.x.mod_floor(&bx);
-        
+
         debug_assert!(dy >= <T as CheckedAddSigned>::S::zero());
         debug_assert!(dx >= <T as CheckedAddSigned>::S::zero());
 
@@ -175,24 +275,99 @@ where
     }
 }
 
-impl<T: Integer + ConstZero + Signed> Coord2<T> {
-    pub fn checked_add_with_bounds(self, rhs: Self, lower: (T, T), upper: (T, T)) -> Option<Self> {
-        let r = self + rhs;
-        (r.y >= lower.0 && r.x >= lower.1 && r.y < upper.0 && r.x < upper.1).then_some(r)
+impl<T> Coord2<T>
+where
+    T: Integer + ConstZero + CheckedAddSigned + Copy,
+    <T as CheckedAddSigned>::S: Signed + ConstZero + ConstOne,
+{
+    /// Takes a step in `dir`, or returns `None` if that leaves `0..bounds`.
+    pub fn step_within<B: Unsigned + Integer + TryFrom<T>>(
+        self,
+        dir: impl Direction,
+        bounds: (B, B),
+    ) -> Option<Self> {
+        self.checked_add_with_upper(dir.delta(), bounds)
+    }
+
+    /// Takes a step in `dir`, or returns `None` if that goes out of the
+    /// range of `T`, like below zero for `usize`.
+    pub fn checked_step(self, dir: impl Direction) -> Option<Self> {
+        let d: Coord2<<T as CheckedAddSigned>::S> = dir.delta();
+        Some(Coord2 {
+            y: self.y.checked_add_signed(d.y)?,
+            x: self.x.checked_add_signed(d.x)?,
+        })
+    }
+
+    /// The coordinates next to this one along the axes, within `0..bounds`.
+    /// The bounds are `(height, width)`, like the `dim()` of an array.
+    pub fn neighbors4<B: Unsigned + Integer + TryFrom<T> + Copy>(
+        self,
+        bounds: (B, B),
+    ) -> impl Iterator<Item = Self> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |d| self.step_within(d, bounds))
+    }
+
+    /// The coordinates next to this one, including diagonally, within
+    /// `0..bounds`.
+    pub fn neighbors8<B: Unsigned + Integer + TryFrom<T> + Copy>(
+        self,
+        bounds: (B, B),
+    ) -> impl Iterator<Item = Self> {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step_within(d, bounds))
+    }
+
+    /// The coordinates next to this one along the axes, limited only by the
+    /// range of `T`.
+    pub fn neighbors4_unbounded(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |d| self.checked_step(d))
+    }
+
+    /// The coordinates next to this one, including diagonally, limited only
+    /// by the range of `T`.
+    pub fn neighbors8_unbounded(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |d| self.checked_step(d))
+    }
+
+    /// The four coordinates next to this one along the axes, wrapping around
+    /// at the edges of `0..bounds`.
+    pub fn wrapping_neighbors<B>(self, bounds: (B, B)) -> impl Iterator<Item = Self>
+    where
+        B: Integer
+            + Unsigned
+            + Copy
+            + Debug
+            + Into<T>
+            + TryInto<<T as CheckedAddSigned>::S, Error: Debug>,
+    {
+        Dir4::ALL
+            .into_iter()
+            .map(move |d| self.wrapping_add(d.delta(), bounds))
     }
 }
 
-impl Coord2<usize> {
-    pub fn usizes(self) -> (usize, usize) {
-        self.into()
+impl<T: Integer + Signed + ConstZero + ConstOne> Coord2<T> {
+    pub fn step(self, dir: impl Direction) -> Self {
+        self + dir.delta()
     }
 }
 
-impl Coord2<isize> {
-    pub fn usizes(self) -> (usize, usize) {
-        self.try_into_unsigned_tuple().unwrap()
+impl<T: Integer + ConstZero + Signed> Coord2<T> {
+    pub fn checked_add_with_bounds(self, rhs: Self, lower: (T, T), upper: (T, T)) -> Option<Self> {
+        let r = self + rhs;
+        (r.y >= lower.0 && r.x >= lower.1 && r.y < upper.0 && r.x < upper.1).then_some(r)
     }
+}
 
+impl Coord2<isize> {
     pub const UP: Self = Coord2 { y: -1, x: 0 };
     pub const DOWN: Self = Coord2 { y: 1, x: 0 };
     pub const LEFT: Self = Coord2 { y: 0, x: -1 };
@@ -214,26 +389,6 @@ pub trait CheckedAddSigned {
         Self: Sized;
 }
 
-impl CheckedAddSigned for usize {
-    type S = isize;
-    fn checked_add_signed(self, other: isize) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        self.checked_add_signed(other)
-    }
-}
-
-impl CheckedAddSigned for isize {
-    type S = isize;
-    fn checked_add_signed(self, other: isize) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        self.checked_add(other)
-    }
-}
-
 pub trait CheckedSignedSub {
     type S: Integer + Signed;
     fn checked_signed_sub(self, other: Self) -> Option<Self::S>
@@ -241,39 +396,168 @@ pub trait CheckedSignedSub {
         Self: Sized;
 }
 
-impl CheckedSignedSub for usize {
-    type S = isize;
-    fn checked_signed_sub(self, rhs: Self) -> Option<isize>
-    where
-        Self: Sized,
-    {
-        // https://github.com/rust-lang/rust/pull/126042
-        let res = self.wrapping_sub(rhs) as isize;
-        let overflow = (self >= rhs) == (res < 0);
-
-        if !overflow {
-            Some(res)
-        } else {
-            None
-        }
-    }
-}
-
 pub trait AbsDiff {
     type Output: Integer;
     fn abs_diff(self, other: Self) -> Self::Output;
 }
 
-impl AbsDiff for usize {
-    type Output = usize;
-    fn abs_diff(self, other: Self) -> Self::Output {
-        self.abs_diff(other)
-    }
+macro_rules! impl_unsigned_traits {
+    ($($u:ty => $s:ty),*) => {
+        $(
+            impl CheckedAddSigned for $u {
+                type S = $s;
+                fn checked_add_signed(self, other: $s) -> Option<Self> {
+                    self.checked_add_signed(other)
+                }
+            }
+
+            impl CheckedSignedSub for $u {
+                type S = $s;
+                fn checked_signed_sub(self, rhs: Self) -> Option<$s> {
+                    // https://github.com/rust-lang/rust/pull/126042
+                    let res = self.wrapping_sub(rhs) as $s;
+                    let overflow = (self >= rhs) == (res < 0);
+
+                    if !overflow {
+                        Some(res)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl AbsDiff for $u {
+                type Output = $u;
+                fn abs_diff(self, other: Self) -> $u {
+                    self.abs_diff(other)
+                }
+            }
+        )*
+    };
 }
 
-impl AbsDiff for isize {
-    type Output = usize;
-    fn abs_diff(self, other: Self) -> Self::Output {
-        self.abs_diff(other)
+macro_rules! impl_signed_traits {
+    ($($s:ty => $u:ty),*) => {
+        $(
+            impl CheckedAddSigned for $s {
+                type S = $s;
+                fn checked_add_signed(self, other: $s) -> Option<Self> {
+                    self.checked_add(other)
+                }
+            }
+
+            impl CheckedSignedSub for $s {
+                type S = $s;
+                fn checked_signed_sub(self, rhs: Self) -> Option<$s> {
+                    self.checked_sub(rhs)
+                }
+            }
+
+            impl AbsDiff for $s {
+                type Output = $u;
+                fn abs_diff(self, other: Self) -> $u {
+                    self.abs_diff(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_traits!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+impl_signed_traits!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_neighbors() {
+        let corner = Coord2::<usize> { y: 0, x: 0 };
+        assert_eq!(
+            vec![Coord2 { y: 0, x: 1 }, Coord2 { y: 1, x: 0 }],
+            corner.neighbors4((3usize, 3)).collect_vec()
+        );
+        assert_eq!(3, corner.neighbors8((3usize, 3)).count());
+        assert_eq!(2, corner.neighbors4_unbounded().count());
+        assert_eq!(
+            vec![
+                Coord2 { y: 2, x: 0 },
+                Coord2 { y: 0, x: 1 },
+                Coord2 { y: 1, x: 0 },
+                Coord2 { y: 0, x: 2 }
+            ],
+            corner.wrapping_neighbors((3usize, 3)).collect_vec()
+        );
+
+        let middle = Coord2::<isize> { y: 1, x: 1 };
+        assert_eq!(4, middle.neighbors4((3usize, 3)).count());
+        assert_eq!(8, middle.neighbors8((3usize, 3)).unique().count());
+        assert_eq!(5, middle.neighbors8((2usize, 3)).count());
+        assert_eq!(
+            8,
+            Coord2::<isize> { y: -5, x: 0 }
+                .neighbors8_unbounded()
+                .count()
+        );
+        assert_eq!(
+            None,
+            Coord2::<usize> { y: 0, x: 1 }.checked_step(Dir8::UpLeft)
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_integer_widths() {
+        let mut a = Coord2 { y: 3i32, x: -4 };
+        a += Coord2 { y: 1, x: 1 };
+        assert_eq!(Coord2 { y: 4, x: -3 }, a);
+        a -= Coord2 { y: 2, x: 0 };
+        assert_eq!(Coord2 { y: 2, x: -3 }, a);
+        assert_eq!(Coord2 { y: 2, x: -2 }, Coord2 { y: 4, x: -5 } / 2);
+        assert_eq!(-7, Coord2 { y: 1i64, x: 2 }.dot(Coord2 { y: -3, x: -2 }));
+        assert_eq!(1, Coord2::<i16> { y: 0, x: 1 }.cross(Coord2 { y: 1, x: 0 }));
+
+        let b = Coord2 { y: 5u16, x: 2 };
+        assert_eq!(Coord2 { y: 3, x: 0 }, b - Coord2 { y: 2, x: 2 });
+        assert_eq!(7u16, b.manhattan_dist(&Coord2 { y: 0, x: 0 }));
+        assert_eq!(
+            5u8,
+            Coord2 { y: -2i8, x: 1 }.manhattan_dist(&Coord2 { y: 1, x: -1 })
+        );
+        assert_eq!(
+            Coord2::<i16> { y: 5, x: -2 },
+            b.signed_sub(Coord2 { y: 0, x: 4 })
+        );
+        assert_eq!(
+            Some(Coord2 { y: 4u8, x: 0 }),
+            Coord2 { y: 4u8, x: 1 }.checked_add_with_upper(Coord2 { y: 0, x: -1 }, (5u8, 5))
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let c = Coord2 { y: 300i32, x: -1 };
+        assert_eq!(None, c.try_cast::<u16>());
+        assert_eq!(Some(Coord2 { y: 300i16, x: -1 }), c.try_cast());
+        assert_eq!(Coord2 { y: 300i64, x: -1 }, c.cast());
+        assert_eq!(None, c.try_usizes());
+        assert_eq!((300, 1), Coord2 { y: 300i32, x: 1 }.usizes());
+        assert_eq!((2, 3), Coord2 { y: 2u8, x: 3 }.usizes());
+        assert!(<(usize, usize)>::try_from(c).is_err());
+        assert_eq!(
+            Ok((1, 2)),
+            <(usize, usize)>::try_from(Coord2 { y: 1i64, x: 2 })
+        );
+        assert_eq!(
+            Ok(Coord2 { y: 1u8, x: 2 }),
+            Coord2::try_from((1usize, 2usize))
+        );
+        assert!(Coord2::<u8>::try_from((256usize, 0usize)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Coordinate out of usize range")]
+    fn test_negative_usizes() {
+        Coord2 { y: -1i32, x: 0 }.usizes();
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use ndarray::{Dim, NdIndex};
+use num_integer::Integer;
+use num_traits::{ConstOne, ConstZero, Signed, Unsigned, Zero};
+
+use crate::coord2::{AbsDiff, CheckedAddSigned, CheckedSignedSub};
+
+/// A coordinate in 3D space. Ordered by `z`, then `y`, then `x`, and used as
+/// an `Array3` index in the same `(z, y, x)` order, like [`Coord2`] is
+/// `(y, x)`.
+///
+/// [`Coord2`]: crate::coord2::Coord2
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Coord3<T: Integer = usize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Ord + Integer> PartialOrd for Coord3<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T: Ord + Integer> Ord for Coord3<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.z
+            .cmp(&other.z)
+            .then(self.y.cmp(&other.y))
+            .then(self.x.cmp(&other.x))
+    }
+}
+
+impl<T: Display + Integer> Display for Coord3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(x={}, y={}, z={})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Integer> From<(T, T, T)> for Coord3<T> {
+    /// Converts from `(z, y, x)`, the order of an `Array3` index.
+    fn from((z, y, x): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Integer> From<Coord3<T>> for (T, T, T) {
+    /// Converts to `(z, y, x)`, the order of an `Array3` index.
+    fn from(value: Coord3<T>) -> Self {
+        (value.z, value.y, value.x)
+    }
+}
+
+impl<T: Integer> From<[T; 3]> for Coord3<T> {
+    /// Converts from `[z, y, x]`, the order of an `Array3` index.
+    fn from([z, y, x]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Integer> From<Coord3<T>> for [T; 3] {
+    /// Converts to `[z, y, x]`, the order of an `Array3` index.
+    fn from(value: Coord3<T>) -> Self {
+        [value.z, value.y, value.x]
+    }
+}
+
+impl<T: Integer + AbsDiff + Copy> Coord3<T> {
+    pub fn manhattan_dist(&self, other: &Self) -> <T as AbsDiff>::Output {
+        let x = self.x.abs_diff(other.x);
+        let y = self.y.abs_diff(other.y);
+        let z = self.z.abs_diff(other.z);
+        x + y + z
+    }
+}
+
+unsafe impl NdIndex<Dim<[usize; 3]>> for Coord3<usize> {
+    fn index_checked(&self, dim: &Dim<[usize; 3]>, strides: &Dim<[usize; 3]>) -> Option<isize> {
+        (self.z, self.y, self.x).index_checked(dim, strides)
+    }
+
+    fn index_unchecked(&self, strides: &Dim<[usize; 3]>) -> isize {
+        (self.z, self.y, self.x).index_unchecked(strides)
+    }
+}
+
+impl<T: Integer> Sub for Coord3<T> {
+    type Output = Coord3<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Integer> Add for Coord3<T> {
+    type Output = Coord3<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coord3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Integer + Copy> Mul<T> for Coord3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Coord3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T: Integer + Signed> Neg for Coord3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Integer + CheckedSignedSub> Coord3<T> {
+    pub fn signed_sub(self, rhs: Self) -> Coord3<<T as CheckedSignedSub>::S> {
+        let sub = |a: T, b: T| a.checked_signed_sub(b).expect("Number out of range");
+        Coord3 {
+            x: sub(self.x, rhs.x),
+            y: sub(self.y, rhs.y),
+            z: sub(self.z, rhs.z),
+        }
+    }
+}
+
+impl<T> Coord3<T>
+where
+    T: Integer + ConstZero + CheckedAddSigned + Copy,
+{
+    /// Adds a signed offset, or returns `None` if the result is outside
+    /// `0..bounds` on any axis. The bounds are in `(z, y, x)` order, like
+    /// the `dim()` of an `Array3`.
+    pub fn checked_add_with_upper<B: Unsigned + Integer + TryFrom<T>>(
+        self,
+        rhs: Coord3<<T as CheckedAddSigned>::S>,
+        bounds: (B, B, B),
+    ) -> Option<Self> {
+        let in_bounds =
+            |v: T, bound: B| v >= T::ZERO && B::try_from(v).ok().is_some_and(|v| v < bound);
+        let x = self.x.checked_add_signed(rhs.x)?;
+        let y = self.y.checked_add_signed(rhs.y)?;
+        let z = self.z.checked_add_signed(rhs.z)?;
+        (in_bounds(z, bounds.0) && in_bounds(y, bounds.1) && in_bounds(x, bounds.2))
+            .then_some(Coord3 { x, y, z })
+    }
+
+    /// Adds a signed offset, wrapping around to stay in `0..bounds` on each
+    /// axis. The bounds are in `(z, y, x)` order, like the `dim()` of an
+    /// `Array3`.
+    pub fn wrapping_add<B>(self, rhs: Coord3<<T as CheckedAddSigned>::S>, bounds: (B, B, B)) -> Self
+    where
+        B: Integer
+            + Unsigned
+            + Copy
+            + Debug
+            + Into<T>
+            + TryInto<<T as CheckedAddSigned>::S, Error: Debug>,
+    {
+        let wrap = |v: T, d: <T as CheckedAddSigned>::S, bound: B| {
+            let b = bound
+                .try_into()
+                .expect("Can't convert unsigned bound to signed");
+            let d = d.mod_floor(&b);
+            debug_assert!(d >= <T as CheckedAddSigned>::S::zero());
+            v.checked_add_signed(d).unwrap() % bound.into()
+        };
+
+        Coord3 {
+            x: wrap(self.x, rhs.x, bounds.2),
+            y: wrap(self.y, rhs.y, bounds.1),
+            z: wrap(self.z, rhs.z, bounds.0),
+        }
+    }
+}
+
+impl<T: Integer + ConstZero + Signed + Copy> Coord3<T> {
+    /// Adds `rhs`, or returns `None` if the result is outside
+    /// `lower..upper` on any axis.
+    pub fn checked_add_with_bounds(self, rhs: Self, lower: Self, upper: Self) -> Option<Self> {
+        let r = self + rhs;
+        (r.x >= lower.x
+            && r.y >= lower.y
+            && r.z >= lower.z
+            && r.x < upper.x
+            && r.y < upper.y
+            && r.z < upper.z)
+            .then_some(r)
+    }
+}
+
+impl<T: Integer + ConstZero + ConstOne + Signed + Copy> Coord3<T> {
+    /// The six coordinates sharing a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (o, z) = (T::ONE, T::ZERO);
+        [
+            (o, z, z),
+            (-o, z, z),
+            (z, o, z),
+            (z, -o, z),
+            (z, z, o),
+            (z, z, -o),
+        ]
+        .into_iter()
+        .map(move |d| self + d.into())
+    }
+
+    /// The 26 coordinates sharing a face, an edge or a corner with this one.
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        let d = [-T::ONE, T::ZERO, T::ONE];
+        itertools::iproduct!(d, d, d)
+            .filter(|&d| d != (T::ZERO, T::ZERO, T::ZERO))
+            .map(move |d| self + d.into())
+    }
+}
+
+impl Coord3<usize> {
+    /// The coordinates sharing a face with this one within `0..bounds`,
+    /// given in `(z, y, x)` order like the `dim()` of an `Array3`.
+    pub fn neighbors6_within(
+        self,
+        bounds: (usize, usize, usize),
+    ) -> impl Iterator<Item = Coord3<usize>> {
+        Coord3::<isize>::ZERO
+            .neighbors6()
+            .filter_map(move |d| self.checked_add_with_upper(d, bounds))
+    }
+}
+
+impl<T: Integer + ConstZero> Coord3<T> {
+    pub const ZERO: Coord3<T> = Coord3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Coord3<isize> = Coord3 { x: 1, y: -2, z: 3 };
+        let b = Coord3::from((-6, 5, 4));
+        assert_eq!(Coord3 { x: 5, y: 3, z: -3 }, a + b);
+        assert_eq!(Coord3 { x: -3, y: -7, z: 9 }, a - b);
+        assert_eq!(Coord3 { x: 2, y: -4, z: 6 }, a * 2);
+        assert_eq!(Coord3 { x: -1, y: 2, z: -3 }, -a);
+        assert_eq!(3 + 7 + 9, a.manhattan_dist(&b));
+        assert_eq!(
+            Coord3::<isize> { x: -1, y: 1, z: 0 },
+            Coord3::<usize> { x: 1, y: 2, z: 3 }.signed_sub(Coord3 { x: 2, y: 1, z: 3 })
+        );
+        assert!(Coord3 { x: 0, y: 0, z: 1 } > Coord3 { x: 5, y: 5, z: 0 });
+        assert_eq!(
+            Coord3 { x: 1, y: 0, z: 2 },
+            Coord3::<usize> { x: 3, y: 2, z: 2 } - Coord3 { x: 2, y: 2, z: 0 }
+        );
+    }
+
+    #[test]
+    fn test_bounded_adds() {
+        let c = Coord3::<usize> { x: 0, y: 1, z: 2 };
+        assert_eq!(
+            Some(Coord3 { x: 1, y: 0, z: 2 }),
+            c.checked_add_with_upper(Coord3 { x: 1, y: -1, z: 0 }, (3usize, 2, 2))
+        );
+        assert_eq!(
+            None,
+            c.checked_add_with_upper(Coord3 { x: -1, y: 0, z: 0 }, (3usize, 2, 2))
+        );
+        assert_eq!(
+            None,
+            c.checked_add_with_upper(Coord3 { x: 0, y: 0, z: 1 }, (3usize, 2, 2))
+        );
+        assert_eq!(
+            Coord3 { x: 1, y: 1, z: 0 },
+            c.wrapping_add(Coord3 { x: -3, y: 4, z: 1 }, (3usize, 2, 2))
+        );
+        assert_eq!(
+            None,
+            Coord3 { x: 0, y: 0, z: 0 }.checked_add_with_bounds(
+                Coord3 { x: 0, y: 0, z: -2 },
+                Coord3 {
+                    x: -1,
+                    y: -1,
+                    z: -1
+                },
+                Coord3 { x: 1, y: 1, z: 1 }
+            )
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let c = Coord3 {
+            x: 0isize,
+            y: 0,
+            z: 0,
+        };
+        assert_eq!(6, c.neighbors6().unique().count());
+        assert!(c.neighbors6().all(|n| n.manhattan_dist(&c) == 1));
+        assert_eq!(26, c.neighbors26().unique().count());
+        assert!(!c.neighbors26().contains(&c));
+
+        let corner = Coord3::<usize> { x: 0, y: 1, z: 0 };
+        assert_eq!(3, corner.neighbors6_within((1, 3, 2)).count());
+    }
+
+    #[test]
+    fn test_array_index() {
+        let mut arr = ndarray::Array3::<u8>::zeros((2, 3, 4));
+        arr[Coord3 { x: 3, y: 2, z: 1 }] = 1;
+        assert_eq!(1, arr[(1, 2, 3)]);
+        assert_eq!(Coord3 { x: 3, y: 2, z: 1 }, Coord3::from((1, 2, 3)));
+        let index: (usize, usize, usize) = Coord3::from([1, 2, 3]).into();
+        assert_eq!(1, arr[index]);
+
+        let far_corner = Coord3 { x: 3, y: 2, z: 1 };
+        let neighbors = far_corner.neighbors6_within(arr.dim()).collect_vec();
+        assert_eq!(3, neighbors.len());
+        assert!(neighbors.iter().all(|&n| arr.get(n).is_some()));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    array,
+    fmt::Display,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+};
+
+use num_integer::Integer;
+use num_traits::{ConstOne, ConstZero, Signed, Zero};
+
+use crate::coord2::{AbsDiff, Coord2};
+use crate::coord3::Coord3;
+
+/// A coordinate with `N` axes, for puzzles where the number of dimensions
+/// varies between the parts. The axes are in the order given, and
+/// coordinates are ordered lexicographically by them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct CoordN<T: Integer, const N: usize>(pub [T; N]);
+
+impl<T: Display + Integer, const N: usize> Display for CoordN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", v)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: Integer, const N: usize> From<[T; N]> for CoordN<T, N> {
+    fn from(value: [T; N]) -> Self {
+        CoordN(value)
+    }
+}
+
+impl<T: Integer> From<Coord2<T>> for CoordN<T, 2> {
+    /// Converts to `[y, x]`, so the axes keep [`Coord2`]'s order.
+    fn from(value: Coord2<T>) -> Self {
+        CoordN([value.y, value.x])
+    }
+}
+
+impl<T: Integer> From<Coord3<T>> for CoordN<T, 3> {
+    /// Converts to `[z, y, x]`, so the axes keep [`Coord3`]'s order.
+    fn from(value: Coord3<T>) -> Self {
+        CoordN(value.into())
+    }
+}
+
+impl<T: Integer, const N: usize> Index<usize> for CoordN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T: Integer, const N: usize> IndexMut<usize> for CoordN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<T: Integer + Copy, const N: usize> CoordN<T, N> {
+    /// Applies `f` to the values of each axis of `self` and `rhs`.
+    pub fn zip_with<U: Integer>(self, rhs: Self, mut f: impl FnMut(T, T) -> U) -> CoordN<U, N> {
+        CoordN(array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+
+    /// The coordinate with one more axis, set to `value`.
+    pub fn extend<const M: usize>(self, value: T) -> CoordN<T, M> {
+        assert_eq!(N + 1, M, "Can only extend by one axis");
+        CoordN(array::from_fn(|i| if i < N { self.0[i] } else { value }))
+    }
+}
+
+impl<T: Integer + AbsDiff + Copy, const N: usize> CoordN<T, N> {
+    pub fn manhattan_dist(&self, other: &Self) -> <T as AbsDiff>::Output {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(&a, &b)| a.abs_diff(b))
+            .fold(<T as AbsDiff>::Output::zero(), |acc, d| acc + d)
+    }
+}
+
+impl<T: Integer + Signed + Copy, const N: usize> Sub for CoordN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Integer + Copy, const N: usize> Add for CoordN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Integer + Copy, const N: usize> Mul<T> for CoordN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        CoordN(self.0.map(|v| v * rhs))
+    }
+}
+
+impl<T: Integer + Signed + Copy, const N: usize> Neg for CoordN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        CoordN(self.0.map(|v| -v))
+    }
+}
+
+impl<T: Integer + Signed + Copy, const N: usize> CoordN<T, N> {
+    /// Adds `rhs`, or returns `None` if the result is outside
+    /// `lower..upper` on any axis.
+    pub fn checked_add_with_bounds(self, rhs: Self, lower: Self, upper: Self) -> Option<Self> {
+        let r = self + rhs;
+        (0..N)
+            .all(|i| r.0[i] >= lower.0[i] && r.0[i] < upper.0[i])
+            .then_some(r)
+    }
+}
+
+impl<T: Integer + ConstZero + ConstOne + Signed + Copy, const N: usize> CoordN<T, N> {
+    /// The `2 * N` coordinates one step away along a single axis.
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-T::ONE, T::ONE].map(|d| {
+                let mut n = self;
+                n.0[axis] = n.0[axis] + d;
+                n
+            })
+        })
+    }
+
+    /// The `3^N - 1` coordinates that differ by at most one on every axis.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(N as u32);
+        (0..count)
+            .filter(move |&i| i != count / 2)
+            .map(move |mut i| {
+                let mut n = self;
+                for v in &mut n.0 {
+                    *v = *v + [-T::ONE, T::ZERO, T::ONE][i % 3];
+                    i /= 3;
+                }
+                n
+            })
+    }
+}
+
+impl<T: Integer + ConstZero, const N: usize> CoordN<T, N> {
+    pub const ZERO: Self = CoordN([T::ZERO; N]);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = CoordN([1isize, -2, 3, 0]);
+        let b = CoordN([4, 5, -6, 1]);
+        assert_eq!(CoordN([5, 3, -3, 1]), a + b);
+        assert_eq!(CoordN([-3, -7, 9, -1]), a - b);
+        assert_eq!(CoordN([3, -6, 9, 0]), a * 3);
+        assert_eq!(CoordN([-1, 2, -3, 0]), -a);
+        assert_eq!(3 + 7 + 9 + 1, a.manhattan_dist(&b));
+        assert_eq!("(1, -2, 3, 0)", a.to_string());
+
+        let c: CoordN<isize, 3> = Coord3 { x: 1, y: 2, z: 3 }.into();
+        assert_eq!(CoordN([3, 2, 1, 7]), c.extend(7));
+        assert_eq!(CoordN([1usize, 2]), Coord2 { y: 1, x: 2 }.into());
+        assert_eq!(
+            None,
+            c.checked_add_with_bounds(CoordN([1, 0, 0]), CoordN::ZERO, CoordN([4, 4, 4]))
+        );
+
+        let (a, b) = (Coord2 { y: 1, x: 5 }, Coord2 { y: 2, x: 0 });
+        assert_eq!(a < b, CoordN::from(a) < CoordN::from(b));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let c = CoordN([0i32, 0, 0, 0]);
+        assert_eq!(8, c.orthogonal_neighbors().unique().count());
+        assert_eq!(80, c.neighbors().unique().count());
+        assert!(!c.neighbors().contains(&c));
+        assert_eq!(8, CoordN([5isize, 5]).neighbors().count());
+        assert!(CoordN([5isize, 5])
+            .neighbors()
+            .all(|n| n.manhattan_dist(&CoordN([5, 5])) <= 2));
+    }
+}
@@ -0,0 +1,274 @@
+//! Directions on a grid, with `Up` towards smaller `y` like in the puzzle
+//! maps.
+//!
+//! ```
+//! use aoclib::coord2::Coord2;
+//! use aoclib::Dir4;
+//!
+//! let dir: Dir4 = '>'.try_into().unwrap();
+//! assert_eq!(Dir4::Down, dir.cw());
+//!
+//! let pos = Coord2::<usize> { y: 0, x: 0 };
+//! assert_eq!(Some(Coord2 { y: 0, x: 1 }), pos.step_within(dir, (2usize, 2)));
+//! assert_eq!(None, pos.step_within(Dir4::Up, (2usize, 2)));
+//! ```
+
+use crate::coord2::Coord2;
+use crate::error::found;
+use crate::InputError;
+use num_integer::Integer;
+use num_traits::{ConstOne, ConstZero, Signed};
+use std::str::FromStr;
+
+/// A direction that can be stepped in, see [`Coord2::step`] and
+/// [`Coord2::step_within`].
+pub trait Direction: Copy {
+    /// The change of coordinates when taking one step in this direction.
+    fn delta<T: Integer + Signed + ConstZero + ConstOne>(self) -> Coord2<T>;
+}
+
+/// One of the four directions along the grid axes, in clockwise order.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// The direction after turning 90° clockwise.
+    pub fn cw(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after turning 90° counter-clockwise.
+    pub fn ccw(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    pub fn iter() -> impl Iterator<Item = Dir4> {
+        Dir4::ALL.into_iter()
+    }
+}
+
+impl Direction for Dir4 {
+    fn delta<T: Integer + Signed + ConstZero + ConstOne>(self) -> Coord2<T> {
+        let (y, x) = match self {
+            Dir4::Up => (-T::ONE, T::ZERO),
+            Dir4::Right => (T::ZERO, T::ONE),
+            Dir4::Down => (T::ONE, T::ZERO),
+            Dir4::Left => (T::ZERO, -T::ONE),
+        };
+        Coord2 { y, x }
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = char;
+
+    /// Reads an arrow (`^>v<`), a letter of `UDLR` or a compass point of
+    /// `NESW`.
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Dir4::Up),
+            '>' | 'R' | 'E' => Ok(Dir4::Right),
+            'v' | 'D' | 'S' => Ok(Dir4::Down),
+            '<' | 'L' | 'W' => Ok(Dir4::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = InputError;
+
+    /// Reads a single character, see [`Dir4::try_from`].
+    fn from_str(s: &str) -> Result<Self, InputError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| InputError::unexpected("a direction", found(s)))
+    }
+}
+
+impl From<Dir4> for Coord2<isize> {
+    fn from(dir: Dir4) -> Self {
+        dir.delta()
+    }
+}
+
+/// One of the eight directions to the adjacent cells of a grid, in
+/// clockwise order.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// The direction after turning 45° clockwise.
+    pub fn cw(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after turning 45° counter-clockwise.
+    pub fn ccw(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The direction as a [`Dir4`], if it is not diagonal.
+    pub fn to_dir4(self) -> Option<Dir4> {
+        (!self.is_diagonal()).then(|| Dir4::ALL[self as usize / 2])
+    }
+
+    pub fn iter() -> impl Iterator<Item = Dir8> {
+        Dir8::ALL.into_iter()
+    }
+}
+
+impl Direction for Dir8 {
+    fn delta<T: Integer + Signed + ConstZero + ConstOne>(self) -> Coord2<T> {
+        match self.to_dir4() {
+            Some(dir) => dir.delta(),
+            None => self.ccw().to_dir4().unwrap().delta() + self.cw().to_dir4().unwrap().delta(),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = InputError;
+
+    /// Reads a compass point like `N` or `SW`, or anything [`Dir4`] reads.
+    fn from_str(s: &str) -> Result<Self, InputError> {
+        match s {
+            "NE" => Ok(Dir8::UpRight),
+            "SE" => Ok(Dir8::DownRight),
+            "SW" => Ok(Dir8::DownLeft),
+            "NW" => Ok(Dir8::UpLeft),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+impl From<Dir8> for Coord2<isize> {
+    fn from(dir: Dir8) -> Self {
+        dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::Right, Dir4::Up.cw());
+        assert_eq!(Dir4::Left, Dir4::Up.ccw());
+        assert_eq!(Dir4::Down, Dir4::Up.reverse());
+        for d in Dir4::iter() {
+            assert_eq!(d, d.cw().ccw());
+            assert_eq!(d.reverse(), d.cw().cw());
+            assert_eq!(d.is_vertical(), d.cw().is_horizontal());
+        }
+
+        assert_eq!(Dir8::UpLeft, Dir8::Up.ccw());
+        assert_eq!(Dir8::DownRight, Dir8::UpLeft.reverse());
+        assert_eq!(Some(Dir4::Left), Dir8::Left.to_dir4());
+        assert_eq!(None, Dir8::DownLeft.to_dir4());
+        assert!(Dir4::iter().all(|d| Dir8::from(d).to_dir4() == Some(d)));
+    }
+
+    #[test]
+    fn test_deltas() {
+        assert_eq!(Coord2::<isize>::UP, Dir4::Up.into());
+        assert_eq!(Coord2::<isize>::RIGHT, Dir4::Right.into());
+        assert_eq!(Coord2 { y: 1i32, x: -1 }, Dir8::DownLeft.delta());
+        assert_eq!(8, Dir8::iter().map(Coord2::<isize>::from).unique().count());
+        for d in Dir8::iter() {
+            assert_eq!(-d.delta::<isize>(), d.reverse().delta());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            vec![Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right],
+            "^vLE"
+                .chars()
+                .map(|c| Dir4::try_from(c).unwrap())
+                .collect_vec()
+        );
+        assert_eq!(Ok(Dir4::Left), "W".parse());
+        assert!("UU".parse::<Dir4>().is_err());
+        assert_eq!(Ok(Dir8::DownLeft), "SW".parse());
+        assert_eq!(Ok(Dir8::Right), ">".parse());
+        assert_eq!(
+            "expected a direction, found \"x\"",
+            "x".parse::<Dir8>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_steps() {
+        let c = Coord2::<usize> { y: 1, x: 0 };
+        assert_eq!(
+            Some(Coord2 { y: 0, x: 1 }),
+            c.step_within(Dir8::UpRight, (2usize, 2))
+        );
+        assert_eq!(None, c.step_within(Dir8::Left, (2usize, 2)));
+        assert_eq!(None, c.step_within(Dir4::Down, (2usize, 2)));
+        assert_eq!(
+            Coord2 { y: 2, x: 2 },
+            Coord2::<isize> { y: 2, x: 3 }.step(Dir4::Left)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+use std::io;
+
+/// Malformed puzzle input. Lines and columns are counted from 1, and are
+/// added by the reader that knows them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InputError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub kind: InputErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InputErrorKind {
+    /// The input, or a part of it that must not be, is empty
+    Empty,
+    /// Something else than expected was found
+    Unexpected { expected: String, found: String },
+    /// A number does not fit the integer type it is read into
+    Overflow { number: String, ty: &'static str },
+    /// A value that could not be parsed
+    Invalid {
+        what: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl InputError {
+    pub fn new(kind: InputErrorKind) -> InputError {
+        InputError {
+            line: None,
+            column: None,
+            kind,
+        }
+    }
+
+    pub fn unexpected(expected: impl Into<String>, found: impl Into<String>) -> InputError {
+        InputError::new(InputErrorKind::Unexpected {
+            expected: expected.into(),
+            found: found.into(),
+        })
+    }
+
+    pub fn overflow<T>(number: &str) -> InputError {
+        InputError::new(InputErrorKind::Overflow {
+            number: number.to_owned(),
+            ty: std::any::type_name::<T>(),
+        })
+    }
+
+    pub fn invalid(what: impl Into<String>, value: &str, reason: impl Display) -> InputError {
+        InputError::new(InputErrorKind::Invalid {
+            what: what.into(),
+            value: value.to_owned(),
+            reason: reason.to_string(),
+        })
+    }
+
+    /// Sets the line, keeping one that is already set.
+    pub fn at_line(self, line: usize) -> InputError {
+        InputError {
+            line: self.line.or(Some(line)),
+            ..self
+        }
+    }
+
+    /// Sets the column, keeping one that is already set.
+    pub fn at_column(self, column: usize) -> InputError {
+        InputError {
+            column: self.column.or(Some(column)),
+            ..self
+        }
+    }
+
+    /// Sets the line and column of the byte offset `pos` in `input`, unless
+    /// already set.
+    pub fn at_offset(self, input: &str, pos: usize) -> InputError {
+        let before = &input[..pos];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.at_line(line)
+            .at_column(before[line_start..].chars().count() + 1)
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+
+        match &self.kind {
+            InputErrorKind::Empty => write!(f, "input is empty"),
+            InputErrorKind::Unexpected { expected, found } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            InputErrorKind::Overflow { number, ty } => {
+                write!(f, "{} does not fit in {}", number, ty)
+            }
+            InputErrorKind::Invalid {
+                what,
+                value,
+                reason,
+            } => write!(f, "invalid {} {:?}: {}", what, value, reason),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<InputError> for io::Error {
+    fn from(e: InputError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+/// Describes what was found at the start of `s`, for
+/// [`InputErrorKind::Unexpected`].
+pub(crate) fn found(s: &str) -> String {
+    match s.lines().next() {
+        None | Some("") => "end of line".to_owned(),
+        Some(line) if line.chars().count() > 20 => {
+            format!("{:?}...", line.chars().take(20).collect::<String>())
+        }
+        Some(line) => format!("{:?}", line),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = InputError::unexpected("digit", "'x'").at_offset("123\n45x\n", 6);
+        assert_eq!("line 2, column 3: expected digit, found 'x'", e.to_string());
+
+        let e = InputError::overflow::<u8>("300").at_line(4).at_line(5);
+        assert_eq!("line 4: 300 does not fit in u8", e.to_string());
+
+        assert_eq!(
+            "input is empty",
+            InputError::new(InputErrorKind::Empty).to_string()
+        );
+        assert_eq!("end of line", found(""));
+        assert_eq!("\"abc\"", found("abc\ndef"));
+        assert_eq!("\"aaaaaaaaaaaaaaaaaaaa\"...", found(&"a".repeat(30)));
+    }
+}
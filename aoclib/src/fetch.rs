@@ -0,0 +1,258 @@
+//! Downloading puzzle inputs from adventofcode.com.
+//!
+//! Inputs are cached on disk: a file that already exists is never downloaded
+//! again. Requests are spaced out by at least [`Fetcher::min_interval`] to
+//! keep the load on the AoC servers low.
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "github.com/luryus/aoc-rust by lk@lkoskela.com";
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FetchStatus {
+    Cached,
+    Downloaded,
+}
+
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            // AoC redirects to the login page when the token is not accepted,
+            // which should not be mistaken for the input
+            .max_redirects(0)
+            .build()
+            .into();
+
+        Fetcher {
+            agent,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session: session.trim().to_owned(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Changes the session token used for the following downloads, keeping
+    /// the requests spaced out.
+    pub fn set_session(&mut self, session: &str) {
+        self.session = session.trim().to_owned();
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Makes sure the input for the given day exists at `path`, downloading
+    /// it if needed.
+    pub fn fetch_input(&mut self, year: u16, day: u8, path: &Path) -> io::Result<FetchStatus> {
+        if path.metadata().is_ok_and(|m| m.len() > 0) {
+            return Ok(FetchStatus::Cached);
+        }
+
+        let input = self.download_input(year, day)?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Write to a temporary file first so that an interrupted download
+        // does not leave a truncated input behind to be picked up as cached.
+        let tmp_path = path.with_extension("txt.part");
+        let mut f = std::fs::File::create(&tmp_path)?;
+        f.write_all(input.as_bytes())?;
+        f.sync_all()?;
+        std::fs::rename(&tmp_path, path)?;
+
+        Ok(FetchStatus::Downloaded)
+    }
+
+    pub fn download_input(&mut self, year: u16, day: u8) -> io::Result<String> {
+        self.wait_for_turn();
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let res = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+        self.last_request = Some(Instant::now());
+
+        let mut res = res.map_err(io::Error::other)?;
+        let status = res.status().as_u16();
+        let to_login = res
+            .headers()
+            .get("location")
+            .and_then(|l| l.to_str().ok())
+            .is_some_and(|l| l.contains("/auth/login"));
+        let body = res.body_mut().read_to_string().map_err(io::Error::other)?;
+
+        match status {
+            200 => Ok(body),
+            400 => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("Session token rejected by {} (HTTP {})", url, status),
+            )),
+            300..=399 if to_login => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("Session token rejected by {} (HTTP {})", url, status),
+            )),
+            404 => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Input not available yet at {}", url),
+            )),
+            _ => Err(io::Error::other(format!(
+                "Unexpected response from {}: HTTP {}: {}",
+                url,
+                status,
+                body.trim()
+            ))),
+        }
+    }
+
+    fn wait_for_turn(&self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serves the given (status, extra headers, body) responses one
+    /// connection at a time and reports each request line and header block
+    /// through the channel.
+    fn serve(
+        responses: Vec<(u16, &'static str, &'static str)>,
+    ) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, headers, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let request: Vec<String> = (&mut reader)
+                    .lines()
+                    .map(|l| l.unwrap())
+                    .take_while(|l| !l.is_empty())
+                    .collect();
+                tx.send(request).unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    headers,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoclib-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, requests) = serve(vec![(200, "", "1\n2\n3\n")]);
+        let dir = test_dir("cache");
+        let path = dir.join("inputs").join("05.txt");
+
+        let mut fetcher = Fetcher::new("abc123\n").with_base_url(&url);
+        assert_eq!(
+            FetchStatus::Downloaded,
+            fetcher.fetch_input(2022, 5, &path).unwrap()
+        );
+        assert_eq!("1\n2\n3\n", std::fs::read_to_string(&path).unwrap());
+
+        let request = requests.recv().unwrap();
+        assert_eq!("GET /2022/day/5/input HTTP/1.1", request[0]);
+        let headers: Vec<_> = request.iter().map(|h| h.to_ascii_lowercase()).collect();
+        assert!(headers.contains(&format!("user-agent: {}", USER_AGENT.to_ascii_lowercase())));
+        assert!(headers.contains(&"cookie: session=abc123".to_owned()));
+
+        // The server only answers once, so this must come from the cache
+        assert_eq!(
+            FetchStatus::Cached,
+            fetcher.fetch_input(2022, 5, &path).unwrap()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, _requests) = serve(vec![
+            (404, "", "Not found"),
+            (400, "", "Bad session"),
+            (302, "Location: /auth/login\r\n", ""),
+            (500, "", "Internal error"),
+        ]);
+        let dir = test_dir("errors");
+        let path = dir.join("01.txt");
+
+        let mut fetcher = Fetcher::new("abc")
+            .with_base_url(&url)
+            .with_min_interval(Duration::from_millis(100));
+
+        let err = fetcher.fetch_input(2030, 1, &path).unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+        assert!(!path.exists());
+
+        let start = Instant::now();
+        let err = fetcher.download_input(2030, 1).unwrap_err();
+        assert_eq!(io::ErrorKind::PermissionDenied, err.kind());
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        let err = fetcher.download_input(2030, 1).unwrap_err();
+        assert_eq!(io::ErrorKind::PermissionDenied, err.kind());
+
+        // A server error says nothing about the token
+        let err = fetcher.download_input(2030, 1).unwrap_err();
+        assert_eq!(io::ErrorKind::Other, err.kind());
+        assert!(err.to_string().contains("HTTP 500"), "{}", err);
+    }
+}
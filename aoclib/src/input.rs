@@ -0,0 +1,186 @@
+//! Choosing where a day binary reads its puzzle input from.
+//!
+//! The binaries take an input file as the only argument, or read stdin when
+//! there are no arguments. Instead of the file, one of the inputs stored
+//! next to the day can be chosen with a flag:
+//!
+//! ```text
+//! 12 inputs/12.txt       the given file
+//! 12 --input other.txt   the same
+//! 12 --example 2         inputs/12.example2.txt
+//! 12 --alt alice         inputs/12.alice.txt, like someone else's input
+//! ```
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A source of puzzle input, see the [module documentation](self).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+    /// The `n`th example of the day, counted from 1
+    Example(u32),
+    /// An alternate input stored with the day's inputs
+    Alt(String),
+}
+
+impl Input {
+    /// Chooses the input from command line arguments, not including the
+    /// program name.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> io::Result<Input> {
+        let mut args = args.into_iter();
+        let input = match args.next().as_deref() {
+            None => Input::Stdin,
+            Some("--input") => Input::File(flag_value(&mut args, "--input")?.into()),
+            Some("--example") => {
+                let n = flag_value(&mut args, "--example")?;
+                Input::Example(n.parse().map_err(|_| {
+                    invalid_args(format!("Expected an example number, got {:?}", n))
+                })?)
+            }
+            Some("--alt") => Input::Alt(flag_value(&mut args, "--alt")?),
+            Some(flag) if flag.starts_with("--") => {
+                return Err(invalid_args(format!("Unknown option {}", flag)))
+            }
+            Some(path) => Input::File(path.into()),
+        };
+
+        let extra = args.count();
+        if extra > 0 {
+            return Err(invalid_args(format!(
+                "Expected an input file as the only argument, got {} extra arguments",
+                extra
+            )));
+        }
+        Ok(input)
+    }
+
+    /// Chooses the input from the arguments of the running program.
+    pub fn from_env() -> io::Result<Input> {
+        Input::from_args(std::env::args().skip(1))
+    }
+
+    /// The file to read the input from, or `None` for stdin. Examples and
+    /// alternate inputs are looked up in `day`, and cannot be used without
+    /// it.
+    pub fn path(&self, day: Option<&DayInputs>) -> io::Result<Option<PathBuf>> {
+        let day = || {
+            day.ok_or_else(|| {
+                invalid_args("Examples and alternate inputs can only be used by day binaries")
+            })
+        };
+        Ok(match self {
+            Input::Stdin => None,
+            Input::File(path) => Some(path.clone()),
+            Input::Example(n) => Some(day()?.example(*n)),
+            Input::Alt(name) => Some(day()?.file(name)),
+        })
+    }
+
+    /// Reads the input, converting `\r\n` line endings to `\n`.
+    pub fn read(&self, day: Option<&DayInputs>) -> io::Result<String> {
+        match self.path(day)? {
+            Some(path) => crate::read_file_string(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+            None => crate::read_stdin_to_string().map(crate::normalize_newlines),
+        }
+    }
+}
+
+/// The directory holding the stored inputs of one day.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DayInputs {
+    pub dir: PathBuf,
+    pub day: u8,
+}
+
+impl DayInputs {
+    pub fn new(dir: impl Into<PathBuf>, day: u8) -> DayInputs {
+        DayInputs {
+            dir: dir.into(),
+            day,
+        }
+    }
+
+    /// The inputs of the day binary with the given source file, like
+    /// `src/bin/12.rs`, in a crate with the given manifest directory.
+    /// Panics if the file is not named after the day.
+    pub fn of_source(manifest_dir: &str, source_file: &str) -> DayInputs {
+        let day = Path::new(source_file)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(|| panic!("{} is not named after a day", source_file));
+        DayInputs::new(Path::new(manifest_dir).join("src/bin/inputs"), day)
+    }
+
+    /// The puzzle input, `DD.txt`.
+    pub fn input(&self) -> PathBuf {
+        self.dir.join(format!("{:02}.txt", self.day))
+    }
+
+    /// The `n`th example, `DD.exampleN.txt`.
+    pub fn example(&self, n: u32) -> PathBuf {
+        self.file(&format!("example{}", n))
+    }
+
+    /// An input stored as `DD.NAME.txt`.
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{:02}.{}.txt", self.day, name))
+    }
+}
+
+fn flag_value(args: &mut impl Iterator<Item = String>, flag: &str) -> io::Result<String> {
+    args.next()
+        .ok_or_else(|| invalid_args(format!("Expected a value after {}", flag)))
+}
+
+fn invalid_args(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> io::Result<Input> {
+        Input::from_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Input::Stdin, parse(&[]).unwrap());
+        assert_eq!(Input::File("a.txt".into()), parse(&["a.txt"]).unwrap());
+        assert_eq!(
+            Input::File("a.txt".into()),
+            parse(&["--input", "a.txt"]).unwrap()
+        );
+        assert_eq!(Input::Example(2), parse(&["--example", "2"]).unwrap());
+        assert_eq!(
+            Input::Alt("alice".into()),
+            parse(&["--alt", "alice"]).unwrap()
+        );
+
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--example"]).is_err());
+        assert!(parse(&["--example", "x"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_paths() {
+        let day = DayInputs::of_source("/aoc/2022", "2022/src/bin/07.rs");
+        assert_eq!(Path::new("/aoc/2022/src/bin/inputs/07.txt"), day.input());
+        assert_eq!(
+            Some(PathBuf::from("/aoc/2022/src/bin/inputs/07.example2.txt")),
+            Input::Example(2).path(Some(&day)).unwrap()
+        );
+        assert_eq!(
+            Some(PathBuf::from("/aoc/2022/src/bin/inputs/07.alice.txt")),
+            Input::Alt("alice".into()).path(Some(&day)).unwrap()
+        );
+        assert_eq!(None, Input::Stdin.path(Some(&day)).unwrap());
+        assert!(Input::Example(1).path(None).is_err());
+    }
+}
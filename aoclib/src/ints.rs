@@ -0,0 +1,180 @@
+//! Extracting the integers in a string.
+//!
+//! The input is scanned byte by byte for runs of ASCII digits, optionally
+//! preceded by a `-`. Everything else is skipped, so `"x=-2, y=15"` reads as
+//! `-2` and `15`. Numbers are accumulated straight from the digits without
+//! going through `str::parse`.
+
+use crate::InputError;
+use num_bigint::BigInt;
+use std::marker::PhantomData;
+
+/// Integer types that can be read with [`Ints`].
+pub trait ScanInt: Sized {
+    /// Whether a `-` before the digits is read as a sign by default
+    const SIGNED: bool;
+
+    /// Converts the ASCII digits of a number, or returns `None` if it does
+    /// not fit in the type.
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_scan_int {
+    ($signed:literal: $($t:ty),*) => {
+        $(
+            impl ScanInt for $t {
+                const SIGNED: bool = $signed;
+
+                fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+                    // Negative numbers are accumulated downwards, so that
+                    // the minimum value does not overflow
+                    let mut n: $t = 0;
+                    for &d in digits {
+                        let d = (d - b'0') as $t;
+                        n = n.checked_mul(10)?;
+                        n = if negative { n.checked_sub(d)? } else { n.checked_add(d)? };
+                    }
+                    Some(n)
+                }
+            }
+        )*
+    };
+}
+
+impl_scan_int!(true: i8, i16, i32, i64, i128, isize);
+impl_scan_int!(false: u8, u16, u32, u64, u128, usize);
+
+impl ScanInt for BigInt {
+    const SIGNED: bool = true;
+
+    fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+        let n = BigInt::parse_bytes(digits, 10)?;
+        Some(if negative { -n } else { n })
+    }
+}
+
+/// Iterator over the integers in a string. Numbers that do not fit in `T`
+/// are returned as errors with their position.
+pub struct Ints<'a, T> {
+    s: &'a str,
+    pos: usize,
+    signed: bool,
+    ty: PhantomData<T>,
+}
+
+impl<'a, T: ScanInt> Ints<'a, T> {
+    /// Reads the integers of `s`, with `-` read as a sign if `signed` is
+    /// set. Reading signed types unsigned is useful for ranges like `3-5`.
+    pub fn new(s: &'a str, signed: bool) -> Ints<'a, T> {
+        Ints {
+            s,
+            pos: 0,
+            signed,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<T: ScanInt> Iterator for Ints<'_, T> {
+    type Item = Result<T, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.s.as_bytes();
+        let digits_start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let end = digits_start
+            + bytes[digits_start..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - digits_start);
+        let negative = self.signed && digits_start > self.pos && bytes[digits_start - 1] == b'-';
+        let start = if negative {
+            digits_start - 1
+        } else {
+            digits_start
+        };
+        self.pos = end;
+
+        Some(
+            T::from_digits(negative, &bytes[digits_start..end]).ok_or_else(|| {
+                InputError::overflow::<T>(&self.s[start..end]).at_offset(self.s, start)
+            }),
+        )
+    }
+}
+
+/// The integers in `s`, signed if `T` is. Numbers that do not fit in `T` are
+/// skipped.
+pub fn iter_ints<'a, T: ScanInt + 'a>(s: &'a str) -> impl Iterator<Item = T> + 'a {
+    Ints::new(s, T::SIGNED).filter_map(Result::ok)
+}
+
+/// The first `N` integers in `s`, signed if `T` is:
+///
+/// ```
+/// let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+/// let [x, y, bx, by] = aoclib::ints::<4, i64>(line).unwrap();
+/// assert_eq!((2, 18, -2, 15), (x, y, bx, by));
+/// ```
+///
+/// Returns `None` if there are fewer than `N` integers, or one of the first
+/// `N` does not fit in `T`.
+pub fn ints<const N: usize, T: ScanInt>(s: &str) -> Option<[T; N]> {
+    let mut ints = Ints::new(s, T::SIGNED);
+    let mut res = [(); N].map(|_| None);
+    for r in &mut res {
+        *r = Some(ints.next()?.ok()?);
+    }
+    Some(res.map(Option::unwrap))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            vec![123, 22, 123, 2, 123, -22, -7],
+            iter_ints::<i32>("a123b22 123x02\n123-22 -x -7").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 5, 10, 12],
+            iter_ints::<u8>("3-5,10-12").collect::<Vec<_>>()
+        );
+        assert_eq!(Some([3i32, -5]), ints("3-5"));
+        assert_eq!(Some([1u32, 2]), ints("1 2 3"));
+        assert_eq!(None, ints::<3, u32>("1 2"));
+        assert_eq!(None, ints::<2, u8>("1 256"));
+        assert_eq!(Some([] as [u8; 0]), ints(""));
+    }
+
+    #[test]
+    fn test_int_limits() {
+        assert_eq!(
+            vec![i8::MIN, i8::MAX],
+            iter_ints::<i8>("-128 127 128 -129").collect::<Vec<_>>()
+        );
+        assert_eq!(Some([u128::MAX, 0]), ints(&format!("{} 0", u128::MAX)));
+        assert_eq!(Some([i128::MIN]), ints(&i128::MIN.to_string()));
+
+        let big = format!("-{}0", u128::MAX);
+        let [b] = ints::<1, BigInt>(&big).unwrap();
+        assert_eq!(big, b.to_string());
+    }
+
+    #[test]
+    fn test_overflow_errors() {
+        let mut ints = Ints::<u8>::new("1\n2, 300 -4", true);
+        assert_eq!(Some(Ok(1)), ints.next());
+        assert_eq!(Some(Ok(2)), ints.next());
+        assert_eq!(
+            "line 2, column 4: 300 does not fit in u8",
+            ints.next().unwrap().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 8: -4 does not fit in u8",
+            ints.next().unwrap().unwrap_err().to_string()
+        );
+        assert_eq!(None, ints.next());
+    }
+}
@@ -1,82 +1,182 @@
-use itertools::{Either, Itertools};
-use ndarray::{Array2, ArrayView2};
+use itertools::Itertools;
+use ndarray::{Array2, ArrayView2, ShapeBuilder};
 use num_integer::Integer;
-use regex::Regex;
 use std::io::{self, Read};
 use std::iter::Iterator;
-use std::str::FromStr;
 
+mod answer;
+mod array;
 pub mod coord2;
+pub mod coord3;
+pub mod coordn;
+mod dir;
+mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod input;
+mod ints;
+pub mod mmap;
+pub mod ocr;
+pub mod parse;
+pub mod pattern;
+pub mod re;
+mod section;
+pub mod solution;
+pub mod transform;
+
+pub use answer::Answer;
+pub use array::{
+    array_from_coords, array_from_coords_in, array_from_rows, array_from_vecs, Bounds,
+};
+use coord2::Coord2;
+pub use dir::{Dir4, Dir8, Direction};
+use error::found;
+pub use error::{InputError, InputErrorKind};
+pub use input::{DayInputs, Input};
+pub use ints::{ints, iter_ints, Ints, ScanInt};
+pub use section::{read_string_sections, split_sections, Section};
+
+/// The input file given on the command line, or `None` for stdin. See
+/// [`Input::from_env`].
+pub fn get_input_filename() -> io::Result<Option<String>> {
+    let path = Input::from_env()?.path(None)?;
+    Ok(path.map(|p| p.to_string_lossy().into_owned()))
+}
+
+/// Reads the input file given on the command line, or stdin. `\r\n` line
+/// endings are converted to `\n`.
+pub fn read_input_string() -> io::Result<String> {
+    Input::from_env()?.read(None)
+}
 
-pub fn get_input_filename() -> Option<String> {
-    let args: Vec<_> = std::env::args().collect();
-    match args.len() {
-        2 => args.into_iter().nth(1),
-        1 => None,
-        _ => panic!("Invalid number of arguments ({})", args.len() - 1),
-    }
+/// Reads a file, converting `\r\n` line endings to `\n`.
+pub fn read_file_string(filename: impl AsRef<std::path::Path>) -> io::Result<String> {
+    std::fs::read_to_string(filename).map(normalize_newlines)
 }
 
-pub fn read_input_string() -> io::Result<String> {
-    match get_input_filename() {
-        Some(path) => std::fs::read_to_string(path),
-        None => read_stdin_to_string(),
+/// Converts `\r\n` line endings to `\n`.
+pub fn normalize_newlines(s: String) -> String {
+    if s.contains('\r') {
+        s.replace("\r\n", "\n")
+    } else {
+        s
     }
 }
 
 pub fn read_stdin_to_string() -> io::Result<String> {
     let mut buffer = String::new();
     io::stdin().read_to_string(&mut buffer)?;
-    Ok(buffer.to_owned())
+    Ok(buffer)
+}
+
+/// Reads the input like [`read_input_string`] and leaks it, so that parsed
+/// input can borrow from it with a `'static` lifetime. The input is kept
+/// until the program exits anyway.
+pub fn read_input_static() -> io::Result<&'static str> {
+    Ok(read_input_string()?.leak())
+}
+
+/// The input's lines borrowed from the leaked input, see
+/// [`read_input_static`].
+pub fn read_input_str_lines() -> io::Result<Vec<&'static str>> {
+    Ok(str_lines(read_input_static()?))
 }
 
 pub fn read_input_lines() -> io::Result<Vec<String>> {
-    match get_input_filename() {
+    match get_input_filename()? {
         Some(path) => read_file_lines(&path),
         None => read_stdin_lines(),
     }
 }
 
 pub fn read_file_lines(filename: &str) -> io::Result<Vec<String>> {
-    let input = std::fs::read_to_string(filename)?;
-    Ok(input.lines().map(|l| l.to_owned()).collect())
+    let input = read_file_string(filename)?;
+    Ok(read_string_lines(&input))
 }
 
 pub fn read_stdin_lines() -> io::Result<Vec<String>> {
     let input = read_stdin_to_string()?;
-    Ok(input.lines().map(|l| l.to_owned()).collect())
+    Ok(read_string_lines(&input))
+}
+
+/// Copies each line into its own `String`. Prefer [`str_lines`] where the
+/// lines can borrow from the input.
+pub fn read_string_lines(s: &str) -> Vec<String> {
+    s.lines().map(|l| l.to_owned()).collect()
+}
+
+/// The lines of `s`, borrowed from it.
+pub fn str_lines(s: &str) -> Vec<&str> {
+    s.lines().collect()
+}
+
+/// The lines of `s` that are not empty. The readers of the older years
+/// skipped blank lines, so stray ones in their inputs are not an error.
+pub fn nonempty_lines(s: &str) -> impl Iterator<Item = &str> {
+    s.lines().filter(|l| !l.is_empty())
+}
+
+/// Copies each non-empty line into its own `String`, see
+/// [`nonempty_lines`].
+pub fn read_nonempty_lines(s: &str) -> Vec<String> {
+    nonempty_lines(s).map(|l| l.to_owned()).collect()
 }
 
-pub fn read_input_ints<T: Integer + FromStr>(signed: bool) -> io::Result<Vec<T>> {
-    match get_input_filename() {
+/// Reads the input and splits it into [`Section`]s, borrowed from the leaked
+/// input (see [`read_input_static`]).
+pub fn read_input_sections() -> io::Result<Vec<Section<'static>>> {
+    Ok(read_string_sections(read_input_static()?))
+}
+
+pub fn read_input_ints<T: ScanInt>(signed: bool) -> io::Result<Vec<T>> {
+    match get_input_filename()? {
         Some(path) => read_ints_from_file(&path, signed),
         None => read_ints_from_stdin(signed),
     }
 }
 
-pub fn read_ints_from_stdin<T: Integer + FromStr>(signed: bool) -> io::Result<Vec<T>> {
+pub fn read_ints_from_stdin<T: ScanInt>(signed: bool) -> io::Result<Vec<T>> {
     let s = read_stdin_to_string()?;
-    Ok(read_ints_from_string(&s, signed))
+    Ok(parse_ints(&s, signed)?)
 }
 
-pub fn read_ints_from_file<T: Integer + FromStr>(
-    filename: &str,
-    signed: bool,
-) -> io::Result<Vec<T>> {
-    let s = std::fs::read_to_string(filename)?;
-    Ok(read_ints_from_string(&s, signed))
+pub fn read_ints_from_file<T: ScanInt>(filename: &str, signed: bool) -> io::Result<Vec<T>> {
+    let s = read_file_string(filename)?;
+    Ok(parse_ints(&s, signed)?)
 }
 
-pub fn read_ints_from_string<T: Integer + FromStr>(s: &str, signed: bool) -> Vec<T> {
-    let re = Regex::new(if signed { r"-?\d+" } else { r"\d+" }).unwrap();
-    re.find_iter(s)
-        .map(|m| m.as_str())
-        .filter_map(|m| m.parse::<T>().ok())
-        .collect::<Vec<T>>()
+/// Reads all integers in the string. Numbers that do not fit in `T` are
+/// skipped, see [`parse_ints`] for reporting them instead.
+#[deprecated(note = "skips numbers that do not fit, use `parse_ints` instead")]
+pub fn read_ints_from_string<T: ScanInt>(s: &str, signed: bool) -> Vec<T> {
+    Ints::new(s, signed).filter_map(Result::ok).collect()
+}
+
+/// Reads all integers in the string, failing on numbers that do not fit in
+/// `T`.
+pub fn parse_ints<T: ScanInt>(s: &str, signed: bool) -> Result<Vec<T>, InputError> {
+    Ints::new(s, signed).collect()
+}
+
+/// Reads exactly `N` integers from the string, failing if there are more or
+/// fewer, or one does not fit in `T`.
+pub fn parse_int_array<const N: usize, T: ScanInt>(
+    s: &str,
+    signed: bool,
+) -> Result<[T; N], InputError> {
+    let ints = parse_ints(s, signed)?;
+    let len = ints.len();
+    ints.try_into().map_err(|_| {
+        let expected = match N {
+            1 => "an integer".to_owned(),
+            _ => format!("{} integers", N),
+        };
+        InputError::unexpected(expected, format!("{} in {}", len, found(s)))
+    })
 }
 
 pub fn read_input_regex_matches(regex_pattern: &str) -> io::Result<Vec<String>> {
-    match get_input_filename() {
+    match get_input_filename()? {
         Some(path) => read_regex_matches_from_file(&path, regex_pattern),
         None => read_regex_matches_from_stdin(regex_pattern),
     }
@@ -95,7 +195,7 @@ pub fn read_regex_matches_from_file(
     filename: &str,
     regex_pattern: &str,
 ) -> io::Result<Vec<String>> {
-    let s = std::fs::read_to_string(filename)?;
+    let s = read_file_string(filename)?;
     let matches = read_regex_matches_from_string(&s, regex_pattern);
 
     let res = matches.into_iter().map(|sm| sm.to_owned()).collect();
@@ -103,93 +203,216 @@ pub fn read_regex_matches_from_file(
     Ok(res)
 }
 
+/// The matches of the pattern in `s`. The compiled pattern is cached, see
+/// [`re::cached`].
 pub fn read_regex_matches_from_string<'a>(s: &'a str, regex_pattern: &str) -> Vec<&'a str> {
-    let re = Regex::new(regex_pattern).unwrap();
-    re.find_iter(s).map(|m| m.as_str()).collect()
+    re::cached(regex_pattern)
+        .find_iter(s)
+        .map(|m| m.as_str())
+        .collect()
+}
+
+/// Reads a grid with a row on each line, converting the characters with
+/// `cell`, which also gets their position. Trailing empty lines are ignored.
+/// If `pad` is given, short rows are padded with its values to the length of
+/// the longest row, otherwise the rows must all have the same length.
+fn parse_grid_with<T>(
+    s: &str,
+    pad: Option<fn() -> T>,
+    mut cell: impl FnMut(Coord2, char) -> Result<T, InputError>,
+) -> Result<Array2<T>, InputError> {
+    let lines: Vec<_> = s.trim_end_matches(['\n', '\r']).lines().collect();
+    let w = match pad {
+        Some(_) => lines.iter().map(|l| l.chars().count()).max().unwrap_or(0),
+        None => lines.first().map_or(0, |l| l.chars().count()),
+    };
+    if w == 0 {
+        return Err(InputError::new(InputErrorKind::Empty));
+    }
+
+    let mut cells = Vec::with_capacity(lines.len() * w);
+    for (y, line) in lines.iter().enumerate() {
+        let len = line.chars().count();
+        if pad.is_none() && len != w {
+            return Err(
+                InputError::unexpected(format!("{} characters", w), len.to_string()).at_line(y + 1),
+            );
+        }
+        for (x, c) in line.chars().enumerate() {
+            cells.push(cell(Coord2 { y, x }, c).map_err(|e| e.at_line(y + 1).at_column(x + 1))?);
+        }
+        if let Some(pad) = pad {
+            cells.extend(std::iter::repeat_with(pad).take(w - len));
+        }
+    }
+
+    Ok(Array2::from_shape_vec((lines.len(), w), cells).unwrap())
+}
+
+fn cell_from_char<T: TryFrom<char>>(c: char) -> Result<T, InputError> {
+    T::try_from(c).map_err(|_| {
+        let ty = std::any::type_name::<T>();
+        let ty = ty.rsplit("::").next().unwrap_or(ty);
+        InputError::unexpected(format!("a `{}`", ty), format!("{:?}", c))
+    })
+}
+
+/// Reads a rectangular grid, converting each character into a `T`, like an
+/// enum of the cells of a map.
+pub fn parse_grid<T: TryFrom<char>>(s: &str) -> Result<Array2<T>, InputError> {
+    parse_grid_with(s, None, |_, c| cell_from_char(c))
+}
+
+/// Reads a grid like [`parse_grid`], but with rows that may be of different
+/// lengths. Short rows are padded with `T::default()` at the end.
+pub fn parse_ragged_grid<T: TryFrom<char> + Default>(s: &str) -> Result<Array2<T>, InputError> {
+    parse_grid_with(s, Some(T::default), |_, c| cell_from_char(c))
+}
+
+/// Reads a rectangular grid like [`parse_grid`], also returning the positions
+/// of markers such as the `S` and `E` of a maze. Markers are given as pairs
+/// of the marker and the character that is converted into the cell in its
+/// place. Each marker must appear exactly once.
+pub fn parse_grid_markers<T: TryFrom<char>, const N: usize>(
+    s: &str,
+    markers: [(char, char); N],
+) -> Result<(Array2<T>, [Coord2; N]), InputError> {
+    let mut positions = [None; N];
+    let grid = parse_grid_with(s, None, |pos, c| {
+        match markers.iter().position(|&(m, _)| m == c) {
+            Some(i) if positions[i].is_some() => Err(InputError::unexpected(
+                format!("a single {:?}", c),
+                "another",
+            )),
+            Some(i) => {
+                positions[i] = Some(pos);
+                cell_from_char(markers[i].1)
+            }
+            None => cell_from_char(c),
+        }
+    })?;
+
+    let mut res = [Coord2 { y: 0, x: 0 }; N];
+    for (i, pos) in positions.into_iter().enumerate() {
+        res[i] = pos.ok_or_else(|| {
+            InputError::unexpected(format!("a {:?} in the grid", markers[i].0), "none")
+        })?;
+    }
+    Ok((grid, res))
 }
 
-pub fn read_input_char_matrix() -> io::Result<Array2<char>> {
-    let lines = read_input_lines()?;
-    let h = lines.len();
-    let w = lines[0].len();
+/// Reads the input as a rectangular grid of `T`s, see [`parse_grid`].
+pub fn read_grid<T: TryFrom<char>>() -> io::Result<Array2<T>> {
+    Ok(parse_grid(&read_input_string()?)?)
+}
 
-    Array2::from_shape_vec((h, w), lines.iter().flat_map(|l| l.chars()).collect())
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+/// Reads a rectangular grid of `T`s from a string, like [`parse_grid`] but
+/// with the error converted for use in [`Solution::parse`].
+///
+/// [`Solution::parse`]: solution::Solution::parse
+pub fn read_string_grid<T: TryFrom<char>>(s: &str) -> io::Result<Array2<T>> {
+    Ok(parse_grid(s)?)
 }
 
-pub fn read_file_char_matrix(filename: &str) -> io::Result<Array2<char>> {
-    let lines = read_file_lines(filename)?;
-    let h = lines.len();
-    let w = lines[0].len();
+/// Reads a grid of characters, which must be rectangular.
+pub fn parse_char_grid(s: &str) -> Result<Array2<char>, InputError> {
+    parse_grid(s)
+}
+
+/// Reads a rectangular grid of single digits.
+pub fn parse_digit_grid<T: From<u8>>(s: &str) -> Result<Array2<T>, InputError> {
+    parse_grid_with(s, None, |_, c| {
+        c.to_digit(10)
+            .map(|d| T::from(d as u8))
+            .ok_or_else(|| InputError::unexpected("a digit", format!("{:?}", c)))
+    })
+}
 
-    Array2::from_shape_vec((h, w), lines.iter().flat_map(|l| l.chars()).collect())
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+fn parse_byte_grid(s: &str) -> Result<Array2<u8>, InputError> {
+    parse_grid_with(s, None, |_, c| {
+        u8::try_from(c)
+            .ok()
+            .filter(u8::is_ascii)
+            .ok_or_else(|| InputError::unexpected("an ASCII character", format!("{:?}", c)))
+    })
 }
 
+/// A rectangular grid of ASCII characters as a view of the bytes of `s`,
+/// without copying. Rows end in `\n` or `\r\n`, the same in every row, and
+/// trailing line breaks are ignored.
+pub fn parse_byte_grid_view(s: &str) -> Result<ArrayView2<'_, u8>, InputError> {
+    let s = s.trim_end_matches(['\n', '\r']);
+    let first = s.split('\n').next().unwrap_or_default();
+    let w = first.strip_suffix('\r').unwrap_or(first).len();
+    if w == 0 {
+        return Err(InputError::new(InputErrorKind::Empty));
+    }
+    let stride = first.len() + 1;
+
+    let mut h: usize = 0;
+    for (y, line) in s.split('\n').enumerate() {
+        if let Some(x) = line.find(|c: char| !c.is_ascii()) {
+            let c = line[x..].chars().next().unwrap();
+            return Err(
+                InputError::unexpected("an ASCII character", format!("{:?}", c))
+                    .at_line(y + 1)
+                    .at_column(line[..x].chars().count() + 1),
+            );
+        }
+        let row = line.strip_suffix('\r').unwrap_or(line);
+        if row.len() != w {
+            return Err(
+                InputError::unexpected(format!("{} characters", w), row.len().to_string())
+                    .at_line(y + 1),
+            );
+        }
+        if line.len() + 1 != stride && y * stride + line.len() != s.len() {
+            return Err(
+                InputError::unexpected("the same line ending on every line", found(line))
+                    .at_line(y + 1),
+            );
+        }
+        h += 1;
+    }
+
+    Ok(ArrayView2::from_shape((h, w).strides((stride, 1)), s.as_bytes()).unwrap())
+}
+
+pub fn read_input_char_matrix() -> io::Result<Array2<char>> {
+    Ok(parse_char_grid(&read_input_string()?)?)
+}
+
+pub fn read_file_char_matrix(filename: &str) -> io::Result<Array2<char>> {
+    Ok(parse_char_grid(&read_file_string(filename)?)?)
+}
+
+/// Reads a grid of characters, padding short rows with `'\0'`.
 pub fn read_string_char_matrix(str: &str) -> io::Result<Array2<char>> {
-    let lines: Vec<_> = str.lines().collect();
-    let h = lines.len();
-    let w = lines.iter().map(|l| l.len()).max().unwrap();
-
-    Array2::from_shape_vec(
-        (h, w),
-        lines
-            .iter()
-            .flat_map(|l| {
-                if l.len() < w {
-                    Either::Left(
-                        l.chars()
-                            .chain(std::iter::repeat_n(Default::default(), w - l.len())),
-                    )
-                } else {
-                    Either::Right(l.chars())
-                }
-            })
-            .collect(),
-    )
-    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    Ok(parse_ragged_grid(str)?)
 }
 
 pub fn read_input_byte_matrix() -> io::Result<Array2<u8>> {
-    let lines = read_input_lines()?;
-    let h = lines.len();
-    let w = lines[0].len();
-
-    Array2::from_shape_vec((h, w), lines.iter().flat_map(|l| l.bytes()).collect())
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    Ok(parse_byte_grid(&read_input_string()?)?)
 }
 
 pub fn read_file_byte_matrix(filename: &str) -> io::Result<Array2<u8>> {
-    let lines = read_file_lines(filename)?;
-    let h = lines.len();
-    let w = lines[0].len();
+    Ok(parse_byte_grid(&read_file_string(filename)?)?)
+}
 
-    Array2::from_shape_vec((h, w), lines.iter().flat_map(|l| l.bytes()).collect())
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+pub fn read_string_byte_matrix(str: &str) -> io::Result<Array2<u8>> {
+    Ok(parse_byte_grid(str)?)
 }
 
 pub fn read_input_int_matrix<T: Integer + From<u8>>() -> io::Result<Array2<T>> {
-    let cm = read_input_char_matrix()?;
-    if !cm.iter().all(|&c| c.is_ascii_digit()) {
-        Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Matrix char not a digit",
-        ))
-    } else {
-        Ok(cm.map(|&c| (c.to_digit(10).unwrap() as u8).into()))
-    }
+    Ok(parse_digit_grid(&read_input_string()?)?)
 }
 
 pub fn read_file_int_matrix<T: Integer + From<u8>>(filename: &str) -> io::Result<Array2<T>> {
-    let cm = read_file_char_matrix(filename)?;
-    if !cm.iter().all(|&c| c.is_ascii_digit()) {
-        Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Matrix char not a digit",
-        ))
-    } else {
-        Ok(cm.map(|&c| (c.to_digit(10).unwrap() as u8).into()))
-    }
+    Ok(parse_digit_grid(&read_file_string(filename)?)?)
+}
+
+pub fn read_string_int_matrix<T: Integer + From<u8>>(str: &str) -> io::Result<Array2<T>> {
+    Ok(parse_digit_grid(str)?)
 }
 
 pub fn split_to_tuple2<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str)> {
@@ -210,6 +433,15 @@ pub fn split_to_tuple4<'a>(
 }
 
 #[test]
+fn test_nonempty_lines() {
+    assert_eq!(
+        vec!["a".to_owned(), "b".to_owned()],
+        read_nonempty_lines("a\n\nb\n\n")
+    );
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_read_ints_from_string() {
     let s = "a123b22 123x02\n123-22";
     let res: Vec<i32> = read_ints_from_string(s, true);
@@ -217,6 +449,154 @@ fn test_read_ints_from_string() {
     assert_eq!(vec![123i32, 22, 123, 2, 123, -22], res);
 }
 
+#[test]
+fn test_parse_ints() {
+    assert_eq!(Ok(vec![1u8, 200]), parse_ints("x1 200", false));
+    assert_eq!(
+        "line 2, column 4: 300 does not fit in u8",
+        parse_ints::<u8>("1\n2, 300", false)
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 1, column 3: -2 does not fit in u32",
+        parse_ints::<u32>("1 -2", true).unwrap_err().to_string()
+    );
+
+    assert_eq!(Ok([3i32, -5]), parse_int_array("3,-5", true));
+    assert_eq!(Ok([3u32, 5]), parse_int_array("3-5", false));
+    assert_eq!(
+        "expected 3 integers, found 2 in \"1,2\"",
+        parse_int_array::<3, u8>("1,2", false)
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "expected an integer, found 0 in end of line",
+        parse_int_array::<1, u8>("", false).unwrap_err().to_string()
+    );
+    assert!(parse_int_array::<1, u8>("1 2", false).is_err());
+    assert!(parse_int_array::<1, u8>("256", false).is_err());
+}
+
+#[test]
+fn test_parse_grids() {
+    assert_eq!(
+        Ok(ndarray::array![[1u32, 2], [3, 4]]),
+        parse_digit_grid("12\r\n34\r\n\n")
+    );
+    assert_eq!(
+        "line 2, column 2: expected a digit, found 'x'",
+        parse_digit_grid::<u8>("12\n3x").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 3: expected 2 characters, found 1",
+        parse_char_grid("ab\ncd\ne").unwrap_err().to_string()
+    );
+    assert_eq!(
+        InputErrorKind::Empty,
+        parse_char_grid("\n").unwrap_err().kind
+    );
+    assert!(read_string_char_matrix("").is_err());
+}
+
+#[test]
+fn test_parse_typed_grids() {
+    #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+    enum Cell {
+        #[default]
+        Wall,
+        Open,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, char> {
+            match c {
+                '#' => Ok(Cell::Wall),
+                '.' => Ok(Cell::Open),
+                _ => Err(c),
+            }
+        }
+    }
+
+    use Cell::*;
+    assert_eq!(
+        Ok(ndarray::array![[Wall, Open], [Open, Wall]]),
+        parse_grid("#.\n.#\n")
+    );
+    assert_eq!(
+        "line 2, column 1: expected a `Cell`, found 'x'",
+        parse_grid::<Cell>("#.\nx#").unwrap_err().to_string()
+    );
+    assert_eq!(
+        Ok(ndarray::array![[Open, Wall, Open], [Open, Wall, Wall]]),
+        parse_ragged_grid(".#.\n.")
+    );
+
+    let (grid, [start, end]) =
+        parse_grid_markers::<Cell, 2>("S.\n#E", [('S', '.'), ('E', '.')]).unwrap();
+    assert_eq!(ndarray::array![[Open, Open], [Wall, Open]], grid);
+    assert_eq!((Coord2 { y: 0, x: 0 }, Coord2 { y: 1, x: 1 }), (start, end));
+    assert_eq!(
+        "expected a 'E' in the grid, found none",
+        parse_grid_markers::<Cell, 2>("S.\n##", [('S', '.'), ('E', '.')])
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 2, column 2: expected a single 'S', found another",
+        parse_grid_markers::<Cell, 1>("S.\n#S", [('S', '.')])
+            .unwrap_err()
+            .to_string()
+    );
+
+    assert_eq!(
+        ndarray::array![['a', 'b'], ['c', '\0']],
+        read_string_char_matrix("ab\nc\n").unwrap()
+    );
+}
+
+#[test]
+fn test_parse_byte_grid_view() {
+    let input = "ab\ncd\nef\n";
+    let view = parse_byte_grid_view(input).unwrap();
+    assert_eq!(
+        ndarray::array![[b'a', b'b'], [b'c', b'd'], [b'e', b'f']],
+        view
+    );
+    assert_eq!(input.as_ptr(), view.as_ptr());
+
+    assert_eq!(
+        ndarray::array![[b'a', b'b'], [b'c', b'd']],
+        parse_byte_grid_view("ab\r\ncd\r\n").unwrap()
+    );
+    assert_eq!(
+        "line 2: expected 2 characters, found 3",
+        parse_byte_grid_view("ab\ncde").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "line 2: expected the same line ending on every line, found \"cd\"",
+        parse_byte_grid_view("ab\r\ncd\nef")
+            .unwrap_err()
+            .to_string()
+    );
+    assert_eq!(
+        "line 2, column 2: expected an ASCII character, found 'ä'",
+        parse_byte_grid_view("ab\ncä").unwrap_err().to_string()
+    );
+    assert_eq!(
+        InputErrorKind::Empty,
+        parse_byte_grid_view("\r\n").unwrap_err().kind
+    );
+}
+
+#[test]
+fn test_str_lines() {
+    assert_eq!(vec!["a", "", "b"], str_lines("a\r\n\nb\n"));
+}
+
 #[test]
 fn test_read_regex_matches_from_string() {
     let s = "0.12,1.23,4.2\n111.1,.,111.,.23";
@@ -239,23 +619,26 @@ impl<T, I: Iterator<Item = Option<T>>> UnwrapOptionIterator<T> for I {
     }
 }
 
+/// Builds an array of shape `(rows, cols)` from nested vectors, or returns
+/// `None` if they are empty or ragged. It used to build the shape with the
+/// dimensions swapped; use [`array_from_vecs`] to get the error instead.
 pub fn make_2d_array<T>(v: Vec<Vec<T>>) -> Option<Array2<T>> {
-    let ncols = v[0].len();
-    let nrows = v.len();
+    array_from_vecs(v).ok()
+}
 
-    Array2::from_shape_vec((ncols, nrows), v.into_iter().flatten().collect_vec()).ok()
+pub fn print_bool_matrix<T: Default + PartialEq>(mtx: &[Vec<T>]) {
+    println!("{}", format_bool_matrix(mtx));
 }
 
-pub fn print_bool_matrix<T: Default + PartialEq>(mtx: &Vec<Vec<T>>) {
+pub fn format_bool_matrix<T: Default + PartialEq>(mtx: &[Vec<T>]) -> String {
     let def = T::default();
-    for r in mtx {
-        println!(
-            "{}",
+    mtx.iter()
+        .map(|r| {
             r.iter()
                 .map(|c| if &def != c { '█' } else { '.' })
                 .collect::<String>()
-        );
-    }
+        })
+        .join("\n")
 }
 
 pub fn print_bool_ndarray<T: Default + PartialEq>(mtx: ArrayView2<T>) {
@@ -289,6 +672,20 @@ macro_rules! get_test_input_file {
     };
 }
 
+/// Path of the `n`th example input of a day, `inputs/DD.exampleN.txt`.
+#[macro_export]
+macro_rules! get_example_input_file {
+    ($day:expr, $n:expr) => {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join("bin")
+            .join("inputs")
+            .join(format!("{:02}.example{}.txt", $day, $n))
+            .to_str()
+            .unwrap()
+    };
+}
+
 pub mod iter {
     pub struct TakeUntilInclusive<I, P> {
         inner: I,
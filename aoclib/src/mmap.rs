@@ -0,0 +1,82 @@
+//! Reading input files by mapping them into memory instead of copying them.
+
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
+
+/// A memory-mapped text file. Dereferences to the file's contents as `str`,
+/// so parsed input can borrow from it like from a `String`.
+///
+/// Unlike the other readers, line endings are left as they are in the file.
+pub struct MappedFile {
+    // Files of zero length cannot be mapped
+    map: Option<memmap2::Mmap>,
+}
+
+impl MappedFile {
+    /// Maps the file and checks that it is valid UTF-8.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<MappedFile> {
+        let file = File::open(path)?;
+        let map = if file.metadata()?.len() == 0 {
+            None
+        } else {
+            // SAFETY: The file must not be modified while it is mapped.
+            // Puzzle inputs are only ever read.
+            Some(unsafe { memmap2::Mmap::map(&file)? })
+        };
+        let mapped = MappedFile { map };
+        std::str::from_utf8(mapped.as_bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(mapped)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.map.as_deref().unwrap_or_default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Checked to be UTF-8 in `open`, and the mapping is read-only
+        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+impl Deref for MappedFile {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mapped_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-mmap-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Each case gets its own file, as a mapped file must not be modified
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "12\n34\n").unwrap();
+        let file = MappedFile::open(&path).unwrap();
+        assert_eq!(vec!["12", "34"], crate::str_lines(&file));
+
+        let path = dir.join("empty.txt");
+        std::fs::write(&path, "").unwrap();
+        assert_eq!("", &*MappedFile::open(&path).unwrap());
+
+        let path = dir.join("invalid.txt");
+        std::fs::write(&path, [0xff, 0xfe]).unwrap();
+        assert_eq!(
+            io::ErrorKind::InvalidData,
+            MappedFile::open(&path).err().unwrap().kind()
+        );
+
+        drop(file);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
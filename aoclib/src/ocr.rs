@@ -0,0 +1,242 @@
+//! Reading the letters some puzzles draw instead of giving an answer.
+//!
+//! Two fonts are used by the puzzles: letters 4 pixels wide and 6 high with
+//! one column between them, and letters 6 pixels wide and 10 high with two
+//! columns between them.
+
+use ndarray::{s, Array2, ArrayView2};
+
+struct Font {
+    width: usize,
+    height: usize,
+    spacing: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 1,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Y', "#...#....#.#..#...#...#."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#.....#.....#.....######",
+        ),
+    ],
+};
+
+impl Font {
+    fn glyph(&self, pixels: ArrayView2<bool>) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| {
+                glyph
+                    .bytes()
+                    .zip(pixels.iter())
+                    .all(|(g, &p)| (g == b'#') == p)
+            })
+            .map(|(c, _)| *c)
+    }
+}
+
+/// Reads the letters drawn in a bitmap. Empty rows and columns around the
+/// letters are ignored, and the font is chosen by the height of the letters.
+/// Returns `None` if the height does not match either font or some letter is
+/// not recognised.
+pub fn recognize(bitmap: ArrayView2<bool>) -> Option<String> {
+    let lit_rows = bitmap
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, r)| r.iter().any(|&p| p))
+        .map(|(y, _)| y);
+    let lit_cols = bitmap
+        .columns()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, c)| c.iter().any(|&p| p))
+        .map(|(x, _)| x);
+    let (top, bottom) = (lit_rows.clone().min()?, lit_rows.max()?);
+    let (left, right) = (lit_cols.clone().min()?, lit_cols.max()?);
+
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|f| f.height == bottom - top + 1)?;
+
+    // Pad with an empty column on the left, as the first letter may start
+    // with one (like I), and with a letter's width on the right, as the last
+    // letter may end with empty columns
+    let width = right - left + 1;
+    let mut letters = Array2::from_elem((font.height, 1 + width + font.width), false);
+    letters
+        .slice_mut(s![.., 1..=width])
+        .assign(&bitmap.slice(s![top..=bottom, left..=right]));
+
+    [1, 0].into_iter().find_map(|start| {
+        (start..=width)
+            .step_by(font.width + font.spacing)
+            .map(|x| font.glyph(letters.slice(s![.., x..x + font.width])))
+            .collect()
+    })
+}
+
+/// [`recognize`] for a bitmap given as rows of pixels.
+pub fn recognize_rows(rows: &[Vec<bool>]) -> Option<String> {
+    let width = rows.iter().map(|r| r.len()).max()?;
+    let bitmap = Array2::from_shape_fn((rows.len(), width), |(y, x)| {
+        rows[y].get(x).copied().unwrap_or(false)
+    });
+    recognize(bitmap.view())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Answer;
+
+    fn render(font: &Font, text: &str) -> String {
+        (0..font.height)
+            .map(|y| {
+                text.chars()
+                    .map(|c| {
+                        let glyph = font.glyphs.iter().find(|(g, _)| *g == c).unwrap().1;
+                        glyph[y * font.width..(y + 1) * font.width].to_owned()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(font.spacing))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_small_font() {
+        let bitmap = Answer::parse_bitmap(
+            "\
+.##..###..####.#....###..
+#..#.#..#....#.#....#..#.
+#..#.#..#...#..#....#..#.
+####.###...#...#....###..
+#..#.#....#....#....#....
+#..#.#....####.####.#....",
+        );
+        assert_eq!(Some("APZLP".to_owned()), recognize(bitmap.view()));
+
+        let all: String = SMALL.glyphs.iter().map(|(c, _)| c).collect();
+        let bitmap = Answer::parse_bitmap(&render(&SMALL, &all));
+        assert_eq!(Some(all), recognize(bitmap.view()));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let all: String = LARGE.glyphs.iter().map(|(c, _)| c).collect();
+        let bitmap = Answer::parse_bitmap(&format!("\n{}", render(&LARGE, &all)));
+        assert_eq!(Some(all), recognize(bitmap.view()));
+    }
+
+    #[test]
+    fn test_unrecognized() {
+        assert_eq!(None, recognize(Answer::parse_bitmap("").view()));
+        assert_eq!(None, recognize(Answer::parse_bitmap("##\n##").view()));
+        let mut bitmap = Answer::parse_bitmap(&render(&SMALL, "AB"));
+        bitmap[(0, 0)] = true;
+        assert_eq!(None, recognize(bitmap.view()));
+    }
+
+    #[test]
+    fn test_recognize_rows() {
+        let rows: Vec<Vec<bool>> = render(&SMALL, "HI")
+            .lines()
+            .map(|l| l.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(Some("HI".to_owned()), recognize_rows(&rows));
+
+        let rows: Vec<Vec<bool>> = render(&SMALL, "IL")
+            .lines()
+            .map(|l| l.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(Some("IL".to_owned()), recognize_rows(&rows));
+    }
+}
@@ -0,0 +1,241 @@
+//! [`nom`] parsers for the formats that keep coming back in the puzzles, so
+//! that recursive inputs can be declared from these instead of parsed by
+//! hand:
+//!
+//! ```
+//! use aoclib::parse::{comma_list, int, nested, parse_all, Nested};
+//!
+//! let packet = parse_all(nested(int::<u32>), "[1,[2,[]],3]").unwrap();
+//! assert_eq!(
+//!     Nested::List(vec![
+//!         Nested::Item(1),
+//!         Nested::List(vec![Nested::Item(2), Nested::List(vec![])]),
+//!         Nested::Item(3),
+//!     ]),
+//!     packet
+//! );
+//! assert_eq!(vec![-3, 4], parse_all(comma_list(int::<i8>), "-3, 4").unwrap());
+//! ```
+//!
+//! [`parse_all`] runs a parser on a whole input, turning nom's errors into
+//! [`InputError`]s with the position where parsing stopped.
+
+use crate::error::found;
+use crate::{InputError, ScanInt};
+use ndarray::Array2;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, space0, space1};
+use nom::combinator::{opt, recognize};
+use nom::error::{Error, ErrorKind};
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::{delimited, pair, separated_pair, tuple};
+
+pub use nom;
+pub use nom::IResult;
+
+/// A value that is either a single item or a list of nested values, like
+/// `[1,[2,[3]]]`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Nested<T> {
+    Item(T),
+    List(Vec<Nested<T>>),
+}
+
+/// An integer, with a leading `-` if `T` is signed.
+pub fn int<T: ScanInt>(input: &str) -> IResult<&str, T> {
+    let (rest, text) = if T::SIGNED {
+        recognize(pair(opt(char('-')), digit1))(input)?
+    } else {
+        digit1(input)?
+    };
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    match T::from_digits(negative, digits.as_bytes()) {
+        Some(n) => Ok((rest, n)),
+        None => Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge))),
+    }
+}
+
+/// Items separated by commas, optionally followed by spaces. At least one
+/// item is required.
+pub fn comma_list<'a, T>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// Items separated by one or more spaces. At least one item is required.
+pub fn space_list<'a, T>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(space1, item)
+}
+
+/// A possibly empty list of comma separated items between `open` and
+/// `close`, like `[1, 2, 3]` or `(a,b)`.
+pub fn bracketed<'a, T>(
+    open: char,
+    close: char,
+    item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    delimited(
+        char(open),
+        separated_list0(pair(char(','), space0), item),
+        char(close),
+    )
+}
+
+/// An item or a bracketed list of nested values, with `item` parsing the
+/// values that are not lists.
+pub fn nested<'a, T>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Nested<T>> {
+    move |input| nested_value(input, &mut item)
+}
+
+fn nested_value<'a, T>(
+    input: &'a str,
+    item: &mut impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> IResult<&'a str, Nested<T>> {
+    if input.starts_with('[') {
+        let (rest, list) = bracketed('[', ']', |i| nested_value(i, item))(input)?;
+        Ok((rest, Nested::List(list)))
+    } else {
+        let (rest, value) = item(input)?;
+        Ok((rest, Nested::Item(value)))
+    }
+}
+
+/// A key and a value separated by `sep`, which may have spaces around it,
+/// like `x=12` or `Time: 7`.
+pub fn key_value<'a, K, V>(
+    key: impl FnMut(&'a str) -> IResult<&'a str, K>,
+    sep: &'static str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, V>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, tuple((space0, tag(sep), space0)), value)
+}
+
+/// A block of grid lines up to the next blank line or the end of the input,
+/// read with [`crate::parse_char_grid`]. A trailing line break is consumed.
+pub fn grid_block(input: &str) -> IResult<&str, Array2<char>> {
+    let mut end = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            break;
+        }
+        end += line.len();
+    }
+    match crate::parse_char_grid(&input[..end]) {
+        Ok(grid) => Ok((&input[end..], grid)),
+        Err(_) => Err(nom::Err::Error(Error::new(input, ErrorKind::Verify))),
+    }
+}
+
+/// Runs `parser` on the whole input, which may end with whitespace. Errors
+/// are reported at the position where the parser failed.
+pub fn parse_all<'a, T>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    input: &'a str,
+) -> Result<T, InputError> {
+    let error = |rest: &str, expected: String| {
+        InputError::unexpected(expected, found(rest)).at_offset(input, input.len() - rest.len())
+    };
+
+    match parser(input) {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(error(rest, "end of input".to_owned())),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let expected = match e.code {
+                ErrorKind::TooLarge => "a smaller number".to_owned(),
+                ErrorKind::Char => "a different character".to_owned(),
+                code => code.description().to_lowercase(),
+            };
+            Err(error(e.input, expected))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(error("", "more input".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete::alpha1;
+
+    #[test]
+    fn test_int() {
+        assert_eq!(Ok((",2", -12)), int::<i32>("-12,2"));
+        assert_eq!(Ok(("", u128::MAX)), int::<u128>(&u128::MAX.to_string()));
+        assert!(int::<u32>("-12").is_err());
+        assert_eq!(
+            Err(nom::Err::Failure(Error::new("300", ErrorKind::TooLarge))),
+            int::<u8>("300")
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            Ok(vec![1, 2, 3]),
+            parse_all(comma_list(int::<u8>), "1,2, 3\n")
+        );
+        assert_eq!(Ok(vec![7, 8]), parse_all(space_list(int::<u8>), "7   8"));
+        assert_eq!(
+            Ok(vec!["a", "b"]),
+            parse_all(bracketed('(', ')', alpha1), "(a, b)")
+        );
+        assert_eq!(
+            Ok(Vec::<u8>::new()),
+            parse_all(bracketed('[', ']', int), "[]")
+        );
+        assert_eq!(
+            Ok(("x", 12)),
+            parse_all(key_value(alpha1, "=", int::<i32>), "x = 12")
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let pair = parse_all(nested(int::<u8>), "[[1,2],3]").unwrap();
+        assert_eq!(
+            Nested::List(vec![
+                Nested::List(vec![Nested::Item(1), Nested::Item(2)]),
+                Nested::Item(3),
+            ]),
+            pair
+        );
+        assert_eq!(Ok(Nested::Item(4)), parse_all(nested(int::<u8>), "4"));
+    }
+
+    #[test]
+    fn test_grid_block() {
+        let (rest, grid) = grid_block("#.\n.#\n\nrest").unwrap();
+        assert_eq!("\nrest", rest);
+        assert_eq!(ndarray::array![['#', '.'], ['.', '#']], grid);
+        assert!(grid_block("#.\n.\n").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "line 1, column 5: expected end of input, found \", x\"",
+            parse_all(comma_list(int::<u8>), "1, 2, x")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 1, column 7: expected a different character, found end of line",
+            parse_all(nested(int::<u8>), "[1,[2]")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 1, column 2: expected a smaller number, found \"300]\"",
+            parse_all(bracketed('[', ']', int::<u8>), "[300]")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,379 @@
+//! Parsing lines by a pattern, see [`crate::line_pattern`].
+//!
+//! A pattern is literal text with fields in braces. A field takes the text up
+//! to the literal that follows it, or up to the end of the line:
+//!
+//! - `{name}` is parsed into the struct field `name`
+//! - `{name:list(", ")}` is split by the separator and parsed into a `Vec`
+//! - `{_}` matches anything and is ignored
+//!
+//! `{{` and `}}` match literal braces.
+
+use crate::error::{found, InputError};
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Piece {
+    Literal(String),
+    Field { name: String, list: Option<String> },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+}
+
+impl Pattern {
+    /// Parses a pattern for a struct with the given fields.
+    ///
+    /// Panics if the pattern is malformed, two fields are not separated by
+    /// literal text, or the fields of the pattern and the struct differ.
+    pub fn new(pattern: &str, fields: &[&str]) -> Pattern {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut rest = pattern;
+
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                literal.push(c);
+                rest = &rest[2..];
+            } else if c == '{' {
+                let end = rest
+                    .find('}')
+                    .unwrap_or_else(|| panic!("Unterminated field in pattern {:?}", pattern));
+                let (name, list) = match rest[1..end].split_once(":list(") {
+                    Some((name, sep)) => {
+                        let sep = sep
+                            .strip_suffix(')')
+                            .and_then(|s| s.strip_prefix('"'))
+                            .and_then(|s| s.strip_suffix('"'))
+                            .unwrap_or_else(|| {
+                                panic!("Invalid list separator in pattern {:?}", pattern)
+                            });
+                        (name, Some(sep.to_owned()))
+                    }
+                    None => (&rest[1..end], None),
+                };
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                if matches!(pieces.last(), Some(Piece::Field { .. })) {
+                    panic!("Fields without text between them in pattern {:?}", pattern);
+                }
+                pieces.push(Piece::Field {
+                    name: name.to_owned(),
+                    list,
+                });
+                rest = &rest[end + 1..];
+            } else if c == '}' {
+                panic!("Unmatched }} in pattern {:?}", pattern);
+            } else {
+                literal.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+
+        let mut names: Vec<&str> = pieces
+            .iter()
+            .filter_map(|p| match p {
+                Piece::Field { name, .. } if name != "_" => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        let mut expected = fields.to_vec();
+        names.sort();
+        expected.sort();
+        assert_eq!(
+            expected, names,
+            "Fields of pattern {:?} do not match the struct",
+            pattern
+        );
+
+        Pattern { pieces }
+    }
+
+    /// Matches a line against the pattern, returning the text of each field.
+    pub fn captures<'a>(&'a self, line: &'a str) -> Result<Captures<'a>, InputError> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let column = |pos: usize| line[..pos].chars().count() + 1;
+
+        let mut fields = Vec::new();
+        let mut pos = 0;
+        for (i, piece) in self.pieces.iter().enumerate() {
+            match piece {
+                Piece::Literal(lit) => {
+                    if !line[pos..].starts_with(lit.as_str()) {
+                        return Err(InputError::unexpected(
+                            format!("{:?}", lit),
+                            found(&line[pos..]),
+                        )
+                        .at_column(column(pos)));
+                    }
+                    pos += lit.len();
+                }
+                Piece::Field { name, list } => {
+                    let end = match self.pieces.get(i + 1) {
+                        Some(Piece::Literal(next)) => {
+                            pos + line[pos..].find(next.as_str()).ok_or_else(|| {
+                                InputError::unexpected(
+                                    format!("{} followed by {:?}", name, next),
+                                    found(&line[pos..]),
+                                )
+                                .at_column(column(pos))
+                            })?
+                        }
+                        _ => line.len(),
+                    };
+                    fields.push(Field {
+                        name,
+                        list: list.as_deref(),
+                        text: &line[pos..end],
+                        column: column(pos),
+                    });
+                    pos = end;
+                }
+            }
+        }
+        if pos < line.len() {
+            return Err(
+                InputError::unexpected("end of line", found(&line[pos..])).at_column(column(pos))
+            );
+        }
+
+        Ok(Captures { fields })
+    }
+}
+
+struct Field<'a> {
+    name: &'a str,
+    list: Option<&'a str>,
+    text: &'a str,
+    column: usize,
+}
+
+pub struct Captures<'a> {
+    fields: Vec<Field<'a>>,
+}
+
+impl Captures<'_> {
+    /// Parses the text of a field.
+    pub fn get<T: FieldValue>(&self, name: &str) -> Result<T, InputError> {
+        let field = self
+            .fields
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap_or_else(|| panic!("No field {} in the pattern", name));
+        T::parse_field(field.text, field.list)
+            .map_err(|e| InputError::invalid(name, field.text, e).at_column(field.column))
+    }
+}
+
+/// Types that a pattern field can be parsed into.
+pub trait FieldValue: Sized {
+    /// `list` is the separator given with `:list(...)` in the pattern.
+    fn parse_field(text: &str, list: Option<&str>) -> Result<Self, String>;
+}
+
+macro_rules! impl_field_value {
+    ($($t:ty),*) => {
+        $(
+            impl FieldValue for $t {
+                fn parse_field(text: &str, _list: Option<&str>) -> Result<Self, String> {
+                    <$t>::from_str(text).map_err(|e| e.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_field_value!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String
+);
+
+impl<T: FieldValue> FieldValue for Vec<T> {
+    fn parse_field(text: &str, list: Option<&str>) -> Result<Self, String> {
+        let sep = list.ok_or("a Vec field needs a :list separator")?;
+        if text.is_empty() {
+            return Ok(Vec::new());
+        }
+        text.split(sep).map(|t| T::parse_field(t, None)).collect()
+    }
+}
+
+/// Parses every non-empty line of the input, adding the line number to
+/// errors.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, InputError>
+where
+    T: FromStr<Err = InputError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| l.parse().map_err(|e: InputError| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Declares structs that are parsed from a line of text by a pattern (see
+/// [`crate::pattern`]), implementing `FromStr` with [`InputError`] errors:
+///
+/// ```
+/// aoclib::line_pattern! {
+///     #[derive(Debug)]
+///     struct Valve {
+///         name: String,
+///         rate: u32,
+///         targets: Vec<String>,
+///     } = "Valve {name} has flow rate={rate}; {_} valve{_} {targets:list(\", \")}";
+/// }
+///
+/// let valve: Valve = "Valve AA has flow rate=0; tunnels lead to valves DD, BB"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(vec!["DD", "BB"], valve.targets);
+/// ```
+///
+/// The field types must implement [`FieldValue`].
+///
+/// [`InputError`]: crate::InputError
+/// [`FieldValue`]: crate::pattern::FieldValue
+#[macro_export]
+macro_rules! line_pattern {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $ty:ty),* $(,)?
+        } = $pattern:literal;
+    )+) => {
+        $(
+            $(#[$attr])*
+            $vis struct $name {
+                $($field_vis $field: $ty),*
+            }
+
+            impl std::str::FromStr for $name {
+                type Err = $crate::InputError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    static PATTERN: std::sync::OnceLock<$crate::pattern::Pattern> =
+                        std::sync::OnceLock::new();
+                    let captures = PATTERN
+                        .get_or_init(|| {
+                            $crate::pattern::Pattern::new($pattern, &[$(stringify!($field)),*])
+                        })
+                        .captures(s)?;
+                    Ok($name {
+                        $($field: captures.get(stringify!($field))?),*
+                    })
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    crate::line_pattern! {
+        #[derive(PartialEq, Debug)]
+        struct Valve {
+            name: String,
+            rate: u32,
+            targets: Vec<String>,
+        } = "Valve {name} has flow rate={rate}; {_} valve{_} {targets:list(\", \")}";
+
+        #[derive(PartialEq, Debug)]
+        struct Robot {
+            px: i32,
+            py: i32,
+            vx: i32,
+            vy: i32,
+        } = "p={px},{py} v={vx},{vy}";
+
+        #[derive(Debug)]
+        struct Braces {
+            a: char,
+        } = "{{{a}}}";
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok(Valve {
+                name: "HH".to_owned(),
+                rate: 22,
+                targets: vec!["GG".to_owned()],
+            }),
+            "Valve HH has flow rate=22; tunnel leads to valve GG".parse()
+        );
+        assert_eq!(
+            Ok(Robot {
+                px: 0,
+                py: 4,
+                vx: 3,
+                vy: -3
+            }),
+            "p=0,4 v=3,-3\r".parse()
+        );
+        assert_eq!('x', "{x}".parse::<Braces>().unwrap().a);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = "Valve HH has flow rate=x2; tunnel leads to valve GG"
+            .parse::<Valve>()
+            .unwrap_err();
+        assert_eq!(
+            "column 24: invalid rate \"x2\": invalid digit found in string",
+            err.to_string()
+        );
+
+        let err = "p=0,4 w=3,-3".parse::<Robot>().unwrap_err();
+        assert_eq!(
+            "column 5: expected py followed by \" v=\", found \"4 w=3,-3\"",
+            err.to_string()
+        );
+
+        // The last field takes the rest of the line
+        let err = "p=0,4 v=3,-3 p=1".parse::<Robot>().unwrap_err();
+        assert_eq!(Some(11), err.column);
+        let err = "{x}}".parse::<Braces>().unwrap_err();
+        assert_eq!(
+            "column 4: expected end of line, found \"}\"",
+            err.to_string()
+        );
+        let err = "q=0,4 v=3,-3".parse::<Robot>().unwrap_err();
+        assert_eq!(
+            "column 1: expected \"p=\", found \"q=0,4 v=3,-3\"",
+            err.to_string()
+        );
+
+        let err = parse_lines::<Robot>("p=0,4 v=3,-3\n\np=1,2 v=3,x\n").unwrap_err();
+        assert_eq!(
+            "line 3, column 11: invalid vy \"x\": invalid digit found in string",
+            err.to_string()
+        );
+        assert_eq!(
+            2,
+            parse_lines::<Robot>("p=0,4 v=3,-3\np=1,2 v=3,4")
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "do not match the struct")]
+    fn test_pattern_missing_field() {
+        Pattern::new("p={px}", &["px", "py"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "without text between them")]
+    fn test_pattern_adjacent_fields() {
+        Pattern::new("{a}{b}", &["a", "b"]);
+    }
+}
@@ -0,0 +1,341 @@
+//! Regexes that are compiled once, and reading their capture groups into
+//! values.
+//!
+//! Use [`crate::regex`] for patterns known at compile time, and [`cached`]
+//! for patterns built at runtime. The groups of a match are read into tuples
+//! by position, or into structs declared with [`crate::regex_struct`] by
+//! name:
+//!
+//! ```
+//! use aoclib::re;
+//!
+//! let (lo, hi, c, pass): (usize, usize, char, &str) =
+//!     re::parse(aoclib::regex!(r"(\d+)-(\d+) (\w): (\w+)"), "1-3 a: abcde").unwrap();
+//! assert_eq!((1, 3, 'a', "abcde"), (lo, hi, c, pass));
+//! ```
+
+use crate::error::found;
+use crate::pattern::FieldValue;
+use crate::InputError;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+pub use regex::{Captures, Regex};
+
+/// Compiles a pattern, or returns the regex compiled for the same pattern
+/// before. Panics if the pattern is invalid.
+pub fn cached(pattern: &str) -> &'static Regex {
+    static CACHE: OnceLock<Mutex<HashMap<String, &'static Regex>>> = OnceLock::new();
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+    if let Some(re) = cache.get(pattern) {
+        return re;
+    }
+    let re = Box::leak(Box::new(compile(pattern)));
+    cache.insert(pattern.to_owned(), re);
+    re
+}
+
+/// Compiles a pattern, panicking with the pattern if it is invalid.
+pub fn compile(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap_or_else(|e| panic!("Invalid regex {:?}: {}", pattern, e))
+}
+
+/// A `&'static Regex` for a literal pattern, compiled the first time it is
+/// used.
+#[macro_export]
+macro_rules! regex {
+    ($pattern:literal) => {{
+        static RE: std::sync::OnceLock<$crate::re::Regex> = std::sync::OnceLock::new();
+        RE.get_or_init(|| $crate::re::compile($pattern))
+    }};
+}
+
+/// Values that a capture group can be read into: the matched text itself,
+/// or anything a [`crate::line_pattern`] field can be.
+pub trait FromGroup<'a>: Sized {
+    fn from_group(text: &'a str) -> Result<Self, String>;
+}
+
+impl<'a, T: FieldValue> FromGroup<'a> for T {
+    fn from_group(text: &'a str) -> Result<Self, String> {
+        T::parse_field(text, None)
+    }
+}
+
+impl<'a> FromGroup<'a> for &'a str {
+    fn from_group(text: &'a str) -> Result<Self, String> {
+        Ok(text)
+    }
+}
+
+/// The capture groups of a match, with the text that was searched for
+/// positioning errors.
+pub struct Groups<'a> {
+    captures: Captures<'a>,
+    haystack: &'a str,
+}
+
+impl<'a> Groups<'a> {
+    /// Reads the group with the given index, counted from 1.
+    pub fn get<T: FromGroup<'a>>(&self, i: usize) -> Result<T, InputError> {
+        self.read(self.captures.get(i), &format!("group {}", i))
+    }
+
+    /// Reads the group with the given name.
+    pub fn name<T: FromGroup<'a>>(&self, name: &str) -> Result<T, InputError> {
+        self.read(self.captures.name(name), name)
+    }
+
+    fn read<T: FromGroup<'a>>(
+        &self,
+        m: Option<regex::Match<'a>>,
+        label: &str,
+    ) -> Result<T, InputError> {
+        let whole = self.captures.get(0).unwrap();
+        let m = m.ok_or_else(|| {
+            InputError::unexpected(format!("a match for {}", label), found(whole.as_str()))
+                .at_offset(self.haystack, whole.start())
+        })?;
+        T::from_group(m.as_str()).map_err(|e| {
+            InputError::invalid(label, m.as_str(), e).at_offset(self.haystack, m.start())
+        })
+    }
+}
+
+/// Types that the capture groups of a match can be read into. Implemented
+/// for tuples, which take the groups in order starting from group 1, and for
+/// structs declared with [`crate::regex_struct`].
+pub trait FromCaptures<'a>: Sized {
+    fn from_captures(groups: &Groups<'a>) -> Result<Self, InputError>;
+}
+
+macro_rules! impl_from_captures {
+    ($(($($t:ident $i:literal),+))*) => {
+        $(
+            impl<'a, $($t: FromGroup<'a>),+> FromCaptures<'a> for ($($t,)+) {
+                fn from_captures(groups: &Groups<'a>) -> Result<Self, InputError> {
+                    Ok(($(groups.get::<$t>($i)?,)+))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_captures! {
+    (A 1)
+    (A 1, B 2)
+    (A 1, B 2, C 3)
+    (A 1, B 2, C 3, D 4)
+    (A 1, B 2, C 3, D 4, E 5)
+    (A 1, B 2, C 3, D 4, E 5, F 6)
+    (A 1, B 2, C 3, D 4, E 5, F 6, G 7)
+    (A 1, B 2, C 3, D 4, E 5, F 6, G 7, H 8)
+}
+
+/// Reads the first match of `re` in `s`. Fails if there is no match.
+pub fn parse<'a, T: FromCaptures<'a>>(re: &Regex, s: &'a str) -> Result<T, InputError> {
+    let captures = re.captures(s).ok_or_else(|| {
+        InputError::unexpected(format!("a match for /{}/", re.as_str()), found(s))
+    })?;
+    T::from_captures(&Groups {
+        captures,
+        haystack: s,
+    })
+}
+
+/// Reads every match of `re` in `s`, which may span several lines.
+pub fn captures_iter<'a, T: FromCaptures<'a>>(
+    re: &'a Regex,
+    s: &'a str,
+) -> impl Iterator<Item = Result<T, InputError>> + 'a {
+    re.captures_iter(s).map(move |captures| {
+        T::from_captures(&Groups {
+            captures,
+            haystack: s,
+        })
+    })
+}
+
+/// Declares structs whose fields are read from the named groups of a regex,
+/// with an associated `parse` function and a `regex` function returning
+/// the compiled regex:
+///
+/// ```
+/// aoclib::regex_struct! {
+///     #[derive(Debug)]
+///     struct Rule<'a> {
+///         field: &'a str,
+///         lo: u32,
+///         hi: u32,
+///     } = r"(?<field>[a-z ]+): (?<lo>\d+)-(?<hi>\d+)";
+/// }
+///
+/// let rule = Rule::parse("departure track: 25-974").unwrap();
+/// assert_eq!(("departure track", 25, 974), (rule.field, rule.lo, rule.hi));
+///
+/// let input = "class: 1-3\nrow: 6-11\n";
+/// let rules: Vec<Rule> = aoclib::re::captures_iter(Rule::regex(), input)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(2, rules.len());
+/// ```
+///
+/// The structs may have one lifetime parameter, for fields borrowing the
+/// matched text. Field types must implement [`FromGroup`].
+///
+/// [`FromGroup`]: crate::re::FromGroup
+#[macro_export]
+macro_rules! regex_struct {
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident<$lt:lifetime> {
+            $($field_vis:vis $field:ident: $ty:ty),* $(,)?
+        } = $pattern:literal;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis struct $name<$lt> {
+            $($field_vis $field: $ty),*
+        }
+
+        $crate::regex_struct!(@impl $lt, $name<$lt>, $pattern, $($field),*);
+        $crate::regex_struct!($($rest)*);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $ty:ty),* $(,)?
+        } = $pattern:literal;
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        $crate::regex_struct!(@impl 'a, $name, $pattern, $($field),*);
+        $crate::regex_struct!($($rest)*);
+    };
+    (@impl $lt:lifetime, $self_ty:ty, $pattern:literal, $($field:ident),*) => {
+        impl<$lt> $crate::re::FromCaptures<$lt> for $self_ty {
+            fn from_captures(
+                groups: &$crate::re::Groups<$lt>,
+            ) -> Result<Self, $crate::InputError> {
+                Ok(Self {
+                    $($field: groups.name(stringify!($field))?),*
+                })
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<$lt> $self_ty {
+            /// Reads the first match of the struct's regex in `s`.
+            pub fn parse(s: &$lt str) -> Result<Self, $crate::InputError> {
+                $crate::re::parse(Self::regex(), s)
+            }
+
+            /// The regex of the struct. Panics the first time if a field
+            /// has no group with its name.
+            pub fn regex() -> &'static $crate::re::Regex {
+                static RE: std::sync::OnceLock<$crate::re::Regex> = std::sync::OnceLock::new();
+                RE.get_or_init(|| {
+                    let re = $crate::re::compile($pattern);
+                    $(
+                        assert!(
+                            re.capture_names().any(|n| n == Some(stringify!($field))),
+                            "No group named {} in regex {:?}",
+                            stringify!($field),
+                            $pattern
+                        );
+                    )*
+                    re
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    crate::regex_struct! {
+        #[derive(PartialEq, Debug)]
+        struct Move {
+            count: usize,
+            from: u8,
+            to: u8,
+        } = r"move (?<count>\d+) from (?<from>\d) to (?<to>\d)";
+
+        #[allow(dead_code)]
+        struct Missing {
+            a: u8,
+        } = r"(?<b>\d)";
+    }
+
+    #[test]
+    fn test_cached() {
+        let a = cached(r"\d+");
+        assert!(std::ptr::eq(a, cached(r"\d+")));
+        assert!(!std::ptr::eq(a, cached(r"\w+")));
+
+        let f = || crate::regex!(r"\d+");
+        assert!(std::ptr::eq(f(), f()));
+    }
+
+    #[test]
+    fn test_tuples() {
+        let re = crate::regex!(r"(\w+) = \((\w+), (\w+)\)");
+        assert_eq!(
+            Ok(("AAA", "BBB".to_owned(), "CCC")),
+            parse::<(&str, String, &str)>(re, "AAA = (BBB, CCC)")
+        );
+
+        let input = "mul(2,4)xmul(3,7)do()mul(11,8)";
+        let muls: Vec<(u32, u32)> = captures_iter(crate::regex!(r"mul\((\d+),(\d+)\)"), input)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(vec![(2, 4), (3, 7), (11, 8)], muls);
+    }
+
+    #[test]
+    fn test_structs() {
+        assert_eq!(
+            Ok(Move {
+                count: 3,
+                from: 1,
+                to: 2
+            }),
+            Move::parse("move 3 from 1 to 2")
+        );
+        assert_eq!(
+            "line 2, column 6: invalid group 1 \"300\": number too large to fit in target type",
+            captures_iter::<(u8,)>(cached(r"move (\d+)"), "move 1\nmove 300")
+                .nth(1)
+                .unwrap()
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "expected a match for /move (?<count>\\d+) from (?<from>\\d) to (?<to>\\d)/, found \"move 3 from x to 2\"",
+            Move::parse("move 3 from x to 2").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_optional_group() {
+        let re = crate::regex!(r"(\d+)(?: \+ (\d+))?");
+        assert_eq!(Ok((1, 2)), parse::<(u8, u8)>(re, "1 + 2"));
+        assert_eq!(
+            "line 1, column 1: expected a match for group 2, found \"1\"",
+            parse::<(u8, u8)>(re, "1").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "No group named a")]
+    fn test_struct_missing_group() {
+        let _ = Missing::parse("1");
+    }
+}
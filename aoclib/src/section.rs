@@ -0,0 +1,100 @@
+//! Splitting the input into sections separated by blank lines.
+
+/// A block of the input separated from the others by blank lines.
+///
+/// A first line ending in `:` (like `Tile 2311:` or `0:`) is taken as the
+/// header of the section and is not part of the body.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Section<'a> {
+    /// The first line without the `:`, if it is a header
+    pub header: Option<&'a str>,
+    /// The lines after the header, without trailing whitespace at the end
+    pub body: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn new(block: &'a str) -> Section<'a> {
+        let (first, rest) = block.split_once('\n').unwrap_or((block, ""));
+        match first.trim_end().strip_suffix(':') {
+            Some(header) => Section {
+                header: Some(header),
+                body: rest.trim_end(),
+            },
+            None => Section {
+                header: None,
+                body: block,
+            },
+        }
+    }
+
+    /// Lines of the body, with trailing whitespace (including `\r`) removed.
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.body.lines().map(str::trim_end)
+    }
+}
+
+/// Splits the input into blocks separated by blank lines. Lines with only
+/// whitespace count as blank, runs of several blank lines separate just two
+/// blocks, and `\r\n` line endings are handled. Each block ends at the end of
+/// its last non-blank line, while leading whitespace of its first line is
+/// kept.
+pub fn split_sections(s: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut pos = 0;
+
+    for line in s.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&s[start..end]);
+            }
+        } else {
+            start.get_or_insert(pos);
+            end = pos + line.trim_end().len();
+        }
+        pos += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&s[start..end]);
+    }
+
+    sections
+}
+
+/// Splits the input into [`Section`]s, see [`split_sections`].
+pub fn read_string_sections(s: &str) -> Vec<Section<'_>> {
+    split_sections(s).into_iter().map(Section::new).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_sections() {
+        assert_eq!(
+            vec!["abc\nd", "e", "  f\n g"],
+            split_sections("\nabc\nd  \n\ne\n \t\n\n  f\n g\n\n")
+        );
+        assert_eq!(vec!["a\r\nb", "c"], split_sections("a\r\nb\r\n\r\nc\r\n"));
+        assert!(split_sections("\n \n").is_empty());
+    }
+
+    #[test]
+    fn test_read_string_sections() {
+        let sections =
+            read_string_sections("Tile 1:\r\n#.\r\n.#\r\n\r\n0:\n##\n\n4x4: 0 1\n5x5: 2 1\n");
+        assert_eq!(3, sections.len());
+        assert_eq!(Some("Tile 1"), sections[0].header);
+        assert_eq!(vec!["#.", ".#"], sections[0].lines().collect::<Vec<_>>());
+        assert_eq!(Some("0"), sections[1].header);
+        assert_eq!("##", sections[1].body);
+        assert_eq!(None, sections[2].header);
+        assert_eq!("4x4: 0 1\n5x5: 2 1", sections[2].body);
+
+        let header_only = Section::new("Header:");
+        assert_eq!(Some("Header"), header_only.header);
+        assert_eq!(0, header_only.lines().count());
+    }
+}
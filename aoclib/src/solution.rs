@@ -0,0 +1,304 @@
+use crate::{Answer, DayInputs, Input};
+use std::hint::black_box;
+use std::io;
+
+/// A solution for a single day's puzzle.
+///
+/// `parse` turns the raw puzzle input into `Input`, which is then handed to
+/// both parts. `Input` may borrow from the raw input string.
+pub trait Solution {
+    type Input<'a>;
+    type Part1: IntoAnswer;
+    type Part2: IntoAnswer;
+
+    fn parse(input: &str) -> io::Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+/// Part 2 answer type for puzzles that only have one part (day 25).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NoAnswer;
+
+pub trait IntoAnswer {
+    fn into_answer(self) -> Option<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Option<Answer> {
+        Some(self.into())
+    }
+}
+
+impl IntoAnswer for NoAnswer {
+    fn into_answer(self) -> Option<Answer> {
+        None
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+pub fn solve<S: Solution>(input: &str) -> io::Result<Answers> {
+    let input = S::parse(input)?;
+    Ok(Answers {
+        part1: S::part1(&input).into_answer(),
+        part2: S::part2(&input).into_answer(),
+    })
+}
+
+/// A separately timed step of a solution.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+/// Measures the stages of a solution. Each stage is given as a closure that
+/// runs it once, and may be called as many times as the measurement needs.
+pub trait Measure {
+    fn measure(&mut self, stage: Stage, run: &mut dyn FnMut());
+}
+
+/// Hands each stage of a solution to `m`. The input is parsed once up front
+/// so that the parts can be run on their own; a parse error is returned
+/// before anything is measured.
+pub fn measure<S: Solution>(input: &str, m: &mut dyn Measure) -> io::Result<()> {
+    let parsed = S::parse(input)?;
+
+    m.measure(Stage::Parse, &mut || {
+        let _ = black_box(S::parse(black_box(input)));
+    });
+    m.measure(Stage::Part1, &mut || {
+        black_box(S::part1(black_box(&parsed)));
+    });
+    m.measure(Stage::Part2, &mut || {
+        black_box(S::part2(black_box(&parsed)));
+    });
+
+    Ok(())
+}
+
+/// Runs a solution the way the per-day binaries always have: input is read
+/// from the file given as the only argument or from stdin, and the answers
+/// are printed. The day's stored examples and alternate inputs can be chosen
+/// with flags, see [`crate::input`].
+pub fn main<S: Solution>(day: &DayInputs) -> io::Result<()> {
+    let input = Input::from_env()?.read(Some(day))?;
+    let input = S::parse(&input)?;
+
+    if let Some(p1) = S::part1(&input).into_answer() {
+        print_answer(1, &p1);
+    }
+    if let Some(p2) = S::part2(&input).into_answer() {
+        print_answer(2, &p2);
+    }
+
+    Ok(())
+}
+
+/// Prints an answer, putting multi-line answers (such as bitmaps) on their
+/// own lines.
+pub fn print_answer(part: u8, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+/// Type-erased handle to a [`Solution`], so that solutions with different
+/// input and answer types can be stored in one registry.
+#[derive(Clone, Copy)]
+pub struct DynSolution {
+    solve: fn(&str) -> io::Result<Answers>,
+    measure: fn(&str, &mut dyn Measure) -> io::Result<()>,
+}
+
+impl DynSolution {
+    pub const fn new<S: Solution>() -> Self {
+        DynSolution {
+            solve: solve::<S>,
+            measure: measure::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> io::Result<Answers> {
+        (self.solve)(input)
+    }
+
+    pub fn measure(&self, input: &str, m: &mut dyn Measure) -> io::Result<()> {
+        (self.measure)(input, m)
+    }
+}
+
+/// A registered solution for one puzzle.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: DynSolution,
+}
+
+impl Entry {
+    pub const fn new(year: u16, day: u8, solution: DynSolution) -> Self {
+        Entry {
+            year,
+            day,
+            solution,
+        }
+    }
+}
+
+/// Generates `main` for a day binary, and the `SOLUTION` constant the year
+/// crate uses to register the day with the runner.
+#[macro_export]
+macro_rules! aoc_main {
+    ($solution:ty) => {
+        pub const SOLUTION: $crate::solution::DynSolution =
+            $crate::solution::DynSolution::new::<$solution>();
+
+        pub fn main() -> std::io::Result<()> {
+            $crate::solution::main::<$solution>(&$crate::DayInputs::of_source(
+                env!("CARGO_MANIFEST_DIR"),
+                file!(),
+            ))
+        }
+    };
+}
+
+/// Checks the answers of a solution against a puzzle example, see
+/// [`Answer::matches`].
+pub fn check_example<S: Solution>(input: &str, part1: Option<String>, part2: Option<String>) {
+    let input = crate::normalize_newlines(input.to_owned());
+    let input = S::parse(&input).expect("Failed to parse the example");
+    if let Some(expected) = part1 {
+        let actual = S::part1(&input).into_answer();
+        assert!(
+            actual.as_ref().is_some_and(|a| a.matches(&expected)),
+            "Part 1: expected {:?}, got {:?}",
+            expected,
+            actual.map(|a| a.to_string())
+        );
+    }
+    if let Some(expected) = part2 {
+        let actual = S::part2(&input).into_answer();
+        assert!(
+            actual.as_ref().is_some_and(|a| a.matches(&expected)),
+            "Part 2: expected {:?}, got {:?}",
+            expected,
+            actual.map(|a| a.to_string())
+        );
+    }
+}
+
+/// Generates a `#[test]` for each puzzle example of a day, in a module named
+/// `examples`. The input is a string literal or an `include_str!` of an
+/// `inputs/DD.exampleN.txt` file, and either part may be left out:
+///
+/// ```ignore
+/// aoclib::example_tests! {
+///     Day01;
+///     example1: include_str!("inputs/01.example1.txt"), part1 = 11, part2 = 31;
+///     basement: ")", part2 = 1;
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty; $($name:ident: $input:expr $(, part1 = $p1:expr)? $(, part2 = $p2:expr)?;)+) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    $crate::solution::check_example::<$solution>(
+                        $input,
+                        None$(.or(Some($p1.to_string())))?,
+                        None$(.or(Some($p2.to_string())))?,
+                    );
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<&'a str>;
+        type Part1 = usize;
+        type Part2 = NoAnswer;
+
+        fn parse(input: &str) -> io::Result<Self::Input<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> usize {
+            input.iter().map(|l| l.len()).sum()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answers = DynSolution::new::<Sum>().solve("ab\ncde\n").unwrap();
+        assert_eq!(answers.part1, Some(Answer::Int(5)));
+        assert_eq!(answers.part2, None);
+    }
+
+    #[test]
+    fn test_measure() {
+        struct Stages(Vec<Stage>);
+        impl Measure for Stages {
+            fn measure(&mut self, stage: Stage, run: &mut dyn FnMut()) {
+                run();
+                run();
+                self.0.push(stage);
+            }
+        }
+
+        let mut stages = Stages(Vec::new());
+        DynSolution::new::<Sum>()
+            .measure("ab\n", &mut stages)
+            .unwrap();
+        assert_eq!(Stage::ALL.to_vec(), stages.0);
+    }
+
+    crate::example_tests! {
+        Sum;
+        lines: "ab\ncde\n", part1 = 5;
+        empty: "", part1 = "0";
+    }
+
+    #[test]
+    #[should_panic(expected = "Part 1")]
+    fn test_check_example_wrong_answer() {
+        check_example::<Sum>("ab\n", Some("3".to_owned()), None);
+    }
+}
@@ -0,0 +1,376 @@
+//! Rotations and reflections of grids and coordinates.
+//!
+//! [`Dihedral`] is one of the eight ways to rotate and mirror a 2D grid, like
+//! the orientations of a jigsaw tile. [`Rotation3`] is one of the 24 ways to
+//! turn a cube, like the orientations of a scanner in 3D space.
+//!
+//! ```
+//! use aoclib::coord2::Coord2;
+//! use aoclib::transform::Dihedral;
+//! use ndarray::array;
+//!
+//! let grid = array![[1, 2, 3], [4, 5, 6]];
+//! assert_eq!(
+//!     array![[4, 1], [5, 2], [6, 3]],
+//!     Dihedral::ROTATE_CW.transform_array(grid.view())
+//! );
+//! assert_eq!(
+//!     Coord2 { y: 1, x: 0 },
+//!     Dihedral::ROTATE_CW.apply(Coord2 { y: 0isize, x: 1 })
+//! );
+//! ```
+
+use ndarray::{s, Array2, ArrayView2};
+use num_integer::Integer;
+use num_traits::Signed;
+
+use crate::coord2::Coord2;
+use crate::coord3::Coord3;
+use crate::dir::Dir4;
+
+/// A symmetry of the square: a mirroring left to right, or not, followed by
+/// a number of clockwise quarter turns. `y` grows downwards, like in the
+/// puzzle maps.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Dihedral {
+    flip: bool,
+    quarter_turns: u8,
+}
+
+impl Dihedral {
+    pub const IDENTITY: Dihedral = Dihedral::new(false, 0);
+    pub const ROTATE_CW: Dihedral = Dihedral::new(false, 1);
+    pub const ROTATE_180: Dihedral = Dihedral::new(false, 2);
+    pub const ROTATE_CCW: Dihedral = Dihedral::new(false, 3);
+    /// Mirrors left to right.
+    pub const FLIP_HORIZONTAL: Dihedral = Dihedral::new(true, 0);
+    /// Mirrors top to bottom.
+    pub const FLIP_VERTICAL: Dihedral = Dihedral::new(true, 2);
+
+    /// All eight transforms, the four rotations first.
+    pub const ALL: [Dihedral; 8] = [
+        Dihedral::new(false, 0),
+        Dihedral::new(false, 1),
+        Dihedral::new(false, 2),
+        Dihedral::new(false, 3),
+        Dihedral::new(true, 0),
+        Dihedral::new(true, 1),
+        Dihedral::new(true, 2),
+        Dihedral::new(true, 3),
+    ];
+
+    /// Mirrors left to right if `flip` is set, then turns clockwise
+    /// `quarter_turns` times.
+    pub const fn new(flip: bool, quarter_turns: u8) -> Dihedral {
+        Dihedral {
+            flip,
+            quarter_turns: quarter_turns % 4,
+        }
+    }
+
+    pub fn is_flipped(self) -> bool {
+        self.flip
+    }
+
+    pub fn quarter_turns(self) -> u8 {
+        self.quarter_turns
+    }
+
+    /// The transform doing `self` first and `next` after it.
+    pub fn then(self, next: Dihedral) -> Dihedral {
+        // Mirroring before turning is the same as turning the other way
+        // before mirroring.
+        let turns = if next.flip {
+            next.quarter_turns + 4 - self.quarter_turns
+        } else {
+            next.quarter_turns + self.quarter_turns
+        };
+        Dihedral::new(self.flip != next.flip, turns)
+    }
+
+    /// The transform undoing `self`.
+    pub fn inverse(self) -> Dihedral {
+        if self.flip {
+            self
+        } else {
+            Dihedral::new(false, 4 - self.quarter_turns)
+        }
+    }
+
+    /// Transforms a coordinate around the origin.
+    pub fn apply<T: Integer + Signed + Copy>(self, c: Coord2<T>) -> Coord2<T> {
+        let c = if self.flip {
+            Coord2 { y: c.y, x: -c.x }
+        } else {
+            c
+        };
+        match self.quarter_turns {
+            0 => c,
+            1 => Coord2 { y: c.x, x: -c.y },
+            2 => -c,
+            _ => Coord2 { y: -c.x, x: c.y },
+        }
+    }
+
+    /// Transforms a direction, so that a step in `dir` turns into a step in
+    /// the returned direction.
+    pub fn apply_dir(self, dir: Dir4) -> Dir4 {
+        let dir = if self.flip && dir.is_horizontal() {
+            dir.reverse()
+        } else {
+            dir
+        };
+        Dir4::ALL[(dir as usize + self.quarter_turns as usize) % 4]
+    }
+
+    /// The shape of a grid of shape `dim` after the transform.
+    pub fn transformed_dim(self, (h, w): (usize, usize)) -> (usize, usize) {
+        if self.quarter_turns.is_multiple_of(2) {
+            (h, w)
+        } else {
+            (w, h)
+        }
+    }
+
+    /// Where an index of a grid of shape `dim` ends up in the transformed
+    /// grid.
+    pub fn apply_index(self, c: Coord2<usize>, (h, w): (usize, usize)) -> Coord2<usize> {
+        let c = if self.flip {
+            Coord2 {
+                y: c.y,
+                x: w - 1 - c.x,
+            }
+        } else {
+            c
+        };
+        match self.quarter_turns {
+            0 => c,
+            1 => Coord2 {
+                y: c.x,
+                x: h - 1 - c.y,
+            },
+            2 => Coord2 {
+                y: h - 1 - c.y,
+                x: w - 1 - c.x,
+            },
+            _ => Coord2 {
+                y: w - 1 - c.x,
+                x: c.y,
+            },
+        }
+    }
+
+    /// A view of the transformed grid, without copying it.
+    pub fn view<'a, T>(self, grid: ArrayView2<'a, T>) -> ArrayView2<'a, T> {
+        let grid = if self.flip {
+            grid.slice_move(s![.., ..;-1])
+        } else {
+            grid
+        };
+        match self.quarter_turns {
+            0 => grid,
+            1 => grid.reversed_axes().slice_move(s![.., ..;-1]),
+            2 => grid.slice_move(s![..;-1, ..;-1]),
+            _ => grid.reversed_axes().slice_move(s![..;-1, ..]),
+        }
+    }
+
+    /// A transformed copy of the grid.
+    pub fn transform_array<T: Clone>(self, grid: ArrayView2<T>) -> Array2<T> {
+        self.view(grid).to_owned()
+    }
+}
+
+/// The orientations of a grid, each returned once even if the grid is
+/// symmetric.
+pub fn orientations<T: Clone + PartialEq>(grid: ArrayView2<T>) -> Vec<Array2<T>> {
+    let mut res: Vec<Array2<T>> = Vec::with_capacity(8);
+    for t in Dihedral::ALL {
+        let g = t.transform_array(grid.view());
+        if !res.contains(&g) {
+            res.push(g);
+        }
+    }
+    res
+}
+
+/// A rotation of 3D space taking the axes onto axes: axis `i` of the
+/// result is axis `axes[i]` of the input, negated if `negate[i]` is set.
+/// Only proper rotations can be built, never mirrorings.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Rotation3 {
+    axes: [usize; 3],
+    negate: [bool; 3],
+}
+
+impl Rotation3 {
+    pub const IDENTITY: Rotation3 = Rotation3 {
+        axes: [0, 1, 2],
+        negate: [false; 3],
+    };
+    /// The quarter turn around the `x` axis taking `y` to `z`.
+    pub const QUARTER_X: Rotation3 = Rotation3 {
+        axes: [0, 2, 1],
+        negate: [false, true, false],
+    };
+    /// The quarter turn around the `y` axis taking `z` to `x`.
+    pub const QUARTER_Y: Rotation3 = Rotation3 {
+        axes: [2, 1, 0],
+        negate: [false, false, true],
+    };
+    /// The quarter turn around the `z` axis taking `x` to `y`.
+    pub const QUARTER_Z: Rotation3 = Rotation3 {
+        axes: [1, 0, 2],
+        negate: [true, false, false],
+    };
+
+    /// All 24 rotations, the identity first.
+    pub fn all() -> impl Iterator<Item = Rotation3> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ];
+        (0..6 * 8).filter_map(|i| {
+            let axes = PERMUTATIONS[i / 8];
+            let negate = [i & 1 != 0, i & 2 != 0, i & 4 != 0];
+            Rotation3::new(axes, negate)
+        })
+    }
+
+    /// The rotation with the given axis mapping, or `None` if `axes` is not
+    /// a permutation or the mapping would mirror space.
+    pub fn new(axes: [usize; 3], negate: [bool; 3]) -> Option<Rotation3> {
+        let odd_permutation = match axes {
+            [0, 1, 2] | [1, 2, 0] | [2, 0, 1] => false,
+            [0, 2, 1] | [2, 1, 0] | [1, 0, 2] => true,
+            _ => return None,
+        };
+        let negations = negate.iter().filter(|&&n| n).count();
+        (odd_permutation == (negations % 2 == 1)).then_some(Rotation3 { axes, negate })
+    }
+
+    /// The rotation doing `self` first and `next` after it.
+    pub fn then(self, next: Rotation3) -> Rotation3 {
+        Rotation3 {
+            axes: next.axes.map(|a| self.axes[a]),
+            negate: [0, 1, 2].map(|i| next.negate[i] != self.negate[next.axes[i]]),
+        }
+    }
+
+    /// The rotation undoing `self`.
+    pub fn inverse(self) -> Rotation3 {
+        let mut inv = self;
+        for i in 0..3 {
+            inv.axes[self.axes[i]] = i;
+            inv.negate[self.axes[i]] = self.negate[i];
+        }
+        inv
+    }
+
+    /// Rotates values given in `(x, y, z)` order.
+    pub fn apply_array<T: Signed + Copy>(self, v: [T; 3]) -> [T; 3] {
+        [0, 1, 2].map(|i| {
+            let a = v[self.axes[i]];
+            if self.negate[i] {
+                -a
+            } else {
+                a
+            }
+        })
+    }
+
+    /// Rotates a coordinate around the origin.
+    pub fn apply<T: Integer + Signed + Copy>(self, c: Coord3<T>) -> Coord3<T> {
+        let [x, y, z] = self.apply_array([c.x, c.y, c.z]);
+        Coord3 { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+    use ndarray::array;
+
+    #[test]
+    fn test_dihedral_group() {
+        for a in Dihedral::ALL {
+            assert_eq!(Dihedral::IDENTITY, a.then(a.inverse()));
+            assert_eq!(Dihedral::IDENTITY, a.inverse().then(a));
+            for b in Dihedral::ALL {
+                let c = Coord2 { y: 2isize, x: 5 };
+                assert_eq!(b.apply(a.apply(c)), a.then(b).apply(c));
+                assert_eq!(
+                    b.apply_dir(a.apply_dir(Dir4::Right)),
+                    a.then(b).apply_dir(Dir4::Right)
+                );
+            }
+        }
+        assert_eq!(8, Dihedral::ALL.iter().unique().count());
+        assert_eq!(
+            Dihedral::ROTATE_180,
+            Dihedral::FLIP_HORIZONTAL.then(Dihedral::FLIP_VERTICAL)
+        );
+        assert_eq!(
+            Dihedral::ROTATE_CCW,
+            Dihedral::ROTATE_CW.then(Dihedral::ROTATE_180)
+        );
+        assert_eq!(Dir4::Down, Dihedral::ROTATE_CW.apply_dir(Dir4::Right));
+        assert_eq!(Dir4::Left, Dihedral::FLIP_HORIZONTAL.apply_dir(Dir4::Right));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let grid = array![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            array![[3, 6], [2, 5], [1, 4]],
+            Dihedral::ROTATE_CCW.transform_array(grid.view())
+        );
+        assert_eq!(
+            array![[4, 5, 6], [1, 2, 3]],
+            Dihedral::FLIP_VERTICAL.transform_array(grid.view())
+        );
+        for t in Dihedral::ALL {
+            let transformed = t.view(grid.view());
+            assert_eq!(t.transformed_dim(grid.dim()), transformed.dim());
+            for ((y, x), v) in grid.indexed_iter() {
+                let i = t.apply_index(Coord2 { y, x }, grid.dim());
+                assert_eq!(v, &transformed[i]);
+            }
+            assert_eq!(grid, t.inverse().transform_array(transformed), "{:?}", t);
+        }
+        assert_eq!(8, orientations(grid.view()).len());
+        assert_eq!(1, orientations(array![[1, 1], [1, 1]].view()).len());
+    }
+
+    #[test]
+    fn test_rotations() {
+        let all = Rotation3::all().collect_vec();
+        assert_eq!(24, all.len());
+        assert_eq!(Rotation3::IDENTITY, all[0]);
+
+        let c = Coord3 {
+            x: 1isize,
+            y: 2,
+            z: 3,
+        };
+        assert_eq!(24, all.iter().map(|r| r.apply(c)).unique().count());
+        for &a in &all {
+            assert_eq!(Rotation3::IDENTITY, a.then(a.inverse()));
+            for &b in &all {
+                assert!(all.contains(&a.then(b)));
+                assert_eq!(b.apply(a.apply(c)), a.then(b).apply(c));
+            }
+        }
+
+        assert_eq!(Coord3 { x: 1, y: -3, z: 2 }, Rotation3::QUARTER_X.apply(c));
+        assert_eq!(Coord3 { x: 3, y: 2, z: -1 }, Rotation3::QUARTER_Y.apply(c));
+        assert_eq!(Coord3 { x: -2, y: 1, z: 3 }, Rotation3::QUARTER_Z.apply(c));
+        assert_eq!(None, Rotation3::new([0, 1, 2], [true, false, false]));
+        assert_eq!(None, Rotation3::new([0, 0, 2], [false; 3]));
+    }
+}
@@ -0,0 +1,32 @@
+use aoclib::solution::{Measure, Stage};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+
+struct Group<'a, 'c>(&'a mut BenchmarkGroup<'c, WallTime>);
+
+impl Measure for Group<'_, '_> {
+    fn measure(&mut self, stage: Stage, run: &mut dyn FnMut()) {
+        self.0.bench_function(stage.name(), |b| b.iter(&mut *run));
+    }
+}
+
+pub fn solutions_benchmark(c: &mut Criterion) {
+    for entry in aoc_runner::solutions() {
+        // Days without a stored input are skipped
+        let Ok(input) = aoclib::read_file_string(aoc_runner::input_path(entry.year, entry.day))
+        else {
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("{}/{:02}", entry.year, entry.day));
+        // Some days take seconds per run, so keep to criterion's minimum
+        group.sample_size(10);
+        if let Err(e) = entry.solution.measure(&input, &mut Group(&mut group)) {
+            eprintln!("{} day {:02}: {}", entry.year, entry.day, e);
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,198 @@
+//! Known answers for the puzzle inputs, kept per year in `YEAR/answers.toml`:
+//!
+//! ```toml
+//! [1]
+//! part1 = "138"
+//! part2 = "1771"
+//! ```
+//!
+//! Days or parts missing from the file are reported as unknown.
+
+use aoclib::solution::Answers;
+use aoclib::Answer;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Option<&Answer>) -> Verdict {
+        match (expected, actual) {
+            (Some(e), Some(a)) if a.matches(e) => Verdict::Pass,
+            // Nothing to check for a part without an answer (day 25 part 2)
+            (None, None) => Verdict::Pass,
+            (Some(e), _) => Verdict::Fail {
+                expected: e.to_owned(),
+            },
+            (None, _) => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct AnswerFile {
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+impl AnswerFile {
+    /// Loads the answers from `path`. A missing file has no answers.
+    pub fn load(path: &Path) -> io::Result<AnswerFile> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => AnswerFile::parse(&s).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(AnswerFile::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_toml())
+    }
+
+    pub fn parse(s: &str) -> Result<AnswerFile, String> {
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| e.to_string())?;
+
+        let mut days = BTreeMap::new();
+        for (key, value) in table {
+            let day = key
+                .parse()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("Invalid day [{}]", key))?;
+            let parts = value
+                .as_table()
+                .ok_or_else(|| format!("[{}] is not a table", key))?;
+
+            let mut answers: [Option<String>; 2] = Default::default();
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => 0,
+                    "part2" => 1,
+                    _ => return Err(format!("Unknown key {} in [{}]", name, key)),
+                };
+                let answer = answer
+                    .as_str()
+                    .ok_or_else(|| format!("[{}] {} is not a string", key, name))?;
+                answers[part] = Some(answer.to_owned());
+            }
+            days.insert(day, answers);
+        }
+
+        Ok(AnswerFile { days })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        for (day, answers) in &self.days {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[{}]\n", day));
+            for (part, answer) in answers.iter().enumerate() {
+                if let Some(answer) = answer {
+                    let value = toml::Value::String(answer.clone());
+                    out.push_str(&format!("part{} = {}\n", part + 1, value));
+                }
+            }
+        }
+        out
+    }
+
+    /// The recorded answer for a part (1 or 2) of a day.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?[part as usize - 1].as_deref()
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        self.days.entry(day).or_default()[part as usize - 1] = Some(answer.to_owned());
+    }
+
+    /// Checks both parts of a day's answers against the recorded ones.
+    pub fn check(&self, day: u8, answers: &Answers) -> [Verdict; 2] {
+        [
+            Verdict::check(self.get(day, 1), answers.part1.as_ref()),
+            Verdict::check(self.get(day, 2), answers.part2.as_ref()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FILE: &str = r#"[1]
+part1 = "138"
+part2 = "1771"
+
+[8]
+part1 = "2286"
+part2 = """
+ ##  
+#  # """
+
+[25]
+part1 = "12"
+"#;
+
+    #[test]
+    fn test_parse_and_write() {
+        let file = AnswerFile::parse(FILE).unwrap();
+        assert_eq!(Some("1771"), file.get(1, 2));
+        assert_eq!(Some(" ##  \n#  # "), file.get(8, 2));
+        assert_eq!(None, file.get(25, 2));
+        assert_eq!(None, file.get(3, 1));
+
+        assert_eq!(FILE, file.to_toml());
+
+        assert!(AnswerFile::parse("[26]\npart1 = \"1\"\n").is_err());
+        assert!(AnswerFile::parse("[1]\npart3 = \"1\"\n").is_err());
+        assert!(AnswerFile::parse("[1]\npart1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut file = AnswerFile::parse(FILE).unwrap();
+        let answers = Answers {
+            part1: Some(Answer::Int(138)),
+            part2: Some(Answer::Int(1772)),
+        };
+        assert_eq!(
+            [
+                Verdict::Pass,
+                Verdict::Fail {
+                    expected: "1771".to_owned()
+                }
+            ],
+            file.check(1, &answers)
+        );
+        assert_eq!(
+            [Verdict::Unknown, Verdict::Unknown],
+            file.check(2, &answers)
+        );
+
+        file.set(2, 1, "138");
+        assert_eq!([Verdict::Pass, Verdict::Unknown], file.check(2, &answers));
+
+        let day25 = Answers {
+            part1: Some(Answer::Int(12)),
+            part2: None,
+        };
+        assert_eq!([Verdict::Pass, Verdict::Pass], file.check(25, &day25));
+
+        let bitmap = Answers {
+            part1: Some(Answer::Int(2286)),
+            part2: Some(Answer::Bitmap(Answer::parse_bitmap(".##.\n#..#"))),
+        };
+        assert_eq!([Verdict::Pass, Verdict::Pass], file.check(8, &bitmap));
+    }
+}
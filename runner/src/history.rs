@@ -0,0 +1,278 @@
+//! Stored timing runs, kept as one JSON file per run in the `perf` directory
+//! of the workspace, and comparing a run against an earlier one.
+
+use crate::timing::Timings;
+use aoclib::solution::Stage;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Median times of one day's stages, in nanoseconds.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    pub parse: u64,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+impl DayTimings {
+    pub fn new(year: u16, day: u8, timings: &Timings) -> DayTimings {
+        let mut day = DayTimings {
+            year,
+            day,
+            parse: 0,
+            part1: 0,
+            part2: 0,
+        };
+        for (stage, stats) in &timings.0 {
+            *day.stage_mut(*stage) = stats.median.as_nanos() as u64;
+        }
+        day
+    }
+
+    pub fn stage(&self, stage: Stage) -> Duration {
+        Duration::from_nanos(match stage {
+            Stage::Parse => self.parse,
+            Stage::Part1 => self.part1,
+            Stage::Part2 => self.part2,
+        })
+    }
+
+    fn stage_mut(&mut self, stage: Stage) -> &mut u64 {
+        match stage {
+            Stage::Parse => &mut self.parse,
+            Stage::Part1 => &mut self.part1,
+            Stage::Part2 => &mut self.part2,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Run {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// `git describe --always --dirty` of the workspace, if available
+    pub commit: Option<String>,
+    /// How many times each stage was run; the stored times are medians
+    pub repeat: usize,
+    pub days: Vec<DayTimings>,
+}
+
+impl Run {
+    /// A run timed now, on the current commit of the workspace.
+    pub fn new(repeat: usize, days: Vec<DayTimings>) -> Run {
+        Run {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: current_commit(),
+            repeat,
+            days,
+        }
+    }
+
+    /// File name of the run in the history directory.
+    pub fn file_name(&self) -> String {
+        match &self.commit {
+            Some(commit) => format!("{}-{}.json", self.timestamp, commit),
+            None => format!("{}.json", self.timestamp),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|d| d.year == year && d.day == day)
+    }
+}
+
+fn current_commit() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(crate::workspace_root())
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !commit.trim().is_empty()).then(|| commit.trim().to_owned())
+}
+
+pub fn history_dir() -> PathBuf {
+    crate::workspace_root().join("perf")
+}
+
+/// Writes a run into `dir`, returning the path of the new file.
+pub fn save(dir: &Path, run: &Run) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(run.file_name());
+    std::fs::write(&path, serde_json::to_string_pretty(run)?)?;
+    Ok(path)
+}
+
+pub fn load(path: &Path) -> io::Result<Run> {
+    let s = std::fs::read_to_string(path)?;
+    serde_json::from_str(&s).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// The most recent run stored in `dir`, if any.
+pub fn latest(dir: &Path) -> io::Result<Option<Run>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let mut latest: Option<Run> = None;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let run = load(&path)?;
+            if latest.as_ref().is_none_or(|l| run.timestamp >= l.timestamp) {
+                latest = Some(run);
+            }
+        }
+    }
+    Ok(latest)
+}
+
+/// A stage whose time changed by more than the threshold between two runs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Change {
+    /// Whether the stage took no time in the baseline, like a part that was
+    /// not solved yet, leaving nothing to compare against.
+    pub fn is_new(&self) -> bool {
+        self.before.is_zero()
+    }
+
+    pub fn regressed(&self) -> bool {
+        !self.is_new() && self.after > self.before
+    }
+
+    /// Relative change, e.g. `0.25` for 25 % slower, or `None` for a new
+    /// stage.
+    pub fn ratio(&self) -> Option<f64> {
+        (!self.is_new()).then(|| self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0)
+    }
+}
+
+/// Stages this fast are too noisy to compare.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+/// Compares the days present in both runs, returning the stages that got
+/// slower or faster by more than `threshold` (e.g. `0.1` for 10 %), and the
+/// new ones. Changes where both times are under [`NOISE_FLOOR`] are ignored.
+pub fn compare(baseline: &Run, current: &Run, threshold: f64) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for after in &current.days {
+        let Some(before) = baseline.get(after.year, after.day) else {
+            continue;
+        };
+        for stage in Stage::ALL {
+            let change = Change {
+                year: after.year,
+                day: after.day,
+                stage,
+                before: before.stage(stage),
+                after: after.stage(stage),
+            };
+            if change.before.max(change.after) < NOISE_FLOOR {
+                continue;
+            }
+            if change.ratio().is_none_or(|r| r.abs() > threshold) {
+                changes.push(change);
+            }
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(timestamp: u64, days: &[(u8, u64, u64, u64)]) -> Run {
+        Run {
+            timestamp,
+            commit: Some("abc123".to_owned()),
+            repeat: 5,
+            days: days
+                .iter()
+                .map(|&(day, parse, part1, part2)| DayTimings {
+                    year: 2024,
+                    day,
+                    parse,
+                    part1,
+                    part2,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let ms = 1_000_000;
+        let baseline = run(1, &[(1, ms, 10 * ms, 10 * ms), (2, 1000, 1000, ms)]);
+        let current = run(
+            2,
+            &[
+                (1, ms, 12 * ms, 5 * ms),
+                (2, 3000, 1000, ms),
+                (3, ms, ms, ms),
+            ],
+        );
+
+        let changes = compare(&baseline, &current, 0.1);
+        assert_eq!(2, changes.len());
+        assert_eq!((1, Stage::Part1), (changes[0].day, changes[0].stage));
+        assert!(changes[0].regressed());
+        assert!((changes[0].ratio().unwrap() - 0.2).abs() < 1e-9);
+        assert_eq!((1, Stage::Part2), (changes[1].day, changes[1].stage));
+        assert!(!changes[1].regressed());
+
+        assert!(compare(&baseline, &current, 1.5).is_empty());
+    }
+
+    #[test]
+    fn test_compare_new_stage() {
+        let ms = 1_000_000;
+        let baseline = run(1, &[(1, ms, ms, 0)]);
+        let current = run(2, &[(1, ms, ms, ms)]);
+
+        let changes = compare(&baseline, &current, 1.5);
+        assert_eq!(1, changes.len());
+        assert_eq!(Stage::Part2, changes[0].stage);
+        assert!(changes[0].is_new());
+        assert_eq!(None, changes[0].ratio());
+        assert!(!changes[0].regressed());
+    }
+
+    #[test]
+    fn test_save_and_latest() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(None, latest(&dir).unwrap());
+
+        let old = run(100, &[(1, 1, 2, 3)]);
+        let new = run(200, &[(1, 4, 5, 6)]);
+        save(&dir, &new).unwrap();
+        let path = save(&dir, &old).unwrap();
+        assert_eq!("100-abc123.json", path.file_name().unwrap());
+        assert_eq!(old, load(&path).unwrap());
+        assert_eq!(Some(new), latest(&dir).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
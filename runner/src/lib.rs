@@ -0,0 +1,172 @@
+use aoclib::solution::Entry;
+use aoclib::DayInputs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub mod answers;
+pub mod history;
+pub mod scaffold;
+pub mod timing;
+
+/// All registered solutions, ordered by year and day.
+pub fn solutions() -> impl Iterator<Item = &'static Entry> {
+    [
+        aoc2015::SOLUTIONS,
+        aoc2019::SOLUTIONS,
+        aoc2020::SOLUTIONS,
+        aoc2021::SOLUTIONS,
+        aoc2022::SOLUTIONS,
+        aoc2023::SOLUTIONS,
+        aoc2024::SOLUTIONS,
+        aoc2025::SOLUTIONS,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate is not inside the workspace")
+}
+
+/// The inputs stored for a day: the puzzle input, examples and alternates.
+pub fn day_inputs(year: u16, day: u8) -> DayInputs {
+    DayInputs::new(
+        workspace_root()
+            .join(year.to_string())
+            .join("src")
+            .join("bin")
+            .join("inputs"),
+        day,
+    )
+}
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_inputs(year, day).input()
+}
+
+/// The known answers of a year, see [`answers`].
+pub fn answers_path(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string()).join("answers.toml")
+}
+
+/// Reads the adventofcode.com session token from the `AOC_SESSION`
+/// environment variable, or from a `session_token` file in the year's
+/// directory or the workspace root, in that order.
+pub fn read_session_token(year: u16) -> io::Result<String> {
+    if let Ok(token) = std::env::var("AOC_SESSION") {
+        return Ok(token);
+    }
+
+    let root = workspace_root();
+    [
+        root.join(year.to_string()).join("session_token"),
+        root.join("session_token"),
+    ]
+    .iter()
+    .find_map(|p| std::fs::read_to_string(p).ok())
+    .ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "No session token: set AOC_SESSION or create a session_token file",
+        )
+    })
+}
+
+/// Selects solutions to run: `all`, a whole year (`2023`) or a single day
+/// (`2023/7` or `2023/07`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Target {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+impl Target {
+    pub const ALL: Target = Target {
+        year: None,
+        day: None,
+    };
+
+    pub fn matches(&self, entry: &Entry) -> bool {
+        self.year.is_none_or(|y| y == entry.year) && self.day.is_none_or(|d| d == entry.day)
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Target::ALL);
+        }
+
+        let (year, day) = match s.split_once('/') {
+            Some((y, d)) => (y, Some(d)),
+            None => (s, None),
+        };
+        let year = year
+            .parse()
+            .map_err(|_| format!("Invalid year '{}'", year))?;
+        let day = day
+            .map(|d| d.parse().map_err(|_| format!("Invalid day '{}'", d)))
+            .transpose()?;
+        if day.is_some_and(|d| !(1..=25).contains(&d)) {
+            return Err(format!("Day out of range in '{}'", s));
+        }
+
+        Ok(Target {
+            year: Some(year),
+            day,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_target() {
+        assert_eq!(Ok(Target::ALL), "all".parse());
+        assert_eq!(
+            Ok(Target {
+                year: Some(2023),
+                day: None
+            }),
+            "2023".parse()
+        );
+        assert_eq!(
+            Ok(Target {
+                year: Some(2023),
+                day: Some(7)
+            }),
+            "2023/07".parse()
+        );
+        assert!("2023/26".parse::<Target>().is_err());
+        assert!("x/1".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn test_day_inputs() {
+        let day = day_inputs(2023, 7);
+        assert_eq!(
+            workspace_root().join("2023/src/bin/inputs/07.txt"),
+            input_path(2023, 7)
+        );
+        assert_eq!(
+            workspace_root().join("2023/src/bin/inputs/07.example2.txt"),
+            day.example(2)
+        );
+    }
+
+    #[test]
+    fn test_solutions_registered_in_order() {
+        let keys: Vec<_> = solutions().map(|e| (e.year, e.day)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+}
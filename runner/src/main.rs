@@ -0,0 +1,610 @@
+use aoc_runner::answers::{AnswerFile, Verdict};
+use aoc_runner::history::{self, DayTimings, Run};
+use aoc_runner::scaffold::{self, Template};
+use aoc_runner::timing::{self, Timings};
+use aoc_runner::Target;
+use aoclib::fetch::{FetchStatus, Fetcher};
+use aoclib::solution::{print_answer, Answers, Entry};
+use aoclib::Input;
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions from every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions against their puzzle inputs
+    Run {
+        /// `YEAR/DAY`, `YEAR` or `all` (the default)
+        targets: Vec<Target>,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Time the parse, part 1 and part 2 stages of solutions separately
+    Time {
+        /// `YEAR/DAY`, `YEAR` or `all` (the default)
+        targets: Vec<Target>,
+
+        /// Run each stage this many times and report statistics
+        #[arg(short = 'n', long, default_value_t = 1)]
+        repeat: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Store the timings in the perf history
+        #[arg(long)]
+        save: bool,
+    },
+    /// Time solutions and report the stages that got slower or faster than
+    /// in a stored run
+    Report {
+        /// `YEAR/DAY`, `YEAR` or `all` (the default)
+        targets: Vec<Target>,
+
+        /// Run each stage this many times and compare the medians
+        #[arg(short = 'n', long, default_value_t = 5)]
+        repeat: usize,
+
+        /// Report changes larger than this many percent
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Compare against this stored run instead of the latest one
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// Also store the new timings in the perf history
+        #[arg(long)]
+        save: bool,
+    },
+    /// Check answers against the ones recorded in each year's answers.toml
+    Verify {
+        /// `YEAR/DAY`, `YEAR` or `all` (the default)
+        targets: Vec<Target>,
+
+        /// Record the answers of parts that have no known answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Download puzzle inputs that are not stored yet
+    Fetch {
+        /// `YEAR/DAY`, `YEAR` (registered days only) or `all`
+        #[arg(required = true)]
+        targets: Vec<Target>,
+
+        /// Fetch from this server instead of adventofcode.com
+        #[arg(long, default_value = aoclib::fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Create a new day from a template and fetch its input
+    New {
+        /// `YEAR/DAY`
+        target: Target,
+
+        /// How the template parses the input
+        #[arg(short, long, value_enum, default_value_t = Template::Lines)]
+        template: Template,
+
+        /// Do not download the puzzle input
+        #[arg(long)]
+        no_fetch: bool,
+    },
+}
+
+/// Chooses another input than the puzzle input of the day, see
+/// [`aoclib::input`].
+#[derive(Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Read the input from this file instead (single day only)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Read the day's Nth example instead (single day only)
+    #[arg(short, long, value_name = "N")]
+    example: Option<u32>,
+
+    /// Read the day's input stored as `DD.NAME.txt` instead (single day only)
+    #[arg(long, value_name = "NAME")]
+    alt: Option<String>,
+}
+
+impl InputArgs {
+    fn input(self) -> Option<Input> {
+        self.input
+            .map(Input::File)
+            .or(self.example.map(Input::Example))
+            .or(self.alt.map(Input::Alt))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { targets, input } => run(&targets, input.input().as_ref()),
+        Command::Time {
+            targets,
+            repeat,
+            input,
+            save,
+        } => time(&targets, repeat, input.input().as_ref(), save),
+        Command::Report {
+            targets,
+            repeat,
+            threshold,
+            baseline,
+            save,
+        } => report(&targets, repeat, threshold, baseline.as_deref(), save),
+        Command::Verify { targets, record } => verify(&targets, record),
+        Command::Fetch { targets, base_url } => fetch(&targets, &base_url),
+        Command::New {
+            target,
+            template,
+            no_fetch,
+        } => new_day(target, template, !no_fetch),
+    }
+}
+
+fn select(targets: &[Target]) -> Vec<&'static Entry> {
+    aoc_runner::solutions()
+        .filter(|e| targets.is_empty() || targets.iter().any(|t| t.matches(e)))
+        .collect()
+}
+
+fn run(targets: &[Target], input: Option<&Input>) -> ExitCode {
+    let entries = select(targets);
+    if entries.is_empty() {
+        eprintln!("No solutions match the given targets");
+        return ExitCode::FAILURE;
+    }
+    if input.is_some() && entries.len() > 1 {
+        eprintln!("Another input can only be chosen when running a single day");
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for entry in entries {
+        println!("== {} day {:02} ==", entry.year, entry.day);
+
+        match solve(entry, &input_file(entry, input)) {
+            Ok(answers) => {
+                if let Some(p1) = answers.part1 {
+                    print_answer(1, &p1);
+                }
+                if let Some(p2) = answers.part2 {
+                    print_answer(2, &p2);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The file to read the input of `entry` from: its puzzle input, or the one
+/// chosen on the command line, looked up in the day's stored inputs.
+fn input_file(entry: &Entry, input: Option<&Input>) -> PathBuf {
+    let day = aoc_runner::day_inputs(entry.year, entry.day);
+    match input {
+        None | Some(Input::Stdin) => day.input(),
+        Some(Input::File(path)) => path.clone(),
+        Some(Input::Example(n)) => day.example(*n),
+        Some(Input::Alt(name)) => day.file(name),
+    }
+}
+
+fn solve(entry: &Entry, input: &Path) -> Result<Answers, String> {
+    aoclib::read_file_string(input)
+        .map_err(|e| format!("Failed to read {}: {}", input.display(), e))
+        .and_then(|s| entry.solution.solve(&s).map_err(|e| e.to_string()))
+}
+
+fn time(targets: &[Target], repeat: usize, input: Option<&Input>, save: bool) -> ExitCode {
+    let entries = select(targets);
+    if entries.is_empty() {
+        eprintln!("No solutions match the given targets");
+        return ExitCode::FAILURE;
+    }
+    if input.is_some() && entries.len() > 1 {
+        eprintln!("Another input can only be chosen when running a single day");
+        return ExitCode::FAILURE;
+    }
+    if input.is_some() && save {
+        eprintln!("Timings of other inputs are not saved");
+        return ExitCode::FAILURE;
+    }
+
+    let (timed, mut failed) = time_entries(&entries, repeat, input, true);
+
+    if timed.len() > 1 {
+        let mut totals: Vec<_> = timed.iter().map(|(e, t)| (e, t.total())).collect();
+        println!();
+        println!(
+            "Total {} for {} days",
+            format_duration(totals.iter().map(|(_, t)| *t).sum()),
+            totals.len()
+        );
+        totals.sort_by_key(|(_, t)| std::cmp::Reverse(*t));
+        println!("Slowest:");
+        for (entry, total) in totals.iter().take(5) {
+            println!(
+                "  {} day {:02}  {:>10}",
+                entry.year,
+                entry.day,
+                format_duration(*total)
+            );
+        }
+    }
+
+    if save && !save_run(&timed, repeat) {
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Times the entries on their puzzle inputs (or `input`), printing either the
+/// stages or just the total of each day. Also returns whether any failed.
+fn time_entries(
+    entries: &[&'static Entry],
+    repeat: usize,
+    input: Option<&Input>,
+    verbose: bool,
+) -> (Vec<(&'static Entry, Timings)>, bool) {
+    let mut failed = false;
+    let mut timed = Vec::new();
+    for &entry in entries {
+        if verbose {
+            println!("== {} day {:02} ==", entry.year, entry.day);
+        }
+
+        let path = input_file(entry, input);
+        let timings = aoclib::read_file_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+            .and_then(|s| timing::time(&entry.solution, &s, repeat).map_err(|e| e.to_string()));
+        match timings {
+            Ok(timings) => {
+                if verbose {
+                    print_timings(&timings, repeat > 1);
+                } else {
+                    println!(
+                        "{} day {:02}: {}",
+                        entry.year,
+                        entry.day,
+                        format_duration(timings.total())
+                    );
+                }
+                timed.push((entry, timings));
+            }
+            Err(e) => {
+                eprintln!("{} day {:02}: error: {}", entry.year, entry.day, e);
+                failed = true;
+            }
+        }
+    }
+    (timed, failed)
+}
+
+fn to_run(timed: &[(&Entry, Timings)], repeat: usize) -> Run {
+    Run::new(
+        repeat,
+        timed
+            .iter()
+            .map(|(e, t)| DayTimings::new(e.year, e.day, t))
+            .collect(),
+    )
+}
+
+fn save_run(timed: &[(&Entry, Timings)], repeat: usize) -> bool {
+    match history::save(&history::history_dir(), &to_run(timed, repeat)) {
+        Ok(path) => {
+            println!("Saved {}", path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to save the timings: {}", e);
+            false
+        }
+    }
+}
+
+fn report(
+    targets: &[Target],
+    repeat: usize,
+    threshold: f64,
+    baseline: Option<&Path>,
+    save: bool,
+) -> ExitCode {
+    let baseline = match baseline {
+        Some(path) => history::load(path).map(Some),
+        None => history::latest(&history::history_dir()),
+    };
+    let baseline = match baseline {
+        Ok(Some(baseline)) => baseline,
+        Ok(None) => {
+            eprintln!("No stored timings to compare against, save some with `aoc time --save`");
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Only the days the baseline has timings for can be compared
+    let entries: Vec<_> = select(targets)
+        .into_iter()
+        .filter(|e| baseline.get(e.year, e.day).is_some())
+        .collect();
+    if entries.is_empty() {
+        eprintln!("The baseline has no timings for the given targets");
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "Comparing against {} ({})",
+        baseline.commit.as_deref().unwrap_or("unknown commit"),
+        baseline.file_name()
+    );
+    let (timed, mut failed) = time_entries(&entries, repeat, None, false);
+    let current = to_run(&timed, repeat);
+    let changes = history::compare(&baseline, &current, threshold / 100.0);
+
+    println!();
+    if changes.is_empty() {
+        println!("No changes over {}%", threshold);
+    }
+    for change in &changes {
+        println!(
+            "{} day {:02} {:<6} {:>10} -> {:>10}  {}{}",
+            change.year,
+            change.day,
+            change.stage.name(),
+            format_duration(change.before),
+            format_duration(change.after),
+            change
+                .ratio()
+                .map_or("new".to_owned(), |r| format!("{:+.0}%", r * 100.0)),
+            if change.regressed() {
+                "  REGRESSED"
+            } else {
+                ""
+            }
+        );
+    }
+    let regressed = changes.iter().filter(|c| c.regressed()).count();
+    let new = changes.iter().filter(|c| c.is_new()).count();
+    println!(
+        "{} regressed, {} improved, {} new",
+        regressed,
+        changes.len() - regressed - new,
+        new
+    );
+
+    if save && !save_run(&timed, repeat) {
+        failed = true;
+    }
+
+    if failed || regressed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_timings(timings: &Timings, stats: bool) {
+    for (stage, s) in &timings.0 {
+        if stats {
+            println!(
+                "{:<6} {:>10}  mean {:>10} ± {:>10}  min {:>10}  max {:>10}",
+                stage.name(),
+                format_duration(s.median),
+                format_duration(s.mean),
+                format_duration(s.stddev),
+                format_duration(s.min),
+                format_duration(s.max)
+            );
+        } else {
+            println!("{:<6} {:>10}", stage.name(), format_duration(s.median));
+        }
+    }
+    println!("{:<6} {:>10}", "total", format_duration(timings.total()));
+}
+
+fn format_duration(d: Duration) -> String {
+    format!("{:.2?}", d)
+}
+
+fn verify(targets: &[Target], record: bool) -> ExitCode {
+    let entries = select(targets);
+    if entries.is_empty() {
+        eprintln!("No solutions match the given targets");
+        return ExitCode::FAILURE;
+    }
+    let mut years: Vec<u16> = entries.iter().map(|e| e.year).collect();
+    years.dedup();
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for year in years {
+        let path = aoc_runner::answers_path(year);
+        let mut known = match AnswerFile::load(&path) {
+            Ok(known) => known,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let mut recorded = false;
+
+        for entry in entries.iter().filter(|e| e.year == year) {
+            let answers = match solve(entry, &aoc_runner::input_path(year, entry.day)) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    println!("{} day {:02}: error: {}", year, entry.day, e);
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let verdicts = known.check(entry.day, &answers);
+            let actual = [&answers.part1, &answers.part2];
+            if verdicts.iter().any(|v| matches!(v, Verdict::Fail { .. })) {
+                println!("{} day {:02}: FAIL", year, entry.day);
+                for (part, verdict) in verdicts.iter().enumerate() {
+                    if let Verdict::Fail { expected } = verdict {
+                        println!(
+                            "  Part {}: expected {:?}, got {:?}",
+                            part + 1,
+                            expected,
+                            actual[part]
+                                .as_ref()
+                                .map(|a| a.to_string())
+                                .unwrap_or_default()
+                        );
+                    }
+                }
+                failed += 1;
+            } else if verdicts.contains(&Verdict::Unknown) {
+                if record {
+                    for (part, verdict) in verdicts.iter().enumerate() {
+                        if let (Verdict::Unknown, Some(answer)) = (verdict, actual[part]) {
+                            known.set(entry.day, part as u8 + 1, &answer.to_string());
+                        }
+                    }
+                    recorded = true;
+                    println!("{} day {:02}: recorded", year, entry.day);
+                } else {
+                    println!("{} day {:02}: unknown", year, entry.day);
+                }
+                unknown += 1;
+            } else {
+                println!("{} day {:02}: pass", year, entry.day);
+                passed += 1;
+            }
+        }
+
+        if recorded {
+            if let Err(e) = known.save(&path) {
+                eprintln!("Failed to write {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(targets: &[Target], base_url: &str) -> ExitCode {
+    let mut days: Vec<(u16, u8)> = targets
+        .iter()
+        .flat_map(|t| match (t.year, t.day) {
+            (Some(y), Some(d)) => vec![(y, d)],
+            _ => select(&[*t]).iter().map(|e| (e.year, e.day)).collect(),
+        })
+        .collect();
+    days.sort();
+    days.dedup();
+
+    if fetch_days(&days, base_url) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Fetches the inputs for the given days, returning whether all succeeded.
+fn fetch_days(days: &[(u16, u8)], base_url: &str) -> bool {
+    if days.is_empty() {
+        eprintln!("No days match the given targets");
+        return false;
+    }
+    let mut fetcher = Fetcher::new("").with_base_url(base_url);
+    // Each year can have its own token, see `read_session_token`
+    let mut token_year = None;
+    let mut have_token = false;
+
+    let mut failed = false;
+    for &(year, day) in days {
+        if token_year != Some(year) {
+            token_year = Some(year);
+            have_token = match aoc_runner::read_session_token(year) {
+                Ok(token) => {
+                    fetcher.set_session(&token);
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}: {}", year, e);
+                    false
+                }
+            };
+        }
+        if !have_token {
+            failed = true;
+            continue;
+        }
+
+        let path = aoc_runner::input_path(year, day);
+        match fetcher.fetch_input(year, day, &path) {
+            Ok(FetchStatus::Cached) => println!("{} day {:02}: already stored", year, day),
+            Ok(FetchStatus::Downloaded) => println!("{} day {:02}: {}", year, day, path.display()),
+            Err(e) => {
+                eprintln!("{} day {:02}: {}", year, day, e);
+                failed = true;
+            }
+        }
+    }
+
+    !failed
+}
+
+fn new_day(target: Target, template: Template, fetch: bool) -> ExitCode {
+    let (Some(year), Some(day)) = (target.year, target.day) else {
+        eprintln!("A single day must be given as YEAR/DAY");
+        return ExitCode::FAILURE;
+    };
+
+    match scaffold::new_day(aoc_runner::workspace_root(), year, day, template) {
+        Ok(created) => {
+            println!("Created {}", created.source.display());
+            println!(
+                "Paste the example into {} and add its answers to example_tests!",
+                created.example.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if fetch && !fetch_days(&[(year, day)], aoclib::fetch::DEFAULT_BASE_URL) {
+        eprintln!("Input not fetched, retry with `aoc fetch {}/{}`", year, day);
+    }
+    ExitCode::SUCCESS
+}
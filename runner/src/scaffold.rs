@@ -0,0 +1,215 @@
+//! Generating new day files from the templates in `runner/templates` and
+//! registering them in the year crate.
+
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum Template {
+    /// Input as a `Vec<String>` of lines
+    Lines,
+    /// Input as the raw `&str`
+    String,
+    /// Input as an `Array2<char>`
+    Chars,
+    /// Input as every integer found in it
+    Ints,
+}
+
+impl Template {
+    fn source(self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("../templates/lines.rs_"),
+            Template::String => include_str!("../templates/string.rs_"),
+            Template::Chars => include_str!("../templates/chars.rs_"),
+            Template::Ints => include_str!("../templates/ints.rs_"),
+        }
+    }
+
+    pub fn render(self, day: u8) -> String {
+        self.source()
+            .replace("{{dd}}", &format!("{:02}", day))
+            .replace("{{day}}", &day.to_string())
+    }
+}
+
+/// Files created by [`new_day`].
+#[derive(Debug)]
+pub struct NewDay {
+    pub source: PathBuf,
+    pub example: PathBuf,
+}
+
+/// Creates the day file and an empty example input, and registers the day in
+/// the year crate's `SOLUTIONS`. Nothing is written if the day already exists.
+pub fn new_day(root: &Path, year: u16, day: u8, template: Template) -> io::Result<NewDay> {
+    let year_dir = root.join(year.to_string());
+    let lib_path = year_dir.join("src").join("lib.rs");
+    let bin_dir = year_dir.join("src").join("bin");
+    let source = bin_dir.join(format!("{:02}.rs", day));
+    let example = bin_dir
+        .join("inputs")
+        .join(format!("{:02}.example1.txt", day));
+
+    if !lib_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No crate for year {} at {}", year, year_dir.display()),
+        ));
+    }
+    if source.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", source.display()),
+        ));
+    }
+    let lib = register_day(&std::fs::read_to_string(&lib_path)?, year, day)
+        .map_err(|e| io::Error::new(io::ErrorKind::AlreadyExists, e))?;
+
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&source)?
+        .write_all(template.render(day).as_bytes())?;
+    std::fs::create_dir_all(example.parent().unwrap())?;
+    if !example.exists() {
+        std::fs::File::create(&example)?;
+    }
+    std::fs::write(&lib_path, lib)?;
+
+    Ok(NewDay { source, example })
+}
+
+/// Adds the module and the `SOLUTIONS` entry for a day to a year crate's
+/// `lib.rs`, keeping both sorted by day.
+pub fn register_day(lib: &str, year: u16, day: u8) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    if lib.contains(&format!("pub mod {};", module)) {
+        return Err(format!("Day {} is already registered for {}", day, year));
+    }
+
+    let solutions_start = lib
+        .find("pub const SOLUTIONS")
+        .ok_or("No SOLUTIONS in the year crate")?;
+    let solutions_end = solutions_start
+        + lib[solutions_start..]
+            .find("];")
+            .ok_or("SOLUTIONS is not terminated")?;
+
+    let mod_at = (day + 1..=25)
+        .find_map(|d| lib.find(&format!("#[path = \"bin/{:02}.rs\"]", d)))
+        .unwrap_or(solutions_start);
+    let entry_at = (day + 1..=25)
+        .find_map(|d| lib.find(&format!("    Entry::new({}, {},", year, d)))
+        .unwrap_or(solutions_end);
+
+    let mut out = String::with_capacity(lib.len() + 100);
+    out.push_str(&lib[..mod_at]);
+    out.push_str(&format!(
+        "#[path = \"bin/{:02}.rs\"]\npub mod {};\n\n",
+        day, module
+    ));
+    out.push_str(&lib[mod_at..entry_at]);
+    out.push_str(&format!(
+        "    Entry::new({}, {}, {}::SOLUTION),\n",
+        year, day, module
+    ));
+    out.push_str(&lib[entry_at..]);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LIB: &str = "use aoclib::solution::Entry;
+
+#[path = \"bin/02.rs\"]
+pub mod day02;
+
+#[path = \"bin/05.rs\"]
+pub mod day05;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2024, 2, day02::SOLUTION),
+    Entry::new(2024, 5, day05::SOLUTION),
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let lib = register_day(LIB, 2024, 3).unwrap();
+        let lib = register_day(&lib, 2024, 1).unwrap();
+        let lib = register_day(&lib, 2024, 25).unwrap();
+
+        assert_eq!(
+            "use aoclib::solution::Entry;
+
+#[path = \"bin/01.rs\"]
+pub mod day01;
+
+#[path = \"bin/02.rs\"]
+pub mod day02;
+
+#[path = \"bin/03.rs\"]
+pub mod day03;
+
+#[path = \"bin/05.rs\"]
+pub mod day05;
+
+#[path = \"bin/25.rs\"]
+pub mod day25;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(2024, 1, day01::SOLUTION),
+    Entry::new(2024, 2, day02::SOLUTION),
+    Entry::new(2024, 3, day03::SOLUTION),
+    Entry::new(2024, 5, day05::SOLUTION),
+    Entry::new(2024, 25, day25::SOLUTION),
+];
+",
+            lib
+        );
+
+        assert!(register_day(&lib, 2024, 5).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let src = Template::Chars.render(7);
+        assert!(src.contains("struct Day07;"));
+        assert!(src.contains("aoclib::aoc_main!(Day07);"));
+        assert!(src.contains("get_test_input_file!(7)"));
+        assert!(src.contains("include_str!(\"inputs/07.example1.txt\")"));
+        assert!(!src.contains("{{"));
+    }
+
+    #[test]
+    fn test_new_day_refuses_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("2024/src/bin")).unwrap();
+        std::fs::write(root.join("2024/src/lib.rs"), LIB).unwrap();
+
+        let created = new_day(&root, 2024, 3, Template::Lines).unwrap();
+        assert!(created.example.exists());
+        let source = std::fs::read_to_string(&created.source).unwrap();
+        assert_eq!(Template::Lines.render(3), source);
+
+        std::fs::write(&created.source, "// solved").unwrap();
+        let err = new_day(&root, 2024, 3, Template::Ints).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        assert_eq!(
+            "// solved",
+            std::fs::read_to_string(&created.source).unwrap()
+        );
+
+        // Registered but missing source file: the lib must stay untouched
+        let err = new_day(&root, 2024, 5, Template::Lines).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+        assert!(!root.join("2024/src/bin/05.rs").exists());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,126 @@
+//! Timing the parse, part 1 and part 2 stages of solutions separately.
+
+use aoclib::solution::{DynSolution, Measure, Stage};
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Runs every stage `repeat` times, recording the duration of each run.
+pub struct Samples {
+    repeat: usize,
+    stages: Vec<(Stage, Vec<Duration>)>,
+}
+
+impl Samples {
+    pub fn new(repeat: usize) -> Samples {
+        Samples {
+            repeat: repeat.max(1),
+            stages: Vec::new(),
+        }
+    }
+}
+
+impl Measure for Samples {
+    fn measure(&mut self, stage: Stage, run: &mut dyn FnMut()) {
+        let durations = (0..self.repeat)
+            .map(|_| {
+                let start = Instant::now();
+                run();
+                start.elapsed()
+            })
+            .collect();
+        self.stages.push((stage, durations));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Stats {
+            runs,
+            min: sorted[0],
+            max: sorted[runs - 1],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The timings of one solution, in [`Stage::ALL`] order.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Timings(pub Vec<(Stage, Stats)>);
+
+impl Timings {
+    /// Sum of the medians of the stages.
+    pub fn total(&self) -> Duration {
+        self.0.iter().map(|(_, s)| s.median).sum()
+    }
+}
+
+pub fn time(solution: &DynSolution, input: &str, repeat: usize) -> io::Result<Timings> {
+    let mut samples = Samples::new(repeat);
+    solution.measure(input, &mut samples)?;
+    Ok(Timings(
+        samples
+            .stages
+            .iter()
+            .map(|(stage, durations)| (*stage, Stats::from_samples(durations)))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(4)]);
+        assert_eq!(4, stats.runs);
+        assert_eq!(ms(2), stats.min);
+        assert_eq!(ms(6), stats.max);
+        assert_eq!(ms(4), stats.median);
+        assert_eq!(ms(4), stats.mean);
+        assert_eq!(1414, stats.stddev.as_micros());
+
+        let single = Stats::from_samples(&[ms(3)]);
+        assert_eq!(ms(3), single.median);
+        assert_eq!(Duration::ZERO, single.stddev);
+    }
+
+    #[test]
+    fn test_samples_repeat() {
+        let mut samples = Samples::new(3);
+        let mut calls = 0;
+        samples.measure(Stage::Part1, &mut || calls += 1);
+        assert_eq!(3, calls);
+        assert_eq!(3, samples.stages[0].1.len());
+    }
+}